@@ -0,0 +1,142 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+#[proc_macro_derive(CosmoFormModel)]
+pub fn derive_cosmo_form_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_cosmo_form_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_cosmo_form_model(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            input.generics,
+            "CosmoFormModel cannot be derived for generic structs",
+        ));
+    }
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "CosmoFormModel can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "CosmoFormModel can only be derived for structs",
+            ))
+        }
+    };
+
+    let vis = input.vis;
+    let model = input.ident;
+    let fields_struct = format_ident!("{}FormFields", model);
+
+    let field_definitions = fields.iter().map(|field| {
+        let field_vis = &field.vis;
+        let name = &field.ident;
+        let ty = &field.ty;
+
+        quote!(#field_vis #name: ::yew_cosmo::prelude::CosmoFormLens<#model, #ty>)
+    });
+    let field_lenses = fields.iter().map(|field| {
+        let name = &field.ident;
+        let ty = &field.ty;
+        let label = name.as_ref().map(|name| name.to_string());
+
+        quote!(
+            #name: ::yew_cosmo::prelude::CosmoFormLens::new(
+                #label,
+                |model: &#model| -> #ty { ::std::clone::Clone::clone(&model.#name) },
+                |model: &mut #model, value: #ty| model.#name = value,
+            )
+        )
+    });
+
+    Ok(quote!(
+        #[derive(Clone, Copy)]
+        #vis struct #fields_struct {
+            #(#field_definitions,)*
+        }
+
+        impl ::yew_cosmo::prelude::CosmoFormModel for #model {
+            type Fields = #fields_struct;
+
+            fn fields() -> Self::Fields {
+                #fields_struct {
+                    #(#field_lenses,)*
+                }
+            }
+        }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn expand_error(input: DeriveInput) -> String {
+        expand_cosmo_form_model(input)
+            .expect_err("Expansion should fail")
+            .to_string()
+    }
+
+    #[test]
+    fn expands_a_lens_per_field() {
+        let expanded = expand_cosmo_form_model(parse_quote!(
+            pub struct Signup {
+                pub name: String,
+                age: i64,
+            }
+        ))
+        .unwrap()
+        .to_string();
+
+        assert!(expanded.contains("pub struct SignupFormFields"));
+        assert!(expanded
+            .contains("pub name : :: yew_cosmo :: prelude :: CosmoFormLens < Signup , String >"));
+        assert!(
+            expanded.contains("age : :: yew_cosmo :: prelude :: CosmoFormLens < Signup , i64 >")
+        );
+        assert!(expanded.contains("\"age\""));
+    }
+
+    #[test]
+    fn rejects_unsupported_types() {
+        assert_eq!(
+            expand_error(parse_quote!(
+                struct Wrapper<T> {
+                    value: T,
+                }
+            )),
+            "CosmoFormModel cannot be derived for generic structs"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                struct Point(i64, i64);
+            )),
+            "CosmoFormModel can only be derived for structs with named fields"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                enum Choice {
+                    Yes,
+                    No,
+                }
+            )),
+            "CosmoFormModel can only be derived for structs"
+        );
+    }
+}
@@ -4,6 +4,7 @@ use yew_router::prelude::*;
 
 use yew_cosmo::prelude::*;
 
+use crate::pages::controls::data_grid::DataGrid;
 use crate::pages::controls::dialog::Dialog;
 use crate::pages::controls::html::HtmlControls;
 use crate::pages::controls::message::Message;
@@ -26,17 +27,11 @@ fn format_title(s: AttrValue) -> AttrValue {
     }
 }
 
-fn render_sub_menu_entry<Route>(label: impl ToString, to: Route) -> impl Fn(Route) -> Html
-where
-    Route: Routable + 'static,
-{
-    move |route| {
-        let is_active = route.eq(&to.clone());
-
-        html!(
-            <CosmoSubMenuItemLink<Route> to={to.clone()} label={label.to_string()} is_active={is_active} />
-        )
-    }
+fn is_cosmo_route(route: &DocsRoute) -> bool {
+    matches!(
+        route,
+        DocsRoute::Home | DocsRoute::CosmoRoot | DocsRoute::Cosmo
+    )
 }
 
 fn switch_sub_menu(route: DocsRoute) -> Html {
@@ -44,53 +39,37 @@ fn switch_sub_menu(route: DocsRoute) -> Html {
         DocsRoute::Home | DocsRoute::CosmoRoot | DocsRoute::Cosmo => {
             html!(
                 <CosmoSubMenuBar>
-                    <Switch<CosmoRoute> render={render_sub_menu_entry("About Cosmo", CosmoRoute::About)} />
-                    <Switch<CosmoRoute> render={render_sub_menu_entry("Typography", CosmoRoute::Typography)} />
-                    <Switch<CosmoRoute> render={render_sub_menu_entry("Theme", CosmoRoute::Theme)} />
-                    <Switch<CosmoRoute> render={render_sub_menu_entry("Customize", CosmoRoute::Customize)} />
+                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::About} label="About Cosmo" />
+                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Typography} label="Typography" />
+                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Theme} label="Theme" />
+                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Customize} label="Customize" />
                 </CosmoSubMenuBar>
             )
         }
         DocsRoute::Controls | DocsRoute::ControlsRoot => {
             html!(
                 <CosmoSubMenuBar>
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("HTML Controls", ControlsRoute::Html)} />
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("Message", ControlsRoute::Message)} />
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("Side List Control", ControlsRoute::SideList)} />
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("Tab Control", ControlsRoute::TabControl)} />
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("Toolbar", ControlsRoute::Toolbar)} />
-                    <Switch<ControlsRoute> render={render_sub_menu_entry("Dialogs", ControlsRoute::Dialog)} />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::Html} label="HTML Controls" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::Message} label="Message" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::SideList} label="Side List Control" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::TabControl} label="Tab Control" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::Toolbar} label="Toolbar" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::Dialog} label="Dialogs" />
+                    <CosmoSubMenuItemLink<ControlsRoute> to={ControlsRoute::DataGrid} label="Data grid" />
                 </CosmoSubMenuBar>
             )
         }
         DocsRoute::Layout | DocsRoute::LayoutRoot => {
             html!(
                 <CosmoSubMenuBar>
-                    <Switch<LayoutRoute> render={render_sub_menu_entry("Base layout and menu", LayoutRoute::Base)} />
-                    <Switch<LayoutRoute> render={render_sub_menu_entry("Layout outline", LayoutRoute::Showcase)} />
+                    <CosmoSubMenuItemLink<LayoutRoute> to={LayoutRoute::Base} label="Base layout and menu" />
+                    <CosmoSubMenuItemLink<LayoutRoute> to={LayoutRoute::Showcase} label="Layout outline" />
                 </CosmoSubMenuBar>
             )
         }
     }
 }
 
-fn render_main_menu_entry<Route>(
-    label: impl ToString,
-    to: Route,
-    active: Route,
-) -> impl Fn(Route) -> Html
-where
-    Route: Routable + 'static,
-{
-    move |route| {
-        let is_active = route.eq(&active.clone());
-
-        html!(
-            <CosmoMainMenuItemLink<Route> to={to.clone()} label={label.to_string()} is_active={is_active} />
-        )
-    }
-}
-
 fn switch_cosmo(route: CosmoRoute) -> Html {
     match route {
         CosmoRoute::About => html!(
@@ -178,6 +157,14 @@ fn switch_controls(route: ControlsRoute) -> Html {
                 <Dialog />
             </>
         ),
+        ControlsRoute::DataGrid => html!(
+            <>
+                <Helmet>
+                    <title>{"Data grid"}</title>
+                </Helmet>
+                <DataGrid />
+            </>
+        ),
     }
 }
 
@@ -262,9 +249,9 @@ pub fn app() -> Html {
                 </CosmoTopBar>
                 <CosmoMenuBar>
                     <CosmoMainMenu>
-                        <Switch<DocsRoute> render={render_main_menu_entry("Cosmo", DocsRoute::CosmoRoot, DocsRoute::Cosmo)} />
-                        <Switch<DocsRoute> render={render_main_menu_entry("Controls", DocsRoute::ControlsRoot, DocsRoute::Controls)} />
-                        <Switch<DocsRoute> render={render_main_menu_entry("Layout", DocsRoute::LayoutRoot, DocsRoute::Layout)} />
+                        <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::CosmoRoot} label="Cosmo" route_match={CosmoRouteMatch::custom(is_cosmo_route)} />
+                        <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::ControlsRoot} label="Controls" />
+                        <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::LayoutRoot} label="Layout" />
                     </CosmoMainMenu>
                     <Switch<DocsRoute> render={switch_sub_menu} />
                 </CosmoMenuBar>
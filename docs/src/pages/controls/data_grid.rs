@@ -0,0 +1,127 @@
+use yew::prelude::*;
+
+use yew_cosmo::prelude::*;
+
+use crate::ui::{CosmoDemo, CosmoDocsCodeSample};
+
+#[derive(PartialEq, Clone)]
+struct Planet {
+    name: AttrValue,
+    moons: usize,
+    distance: f64,
+}
+
+fn planets() -> Vec<Planet> {
+    vec![
+        Planet {
+            name: "Mercury".into(),
+            moons: 0,
+            distance: 0.39,
+        },
+        Planet {
+            name: "Venus".into(),
+            moons: 0,
+            distance: 0.72,
+        },
+        Planet {
+            name: "Earth".into(),
+            moons: 1,
+            distance: 1.0,
+        },
+        Planet {
+            name: "Mars".into(),
+            moons: 2,
+            distance: 1.52,
+        },
+        Planet {
+            name: "Jupiter".into(),
+            moons: 95,
+            distance: 5.2,
+        },
+        Planet {
+            name: "Saturn".into(),
+            moons: 146,
+            distance: 9.54,
+        },
+        Planet {
+            name: "Uranus".into(),
+            moons: 28,
+            distance: 19.2,
+        },
+        Planet {
+            name: "Neptune".into(),
+            moons: 16,
+            distance: 30.06,
+        },
+    ]
+}
+
+#[function_component(DataGrid)]
+pub fn data_grid() -> Html {
+    let columns = vec![
+        CosmoDataGridColumn::new("Name", |planet: &Planet| html!(planet.name.clone()))
+            .with_sort_key(|planet: &Planet| planet.name.clone()),
+        CosmoDataGridColumn::new("Moons", |planet: &Planet| html!(planet.moons))
+            .with_sort_key(|planet: &Planet| planet.moons),
+        CosmoDataGridColumn::new("Distance to the sun", |planet: &Planet| {
+            html!(format!("{} AU", planet.distance))
+        })
+        .with_compare(|a: &Planet, b: &Planet| a.distance.total_cmp(&b.distance)),
+    ];
+
+    let selected_state = use_state_eq(Vec::<AttrValue>::new);
+    let on_selection_change = use_callback(selected_state.clone(), |keys, state| state.set(keys));
+    let on_delete = use_callback(selected_state.clone(), |_, state| state.set(vec![]));
+
+    html!(
+        <>
+            <CosmoTitle title="Data grid" />
+            <CosmoParagraph>
+                {"The data grid is a typed table, it takes a list of rows and column definitions. Every column has a header, a cell renderer and an optional sort key. Clicking the header of a sortable column sorts the rows ascending, clicking it again sorts them descending."}
+            </CosmoParagraph>
+            <CosmoParagraph>
+                {"The rows are paginated on the client, the page size can be changed by the user. If there are no rows, a placeholder is shown."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoDataGrid<Planet> columns={columns.clone()} rows={planets()} page_size={5} page_sizes={vec![5, 10]} />
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"let columns = vec![
+    CosmoDataGridColumn::new("Name", |planet: &Planet| html!(planet.name.clone()))
+        .with_sort_key(|planet: &Planet| planet.name.clone()),
+    CosmoDataGridColumn::new("Moons", |planet: &Planet| html!(planet.moons))
+        .with_sort_key(|planet: &Planet| planet.moons),
+    CosmoDataGridColumn::new("Distance to the sun", |planet: &Planet| {
+        html!(format!("{} AU", planet.distance))
+    })
+    .with_compare(|a: &Planet, b: &Planet| a.distance.total_cmp(&b.distance)),
+];
+
+html!(
+    <CosmoDataGrid<Planet> columns={columns.clone()} rows={planets()} page_size={5} page_sizes={vec![5, 10]} />
+)"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Row selection" />
+            <CosmoParagraph>
+                {"Selectable data grids render a checkbox in front of every row and a select all checkbox in the header. Holding shift while clicking a checkbox selects the range from the last clicked row. The selection toolbar item only shows up if rows are selected and is the place for bulk actions."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoToolbar>
+                    <CosmoToolbarSelection count={(*selected_state).len()}>
+                        <CosmoButton label={format!("Delete {} items", (*selected_state).len())} state={CosmoButtonType::Negative} on_click={on_delete} />
+                    </CosmoToolbarSelection>
+                </CosmoToolbar>
+                <CosmoDataGrid<Planet> columns={columns.clone()} rows={planets()} is_selectable={true} row_key={|planet: Planet| planet.name} selected_keys={(*selected_state).clone()} on_selection_change={on_selection_change} />
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"<CosmoToolbar>
+    <CosmoToolbarSelection count={(*selected_state).len()}>
+        <CosmoButton label={format!("Delete {} items", (*selected_state).len())} state={CosmoButtonType::Negative} on_click={on_delete} />
+    </CosmoToolbarSelection>
+</CosmoToolbar>
+<CosmoDataGrid<Planet> columns={columns.clone()} rows={planets()} is_selectable={true} row_key={|planet: Planet| planet.name} selected_keys={(*selected_state).clone()} on_selection_change={on_selection_change} />"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Empty data grid" />
+            <CosmoDemo>
+                <CosmoDataGrid<Planet> columns={columns} rows={vec![]} no_data_label="There are no planets" />
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"<CosmoDataGrid<Planet> columns={columns} rows={vec![]} no_data_label="There are no planets" />"#}</CosmoDocsCodeSample>
+        </>
+    )
+}
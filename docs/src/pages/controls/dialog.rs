@@ -1,3 +1,4 @@
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
 use yew_cosmo::prelude::*;
@@ -42,6 +43,40 @@ pub fn dialog() -> Html {
         |value: AttrValue, state| state.set(value),
     );
 
+    let dialogs = use_cosmo_dialogs();
+    let toast = use_cosmo_toast();
+
+    let on_imperative_alert = use_callback(dialogs.clone(), |_, dialogs| {
+        let alert = dialogs.alert(("I am an Alert", "I was opened from a callback."));
+        spawn_local(alert);
+    });
+    let on_imperative_confirm =
+        use_callback((dialogs.clone(), toast.clone()), |_, (dialogs, toast)| {
+            let confirm = dialogs.confirm(
+                CosmoDialogOptions::new("Delete item", "Do you really want to delete the item?")
+                    .with_type(CosmoModalType::Negative)
+                    .with_confirm_label("Delete")
+                    .with_decline_label("Keep"),
+            );
+            let toast = toast.clone();
+            spawn_local(async move {
+                if confirm.await {
+                    toast.positive("The item was deleted");
+                } else {
+                    toast.information("The item was kept");
+                }
+            });
+        });
+    let on_imperative_prompt = use_callback((dialogs, toast), |_, (dialogs, toast)| {
+        let prompt = dialogs.prompt(("Your name", "Name"), "Cosmo");
+        let toast = toast.clone();
+        spawn_local(async move {
+            if let Some(name) = prompt.await {
+                toast.information(format!("Hello {name}"));
+            }
+        });
+    });
+
     html!(
         <>
             <CosmoTitle title="Dialogs" />
@@ -51,6 +86,9 @@ pub fn dialog() -> Html {
             <CosmoParagraph>
                 {"Cosmo provides a generic dialog which can be filled with basically any content and replacements for the browser internal confirm and alert dialogs."}
             </CosmoParagraph>
+            <CosmoParagraph>
+                {"When a dialog opens, the focus moves into the dialog and stays there while tabbing. After the dialog closes, the focus returns to the element that opened it. If you pass an on_dismiss callback, the user can close the dialog with the Escape key."}
+            </CosmoParagraph>
             <CosmoDemo>
                 <CosmoToolbar>
                     <CosmoToolbarGroup>
@@ -69,13 +107,13 @@ pub fn dialog() -> Html {
                     </CosmoToolbarGroup>
                 </CosmoToolbar>
                 if *alert_open_state {
-                    <CosmoAlert alert_type={(*alert_type_state).clone()} close_label="Close" title="I am an Alert" message="I am just a small alert modal, you can close me." on_close={close_alert} />
+                    <CosmoAlert alert_type={(*alert_type_state).clone()} close_label="Close" title="I am an Alert" message="I am just a small alert modal, you can close me." on_close={close_alert.clone()} on_dismiss={close_alert} />
                 }
                 if *confirm_open_state {
-                    <CosmoConfirm confirm_type={(*alert_type_state).clone()} title="I am a confirm" message="I am a confirm modal, it is best to use me to ask the user for confirmation." confirm_label="Confirm" decline_label="Decline" on_confirm={close_confirm.clone()} on_decline={close_confirm} />
+                    <CosmoConfirm confirm_type={(*alert_type_state).clone()} title="I am a confirm" message="I am a confirm modal, it is best to use me to ask the user for confirmation." confirm_label="Confirm" decline_label="Decline" on_confirm={close_confirm.clone()} on_decline={close_confirm.clone()} on_dismiss={close_confirm} />
                 }
                 if *modal_open_state {
-                    <CosmoModal modal_type={(*alert_type_state).clone()} is_form={true} title="I am a simple form" on_form_submit={close_modal.clone()} buttons={html!(
+                    <CosmoModal modal_type={(*alert_type_state).clone()} is_form={true} title="I am a simple form" on_form_submit={close_modal.clone()} on_dismiss={close_modal.clone()} buttons={html!(
                         <>
                             <CosmoButton label="Discard changes" on_click={close_modal} />
                             <CosmoButton label="Save changes" is_submit={true} />
@@ -118,13 +156,13 @@ pub fn dialog() -> Html {
         </CosmoToolbarGroup>
     </CosmoToolbar>
     if *alert_open_state {
-        <CosmoAlert alert_type={(*alert_type_state).clone()} close_label="Close" title="I am an Alert" message="I am just a small alert modal, you can close me." on_close={close_alert} />
+        <CosmoAlert alert_type={(*alert_type_state).clone()} close_label="Close" title="I am an Alert" message="I am just a small alert modal, you can close me." on_close={close_alert.clone()} on_dismiss={close_alert} />
     }
     if *confirm_open_state {
-        <CosmoConfirm confirm_type={(*alert_type_state).clone()} title="I am a confirm" message="I am a confirm modal, it is best to use me to ask the user for confirmation." confirm_label="Confirm" decline_label="Decline" on_confirm={close_confirm.clone()} on_decline={close_confirm} />
+        <CosmoConfirm confirm_type={(*alert_type_state).clone()} title="I am a confirm" message="I am a confirm modal, it is best to use me to ask the user for confirmation." confirm_label="Confirm" decline_label="Decline" on_confirm={close_confirm.clone()} on_decline={close_confirm.clone()} on_dismiss={close_confirm} />
     }
     if *modal_open_state {
-        <CosmoModal modal_type={(*alert_type_state).clone()} is_form={true} title="I am a simple form" on_form_submit={close_modal.clone()} buttons={html!(
+        <CosmoModal modal_type={(*alert_type_state).clone()} is_form={true} title="I am a simple form" on_form_submit={close_modal.clone()} on_dismiss={close_modal.clone()} buttons={html!(
             <>
                 <CosmoButton label="Discard changes" on_click={close_modal} />
                 <CosmoButton label="Save changes" is_submit={true} />
@@ -149,6 +187,54 @@ pub fn dialog() -> Html {
         </CosmoModal>
     }
 </>"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Imperative dialogs" />
+            <CosmoParagraph>
+                {"Instead of tracking the open state yourself, you can open alerts, confirms and prompts from any callback. The page layout provides the dialog service, you can access it with the use_cosmo_dialogs hook. Each method returns a future, which resolves once the user closes the dialog."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoToolbar>
+                    <CosmoToolbarGroup>
+                        <CosmoButton label="Alert" on_click={on_imperative_alert} />
+                        <CosmoButton label="Confirm" on_click={on_imperative_confirm} />
+                        <CosmoButton label="Prompt" on_click={on_imperative_prompt} />
+                    </CosmoToolbarGroup>
+                </CosmoToolbar>
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"let dialogs = use_cosmo_dialogs();
+let toast = use_cosmo_toast();
+
+let on_imperative_alert = use_callback(dialogs.clone(), |_, dialogs| {
+    let alert = dialogs.alert(("I am an Alert", "I was opened from a callback."));
+    spawn_local(alert);
+});
+let on_imperative_confirm = use_callback(
+    (dialogs.clone(), toast.clone()),
+    |_, (dialogs, toast)| {
+        let confirm = dialogs.confirm(
+            CosmoDialogOptions::new("Delete item", "Do you really want to delete the item?")
+                .with_type(CosmoModalType::Negative)
+                .with_confirm_label("Delete")
+                .with_decline_label("Keep"),
+        );
+        let toast = toast.clone();
+        spawn_local(async move {
+            if confirm.await {
+                toast.positive("The item was deleted");
+            } else {
+                toast.information("The item was kept");
+            }
+        });
+    },
+);
+let on_imperative_prompt = use_callback((dialogs, toast), |_, (dialogs, toast)| {
+    let prompt = dialogs.prompt(("Your name", "Name"), "Cosmo");
+    let toast = toast.clone();
+    spawn_local(async move {
+        if let Some(name) = prompt.await {
+            toast.information(format!("Hello {name}"));
+        }
+    });
+});"#}</CosmoDocsCodeSample>
         </>
     )
 }
@@ -5,6 +5,14 @@ use yew_cosmo::prelude::*;
 use crate::ui::CosmoDemo;
 use crate::ui::CosmoDocsCodeSample;
 
+#[derive(PartialEq, Clone, Default, CosmoFormModel)]
+struct Contact {
+    name: AttrValue,
+    age: i64,
+    birthday: NaiveDate,
+    newsletter: bool,
+}
+
 #[function_component(HtmlControls)]
 pub fn html() -> Html {
     let textbox_state = use_state_eq(|| AttrValue::from("I like Cosmo"));
@@ -12,6 +20,10 @@ pub fn html() -> Html {
     let decimalbox_state = use_state_eq(|| 25.03);
     let date_time_state = use_state_eq(Local::now);
     let date_state = use_state_eq(|| Local::now().date_naive());
+    let date_range_state = use_state_eq(|| {
+        let today = Local::now().date_naive();
+        (today, today.iter_days().nth(7).unwrap_or(today))
+    });
     let time_state = use_state_eq(|| Local::now().time());
     let color_state = use_state_eq(|| color!(#514B57));
     let checkbox_state = use_state_eq(|| false);
@@ -40,6 +52,10 @@ pub fn html() -> Html {
     let on_date_input = use_callback(date_state.clone(), |value: NaiveDate, state| {
         state.set(value)
     });
+    let on_date_range_input = use_callback(
+        date_range_state.clone(),
+        |value: (NaiveDate, NaiveDate), state| state.set(value),
+    );
     let on_time_input = use_callback(time_state.clone(), |value: NaiveTime, state| {
         state.set(value)
     });
@@ -82,6 +98,83 @@ pub fn html() -> Html {
         },
     );
 
+    let username_state = use_state_eq(|| AttrValue::from(""));
+    let email_state = use_state_eq(|| AttrValue::from(""));
+    let age_state = use_state_eq(|| 12);
+    let terms_state = use_state_eq(|| false);
+
+    let on_username_input = use_callback(username_state.clone(), |value: AttrValue, state| {
+        state.set(value)
+    });
+    let on_email_input = use_callback(email_state.clone(), |value: AttrValue, state| {
+        state.set(value)
+    });
+    let on_age_input = use_callback(age_state.clone(), |value: i64, state| state.set(value));
+    let on_terms_check = use_callback(terms_state.clone(), |value: bool, state| state.set(value));
+
+    let contact_form = use_cosmo_form::<Contact>();
+    let contact_fields = contact_form.fields();
+    let contact_state = use_state_eq(|| None as Option<AttrValue>);
+
+    let on_contact_reset = use_callback(contact_form.clone(), |_, form| form.reset());
+    let on_contact_submit = use_callback(contact_state.clone(), |contact: Contact, state| {
+        state.set(Some(AttrValue::from(format!(
+            "{} was born on {} and is {} years old",
+            contact.name, contact.birthday, contact.age
+        ))))
+    });
+
+    let toast = use_cosmo_toast();
+    let selected_files_state = use_state_eq(Vec::<AttrValue>::new);
+
+    let on_select_files = use_callback(selected_files_state.clone(), |files: Vec<File>, state| {
+        state.set(
+            files
+                .iter()
+                .map(|file| AttrValue::from(file.name()))
+                .collect(),
+        )
+    });
+    let on_reject_files = use_callback(toast, |files: Vec<CosmoRejectedFile>, toast| {
+        for rejected in files {
+            toast.warning(match rejected.reason {
+                CosmoFileRejectReason::Type => format!("{} is not an image", rejected.file.name()),
+                CosmoFileRejectReason::Size => {
+                    format!("{} is larger than 2 MB", rejected.file.name())
+                }
+            });
+        }
+    });
+
+    let tags_state = use_state_eq(Vec::<AttrValue>::new);
+    let tag_loader = use_memo((), |_| {
+        CosmoModernSelectLoader::new(|search: AttrValue, page: usize| async move {
+            let tags = (1..=5000)
+                .map(|tag| format!("Tag {tag}"))
+                .filter(|tag| tag.to_lowercase().contains(&search.to_lowercase()))
+                .collect::<Vec<_>>();
+            let items = tags
+                .iter()
+                .skip(page * 50)
+                .take(50)
+                .map(|tag| CosmoModernSelectItem::new(tag.clone(), tag.clone(), false))
+                .collect::<Vec<_>>();
+
+            CosmoModernSelectPage::new(items, tags.len() > (page + 1) * 50)
+        })
+    });
+
+    let on_tag_select = use_callback(tags_state.clone(), |value: AttrValue, state| {
+        let mut data = (**state).clone();
+        data.push(value);
+        state.set(data);
+    });
+    let on_tag_deselect = use_callback(tags_state.clone(), |value: AttrValue, state| {
+        let mut data = (**state).clone();
+        data.retain(|tag| *tag != value);
+        state.set(data);
+    });
+
     let on_form_submit = use_callback(alert_open_state.clone(), |_: (), state| state.set(true));
     let on_alert_close = use_callback(alert_open_state.clone(), |_: (), state| state.set(false));
 
@@ -96,7 +189,7 @@ pub fn html() -> Html {
             </CosmoParagraph>
             <CosmoHeader level={CosmoHeaderLevel::H2} header="Forms" />
             <CosmoDemo>
-                <CosmoForm on_submit={on_form_submit} buttons={html!(
+                <CosmoForm on_submit={on_form_submit.clone()} buttons={html!(
                     <>
                         <CosmoButton label="Cancel" />
                         <CosmoButton label="Submit" is_submit={true} />
@@ -111,7 +204,8 @@ pub fn html() -> Html {
                     </CosmoFieldset>
                     <CosmoFieldset title="Picker controls">
                         <CosmoDateTimePicker value={*date_time_state} on_input={on_date_time_input} label="Date Time picker" />
-                        <CosmoDatePicker value={*date_state} on_input={on_date_input} label="Date picker" />
+                        <CosmoDatePicker value={*date_state} on_input={on_date_input} show_week_numbers={true} label="Date picker" />
+                        <CosmoDateRangePicker value={*date_range_state} on_input={on_date_range_input} disabled_dates={CosmoDateFilter::weekends()} label="Date range picker" />
                         <CosmoTimePicker value={*time_state} on_input={on_time_input} label="Time picker" />
                         <CosmoColorPicker value={*color_state} on_input={on_color_input} label="Color picker" />
                         <CosmoFilePicker on_select={|_| {}} label="File picker" />
@@ -161,7 +255,8 @@ pub fn html() -> Html {
     </CosmoFieldset>
     <CosmoFieldset title="Picker controls">
         <CosmoDateTimePicker value={(*date_time_state).clone()} on_input={on_date_time_input} label="Date Time picker" />
-        <CosmoDatePicker value={(*date_state).clone()} on_input={on_date_input} label="Date picker" />
+        <CosmoDatePicker value={(*date_state).clone()} on_input={on_date_input} show_week_numbers={true} label="Date picker" />
+        <CosmoDateRangePicker value={(*date_range_state).clone()} on_input={on_date_range_input} disabled_dates={CosmoDateFilter::weekends()} label="Date range picker" />
         <CosmoTimePicker value={(*time_state).clone()} on_input={on_time_input} label="Time picker" />
         <CosmoColorPicker value={(*color_state).clone()} on_input={on_color_input} label="Color picker" />
         <CosmoFilePicker on_select={|_| {}} label="File picker" />
@@ -195,6 +290,183 @@ pub fn html() -> Html {
         ]} label="Modern multiple select" />
     </CosmoFieldset>
 </CosmoForm>"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="Date pickers" />
+            <CosmoParagraph>
+                {"The date pickers accept typed input in the date format of the locale and open a calendar next to the input. The calendar navigates by month and year, marks today and can show week numbers. Dates outside of min and max or rejected by a date filter can't be selected. The date range picker emits the start and end date once both are picked. The calendar is also available on its own. When you render it on the server, pass today and an id, otherwise today is resolved in the browser after hydration."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoDateRangePicker value={(start, end)} on_input={on_range_input} min={today} disabled_dates={CosmoDateFilter::new(|date| holidays.contains(date))} label="Publishing window" />
+
+<CosmoCalendar value={selected_date} on_select={on_select} show_week_numbers={true} />"#}</CosmoDocsCodeSample>
+            <CosmoParagraph>
+                {"The date time picker works in the local time zone of the browser. To schedule in another zone use the zoned date time picker, it is generic over the chrono time zone of its value and shows the zone next to the input. Typed times are converted in that zone, times skipped by a daylight saving change are moved forward and repeated times keep the current offset. Pass a list of zones to show a zone selector. Switching the zone keeps the wall clock time, so the instant changes. Min and max are compared as instants, no matter which zone is selected."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoZonedDateTimePicker<FixedOffset> value={publish_at} on_input={on_publish_at_input} zones={vec![
+    CosmoTimeZone::new("New York", FixedOffset::west_opt(5 * 3600).unwrap()),
+    CosmoTimeZone::new("Tokyo", FixedOffset::east_opt(9 * 3600).unwrap()),
+]} label="Publish at" />"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="Empty values" />
+            <CosmoParagraph>
+                {"The number, decimal, date, time and date time inputs require a value and only emit when the text parses. If a field may be left empty, use the optional variants, they take and emit an Option and emit None once the field is cleared. Text that can't be parsed or lies outside of min and max is never emitted, the input keeps it and shows an error on validation. The error message defaults to the locale and can be changed with the invalid label."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoOptionalNumberBox value={*age_state} on_input={on_age_input} label="Age" />
+    <CosmoOptionalDatePicker value={*due_date_state} on_input={on_due_date_input} invalid_label="Please enter the due date as DD.MM.YYYY" label="Due date" />"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="Validation" />
+            <CosmoParagraph>
+                {"Every input control accepts a list of validators. Cosmo ships validators for required fields, minimum and maximum length, patterns and numeric ranges. You can also write your own validators, synchronous or asynchronous. Errors are shown below the input, when the form has invalid fields the submission is blocked and the first invalid field is focused. The validation mode of the form controls whether fields are validated on blur, on input or on submit."}
+            </CosmoParagraph>
+            <CosmoParagraph>
+                {"Patterns use the Rust regex syntax and have to match the whole value. The pattern is compiled once when the validator is created, an invalid pattern rejects every value that isn't empty. Use CosmoValidator::try_pattern if you want to handle the error yourself."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoForm on_submit={on_form_submit.clone()} validation_mode={CosmoValidationMode::OnBlur} buttons={html!(
+                    <CosmoButton label="Register" is_submit={true} />
+                )}>
+                    <CosmoTextBox value={(*username_state).clone()} on_input={on_username_input} label="Username" validators={vec![
+                        CosmoValidator::required("Please enter a username"),
+                        CosmoValidator::min_length(3, "The username needs at least three characters"),
+                        CosmoValidator::custom_async(|value| async move {
+                            if value == "admin" {
+                                Err(AttrValue::from("The username is already taken"))
+                            } else {
+                                Ok(())
+                            }
+                        }),
+                    ]} />
+                    <CosmoTextBox input_type={CosmoTextBoxType::Email} value={(*email_state).clone()} on_input={on_email_input} label="Email" validators={vec![
+                        CosmoValidator::required("Please enter an email"),
+                        CosmoValidator::pattern(r"[^@\s]+@[^@\s]+\.[^@\s]+", "Please enter a valid email"),
+                    ]} />
+                    <CosmoNumberBox value={*age_state} on_input={on_age_input} label="Age" validators={vec![
+                        CosmoValidator::range(Some(18.0), None, "You need to be at least 18 years old"),
+                    ]} />
+                    <CosmoCheckbox checked={*terms_state} on_check={on_terms_check} label="I accept the terms" validators={vec![
+                        CosmoValidator::required("Please accept the terms"),
+                    ]} />
+                </CosmoForm>
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"<CosmoForm on_submit={on_form_submit} validation_mode={CosmoValidationMode::OnBlur} buttons={html!(
+    <CosmoButton label="Register" is_submit={true} />
+)}>
+    <CosmoTextBox value={(*username_state).clone()} on_input={on_username_input} label="Username" validators={vec![
+        CosmoValidator::required("Please enter a username"),
+        CosmoValidator::min_length(3, "The username needs at least three characters"),
+        CosmoValidator::custom_async(|value| async move {
+            if value == "admin" {
+                Err(AttrValue::from("The username is already taken"))
+            } else {
+                Ok(())
+            }
+        }),
+    ]} />
+    <CosmoTextBox input_type={CosmoTextBoxType::Email} value={(*email_state).clone()} on_input={on_email_input} label="Email" validators={vec![
+        CosmoValidator::required("Please enter an email"),
+        CosmoValidator::pattern(r"[^@\s]+@[^@\s]+\.[^@\s]+", "Please enter a valid email"),
+    ]} />
+    <CosmoNumberBox value={*age_state} on_input={on_age_input} label="Age" validators={vec![
+        CosmoValidator::range(Some(18.0), None, "You need to be at least 18 years old"),
+    ]} />
+    <CosmoCheckbox checked={*terms_state} on_check={on_terms_check} label="I accept the terms" validators={vec![
+        CosmoValidator::required("Please accept the terms"),
+    ]} />
+</CosmoForm>"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="Typed forms" />
+            <CosmoParagraph>
+                {"Instead of wiring a state and a callback for every field, you can bind the fields of a struct to the inputs with the use_cosmo_form hook. The hook tracks which fields are dirty or touched and can reset the form to its initial value. When you pass the form to CosmoForm, the on_submit callback receives the whole struct. Enable the derive feature to derive the field bindings with CosmoFormModel."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoForm<Contact> form={contact_form.clone()} on_submit={on_contact_submit} buttons={html!(
+                    <>
+                        <CosmoButton label="Reset" on_click={on_contact_reset} />
+                        <CosmoButton label="Save contact" is_submit={true} />
+                    </>
+                )}>
+                    <CosmoTextBox value={contact_form.get(contact_fields.name)} on_input={contact_form.on_input(contact_fields.name)} label="Name" />
+                    <CosmoNumberBox value={contact_form.get(contact_fields.age)} on_input={contact_form.on_input(contact_fields.age)} label="Age" />
+                    <CosmoDatePicker value={contact_form.get(contact_fields.birthday)} on_input={contact_form.on_input(contact_fields.birthday)} label="Birthday" />
+                    <CosmoCheckbox checked={contact_form.get(contact_fields.newsletter)} on_check={contact_form.on_input(contact_fields.newsletter)} label="Subscribe to newsletter" />
+                </CosmoForm<Contact>>
+                <CosmoParagraph>
+                    {if contact_form.is_dirty() { "The form has unsaved changes" } else { "The form is unchanged" }}
+                </CosmoParagraph>
+                if let Some(contact) = (*contact_state).clone() {
+                    <CosmoParagraph>{contact}</CosmoParagraph>
+                }
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"#[derive(PartialEq, Clone, Default, CosmoFormModel)]
+struct Contact {
+    name: AttrValue,
+    age: i64,
+    birthday: NaiveDate,
+    newsletter: bool,
+}
+
+let contact_form = use_cosmo_form::<Contact>();
+let contact_fields = contact_form.fields();
+
+let on_contact_reset = use_callback(contact_form.clone(), |_, form| form.reset());
+let on_contact_submit = use_callback((), |contact: Contact, _| {
+    log::info!("{} is {} years old", contact.name, contact.age);
+});
+
+html!(
+    <CosmoForm<Contact> form={contact_form.clone()} on_submit={on_contact_submit} buttons={html!(
+        <>
+            <CosmoButton label="Reset" on_click={on_contact_reset} />
+            <CosmoButton label="Save contact" is_submit={true} />
+        </>
+    )}>
+        <CosmoTextBox value={contact_form.get(contact_fields.name)} on_input={contact_form.on_input(contact_fields.name)} label="Name" />
+        <CosmoNumberBox value={contact_form.get(contact_fields.age)} on_input={contact_form.on_input(contact_fields.age)} label="Age" />
+        <CosmoDatePicker value={contact_form.get(contact_fields.birthday)} on_input={contact_form.on_input(contact_fields.birthday)} label="Birthday" />
+        <CosmoCheckbox checked={contact_form.get(contact_fields.newsletter)} on_check={contact_form.on_input(contact_fields.newsletter)} label="Subscribe to newsletter" />
+    </CosmoForm<Contact>>
+)"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="File pickers" />
+            <CosmoParagraph>
+                {"The file picker can select multiple files and can be rendered as a drop zone. Selected files are listed with their size and type, images get a preview. Files that don't match the accept pattern or exceed the maximum file size are reported through the on_reject callback."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoInputGroup>
+                    <CosmoFilePicker multiple={true} on_select_files={on_select_files.clone()} label="Multiple files" />
+                    <CosmoFilePicker is_drop_zone={true} multiple={true} accept="image/*" max_file_size={2 * 1024 * 1024} on_select_files={on_select_files} on_reject={on_reject_files} label="Images" />
+                </CosmoInputGroup>
+                <CosmoParagraph>
+                    {format!("Selected files: {}", selected_files_state.iter().map(|name| name.to_string()).collect::<Vec<_>>().join(", "))}
+                </CosmoParagraph>
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"<CosmoInputGroup>
+    <CosmoFilePicker multiple={true} on_select_files={on_select_files.clone()} label="Multiple files" />
+    <CosmoFilePicker is_drop_zone={true} multiple={true} accept="image/*" max_file_size={2 * 1024 * 1024} on_select_files={on_select_files} on_reject={on_reject_files} label="Images" />
+</CosmoInputGroup>"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H3} header="Async options" />
+            <CosmoParagraph>
+                {"The modern select can load its options asynchronously. The loader gets called with the debounced search term and the page to load, while loading a progress ring is shown. When the user scrolls to the end of the options the next page is loaded. The selected items are still passed via the items property. Large option lists are virtualized, only the visible options are rendered."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoInputGroup>
+                    <CosmoModernSelect loader={(*tag_loader).clone()} on_select={on_tag_select} on_deselect={on_tag_deselect} items={tags_state.iter().map(|tag| CosmoModernSelectItem::new(tag.clone(), tag.clone(), true)).collect::<Vec<_>>()} label="Tags" />
+                </CosmoInputGroup>
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"let tag_loader = use_memo((), |_| {
+    CosmoModernSelectLoader::new(|search: AttrValue, page: usize| async move {
+        let tags = (1..=5000)
+            .map(|tag| format!("Tag {tag}"))
+            .filter(|tag| tag.to_lowercase().contains(&search.to_lowercase()))
+            .collect::<Vec<_>>();
+        let items = tags
+            .iter()
+            .skip(page * 50)
+            .take(50)
+            .map(|tag| CosmoModernSelectItem::new(tag.clone(), tag.clone(), false))
+            .collect::<Vec<_>>();
+
+        CosmoModernSelectPage::new(items, tags.len() > (page + 1) * 50)
+    })
+});
+
+html!(
+    <CosmoModernSelect loader={(*tag_loader).clone()} on_select={on_tag_select} on_deselect={on_tag_deselect} items={tags_state.iter().map(|tag| CosmoModernSelectItem::new(tag.clone(), tag.clone(), true)).collect::<Vec<_>>()} label="Tags" />
+)"#}</CosmoDocsCodeSample>
             <CosmoHeader level={CosmoHeaderLevel::H2} header="Buttons" />
             <CosmoParagraph>
                 {"Cosmo provides two different button types and five different button styles."}
@@ -7,6 +7,31 @@ use crate::ui::CosmoDocsCodeSample;
 
 #[function_component(Message)]
 pub fn message() -> Html {
+    let toast = use_cosmo_toast();
+
+    let on_information = use_callback(toast.clone(), |_, toast| {
+        toast.information("I am an information toast, I disappear after five seconds");
+    });
+    let on_positive = use_callback(toast.clone(), |_, toast| {
+        toast.show(
+            CosmoToast::new(CosmoMessageType::Positive, "The item was saved").with_header("Saved"),
+        );
+    });
+    let on_negative = use_callback(toast.clone(), |_, toast| {
+        let retry_toast = toast.clone();
+        toast.show(
+            CosmoToast::new(CosmoMessageType::Negative, "The item could not be deleted")
+                .with_header("Error")
+                .with_action(
+                    "Retry",
+                    Callback::from(move |_| {
+                        retry_toast.positive("The item was deleted");
+                    }),
+                )
+                .sticky(),
+        );
+    });
+
     html!(
         <>
             <CosmoTitle title="Message" />
@@ -23,6 +48,44 @@ pub fn message() -> Html {
 <CosmoMessage message_type={CosmoMessageType::Warning} header="Warning" message="I am a warning message, keep your eyes open and check before you click" />
 <CosmoMessage message_type={CosmoMessageType::Positive} header="Positive" message="I am a positive message, something worked or is a good thing to do" />
 <CosmoMessage message_type={CosmoMessageType::Negative} header="Negative" message="I am a negative message, something didn't work or is dangerous to do" />"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Toasts" />
+            <CosmoParagraph>
+                {"Toasts are messages that show up in the bottom right corner of the page. The page layout provides the toast service, you can access it with the use_cosmo_toast hook. Toasts disappear after a timeout, hovering them pauses the timeout."}
+            </CosmoParagraph>
+            <CosmoDemo>
+                <CosmoToolbar>
+                    <CosmoToolbarGroup>
+                        <CosmoButton label="Show information toast" on_click={on_information} />
+                        <CosmoButton label="Show positive toast" on_click={on_positive} />
+                        <CosmoButton label="Show sticky toast with action" on_click={on_negative} />
+                    </CosmoToolbarGroup>
+                </CosmoToolbar>
+            </CosmoDemo>
+            <CosmoDocsCodeSample>{r#"let toast = use_cosmo_toast();
+
+let on_information = use_callback(toast.clone(), |_, toast| {
+    toast.information("I am an information toast, I disappear after five seconds");
+});
+let on_positive = use_callback(toast.clone(), |_, toast| {
+    toast.show(
+        CosmoToast::new(CosmoMessageType::Positive, "The item was saved")
+            .with_header("Saved"),
+    );
+});
+let on_negative = use_callback(toast.clone(), |_, toast| {
+    let retry_toast = toast.clone();
+    toast.show(
+        CosmoToast::new(CosmoMessageType::Negative, "The item could not be deleted")
+            .with_header("Error")
+            .with_action(
+                "Retry",
+                Callback::from(move |_| {
+                    retry_toast.positive("The item was deleted");
+                }),
+            )
+            .sticky(),
+    );
+});"#}</CosmoDocsCodeSample>
         </>
     )
 }
@@ -1,3 +1,4 @@
+pub mod data_grid;
 pub mod dialog;
 pub mod html;
 pub mod message;
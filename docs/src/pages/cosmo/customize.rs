@@ -15,6 +15,89 @@ pub fn customize() -> Html {
             <CosmoParagraph>
                 {"For the customized variables to take effect they need to be applied on the body element in your custom CSS file."}
             </CosmoParagraph>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Theme tokens" />
+            <CosmoParagraph>
+                {"The easiest way to brand Cosmo is to pass your own theme tokens to the page layout. The tokens contain the colors for light and dark mode, the font families, the border radius, the spacing scale, the control height and the transition duration. All fields have sensible defaults, so you only need to set the values you want to change. The page layout generates the CSS variables from the tokens."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"let tokens = CosmoThemeTokens {
+    light: CosmoColorTokens {
+        primary: color!(#514b57),
+        ..CosmoColorTokens::light()
+    },
+    dark: CosmoColorTokens {
+        primary: color!(#2b282e),
+        ..CosmoColorTokens::dark()
+    },
+    font_family: AttrValue::from("Lato, sans-serif"),
+    border_radius: AttrValue::from("0"),
+    ..CosmoThemeTokens::default()
+};
+
+html!(
+    <CosmoPageLayout tokens={tokens}>
+        // Your app
+    </CosmoPageLayout>
+)"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Fonts" />
+            <CosmoParagraph>
+                {"By default the page layout loads Albert Sans, Urbanist and Oxygen Mono from the Jinya font CDN. When your app can't reach the CDN or your content security policy forbids third party font origins, you can change the font source. You can load the fonts from your own font server, serve the font files from your app or only use the fonts installed on the system. Copy the fonts folder of the crate into your app assets to self-host the fonts. With the embed-fonts feature the font files are embedded into your app."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoPageLayout font_source={CosmoFontSource::Cdn}>
+<CosmoPageLayout font_source={CosmoFontSource::CustomUrl(AttrValue::from("https://fonts.example.com"))}>
+<CosmoPageLayout font_source={CosmoFontSource::SelfHosted(AttrValue::from("/assets/fonts"))}>
+<CosmoPageLayout font_source={CosmoFontSource::Embedded}>
+<CosmoPageLayout font_source={CosmoFontSource::System}>"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Contrast" />
+            <CosmoParagraph>
+                {"For every accent color Cosmo picks the foreground color with the best contrast and derives a text color that meets the WCAG AA contrast ratio on the page background. They are available as "}<CosmoCode>{"--primary-color-foreground"}</CosmoCode>{" and "}<CosmoCode>{"--primary-color-text"}</CosmoCode>{", the same goes for the other accent colors. In debug builds the page layout warns in the console, when the primary color doesn't allow readable text. The contrast functions are also available in your own code."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"let ratio = contrast_ratio(&color!(#ffffff), &color!(#19324c));
+let is_readable = meets_contrast(&color!(#ffffff), &color!(#19324c), CosmoContrastLevel::Aa);
+let foreground = accessible_foreground(&color!(#19324c), &color!(#ffffff), &color!(#333333));
+let text_color = adjust_for_contrast(&color!(#ffe066), &color!(#ffffff), CosmoContrastLevel::Aa);"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Localization" />
+            <CosmoParagraph>
+                {"The page layout takes a locale that all controls read. It sets the decimal and group separators of number inputs, the date and time display formats, the first day of the week, the text direction and the default labels of built-in buttons and messages. Cosmo Yew ships an English and a German locale. You can start from either and change single fields. Labels passed directly to a control still win over the locale."}
+            </CosmoParagraph>
+            <CosmoParagraph>
+                {"Number and decimal boxes stay native number inputs with spin buttons and arrow key steps as long as the locale uses a point as decimal separator. Thousands grouping is off by default, set grouping on the box to show the group separator of the locale. Grouped boxes and decimal boxes in locales with a comma as decimal separator are rendered as text inputs with a numeric keyboard."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoPageLayout locale={CosmoLocale::german()}>
+    // Your app
+</CosmoPageLayout>
+
+let locale = CosmoLocale {
+    date_format: AttrValue::from("%Y-%m-%d"),
+    labels: CosmoLocaleLabels {
+        clear: AttrValue::from("Zurücksetzen"),
+        ..CosmoLocaleLabels::german()
+    },
+    ..CosmoLocale::german()
+};
+
+// In your own components
+let locale = use_cosmo_locale();
+let price = locale.format_decimal(19.99, 2);"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Right to left" />
+            <CosmoParagraph>
+                {"For Arabic or Hebrew installations set the direction of the locale or pass dir to the page layout. The layout uses logical CSS properties, so the menus, side lists, top and bottom bars, the back button and the modal button bars are mirrored."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoPageLayout dir={CosmoTextDirection::Rtl}>
+    // Your app
+</CosmoPageLayout>"#}</CosmoDocsCodeSample>
+            <CosmoParagraph>
+                {"The CSS variables kept their names, but the ones named left and right are now applied as logical start and end values. For example --list-item-padding-left, --input-padding-left, --button-padding-left and --modal-padding-left set the padding at the start of the line, their right counterparts the padding at the end. --menu-left-touch-width is the width of the colored strip at the start of the main menu, which is on the right in right to left layouts. When you override these variables, think of left as start and right as end."}
+            </CosmoParagraph>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Server side rendering" />
+            <CosmoParagraph>
+                {"With the ssr feature you can render your app on the server. The styles, the title and the theme tokens are collected while rendering and returned as head markup, so the page is styled before the wasm bundle loads. Enable the hydration feature in your client build and hydrate the app with the same component. Modals and toasts are mounted after hydration, since portals can't be rendered on the server."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"// Server
+let page = render_cosmo_app::<App>(()).await;
+let html = format!("<html><head>{}</head><body>{}</body></html>", page.head, page.body);
+
+// Client
+hydrate_cosmo_app::<App>(());"#}</CosmoDocsCodeSample>
             <CosmoHeader level={CosmoHeaderLevel::H2} header="Cosmo variables" />
             <CosmoDocsCodeSample>{r#"--control-border-color: #CCCCCC;            /** The border color for input and button controls **/
 --primary-color: #514B57;                   /** The primary color used for the highlights and accents **/
@@ -1,14 +1,15 @@
-use bounce::helmet::Helmet;
 use yew::prelude::*;
 
 use yew_cosmo::prelude::*;
 
+use crate::ui::CosmoDocsCodeSample;
+
 #[function_component(Theme)]
 pub fn theme() -> Html {
     let light_theme_alert_state = use_state_eq(|| false);
     let dark_theme_alert_state = use_state_eq(|| false);
 
-    let theme_state = use_state_eq(|| CosmoTheme::Auto);
+    let theme = use_cosmo_theme();
 
     let on_open_light_theme_dialog =
         use_callback(light_theme_alert_state.clone(), |_: (), state| {
@@ -17,26 +18,22 @@ pub fn theme() -> Html {
     let on_open_dark_theme_dialog = use_callback(dark_theme_alert_state.clone(), |_: (), state| {
         state.set(true)
     });
-    let on_toggle_theme =
-        use_callback(
-            theme_state.clone(),
-            |value: Option<AttrValue>, state| match value {
-                None => state.set(CosmoTheme::Auto),
-                Some(val) => {
-                    if val == "dark" {
-                        state.set(CosmoTheme::Dark)
-                    } else {
-                        state.set(CosmoTheme::Light)
-                    }
+    let on_toggle_theme = use_callback(
+        theme.clone(),
+        |value: Option<AttrValue>, theme| match value {
+            None => theme.set_theme(CosmoTheme::Auto),
+            Some(val) => {
+                if val == "dark" {
+                    theme.set_theme(CosmoTheme::Dark)
+                } else {
+                    theme.set_theme(CosmoTheme::Light)
                 }
-            },
-        );
+            }
+        },
+    );
 
     html!(
         <>
-            <Helmet>
-                <body class={(*theme_state).clone()} />
-            </Helmet>
             <CosmoTitle title="Supported themes" />
             <CosmoParagraph>
                 {"Since a few versions, browser support an automatic switch between dark and light theme. This switch is based on the theme setting in your operating system. There might be situations, where you want to force light or dark theme. For that purpose Cosmo supports a forced theme. Check below to see which classes you need to apply."}
@@ -46,12 +43,25 @@ pub fn theme() -> Html {
                 {"To force the light theme apply the "}<CosmoCode>{"is--light"}</CosmoCode>{" to the HTML tag of your document. On the other hand, you need to apply the "}<CosmoCode>{"is--dark"}</CosmoCode>{" class to the HTML tag to force the dark theme. "}
             </CosmoParagraph>
             <CosmoParagraph>
-                <CosmoDropdown value={match (*theme_state).clone() {
+                <CosmoDropdown value={match theme.theme() {
                     CosmoTheme::Auto => None,
                     CosmoTheme::Dark => Some("dark"),
                     CosmoTheme::Light => Some("light"),
                 }} label="Toggle theme" on_select={on_toggle_theme} items={vec![(None, AttrValue::from("Automatic")), (Some(AttrValue::from("light")), AttrValue::from("Light theme")), (Some(AttrValue::from("dark")), AttrValue::from("Dark theme"))]} />
             </CosmoParagraph>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Switch the theme at runtime" />
+            <CosmoParagraph>
+                {"The page layout provides a theme context, which you can access with the "}<CosmoCode>{"use_cosmo_theme"}</CosmoCode>{" hook. The selected theme is stored in the local storage and restored on the next visit. In automatic mode the theme follows the color scheme of the operating system, even when it changes while the page is open. Modals and toasts pick up the selected theme automatically."}
+            </CosmoParagraph>
+            <CosmoParagraph>
+                {format!("The current theme resolves to the {} theme.", if theme.is_dark() { "dark" } else { "light" })}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"let theme = use_cosmo_theme();
+let on_dark = use_callback(theme.clone(), |_: (), theme| theme.set_theme(CosmoTheme::Dark));
+
+html!(
+    <CosmoButton label="Dark theme" on_click={on_dark} />
+)"#}</CosmoDocsCodeSample>
             <CosmoHeader level={CosmoHeaderLevel::H2} header="Apply theme only to dialogs" />
             <CosmoParagraph>
                 {"It is also possible to force a theme on modal dialogs, by applying setting the theme prop to either Light or Dark."}
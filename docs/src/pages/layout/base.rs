@@ -41,12 +41,12 @@ pub fn base_layout() -> Html {
             </CosmoTopBar>
             <CosmoMenuBar>
                 <CosmoMainMenu>
-                    <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::CosmoRoot} label="Cosmo" is_active={true} />
+                    <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::CosmoRoot} label="Cosmo" />
                     <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::ControlsRoot} label="Controls" />
                     <CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::LayoutRoot} label="Layout" />
                 </CosmoMainMenu>
                 <CosmoSubMenuBar>
-                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::About} label="About Cosmo" is_active={true} />
+                    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::About} label="About Cosmo" />
                     <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Typography} label="Typography" />
                     <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Theme} label="Theme" />
                     <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::Customize} label="Customize" />
@@ -67,6 +67,72 @@ pub fn base_layout() -> Html {
         </CosmoPageLayout>
     </BounceRoot>
 </BrowserRouter>"#}</CosmoDocsCodeSample>
+            <CosmoParagraph>
+                {"Menu links derive their active state from the current location. Main menu links are active when the location starts with their route, sub menu links only when it matches their route exactly. You can change that with the "}<CosmoCode>{"route_match"}</CosmoCode>{" property, either to "}<CosmoCode>{"CosmoRouteMatch::Exact"}</CosmoCode>{", "}<CosmoCode>{"CosmoRouteMatch::Prefix"}</CosmoCode>{" or a custom predicate. If you need full control, "}<CosmoCode>{"is_active"}</CosmoCode>{" still overrides the matching."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoMainMenuItemLink<DocsRoute> to={DocsRoute::CosmoRoot} label="Cosmo" route_match={CosmoRouteMatch::custom(|route: &DocsRoute| matches!(route, DocsRoute::Home | DocsRoute::CosmoRoot | DocsRoute::Cosmo))} />
+    <CosmoSubMenuItemLink<CosmoRoute> to={CosmoRoute::About} label="About Cosmo" is_active={true} />"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Menu model" />
+            <CosmoParagraph>
+                {"Instead of writing the menu by hand you can describe it as a tree of "}<CosmoCode>{"CosmoMenuItem"}</CosmoCode>{" values. "}<CosmoCode>{"CosmoMenu"}</CosmoCode>{" renders the main menu and the sub menu of the active branch, "}<CosmoCode>{"CosmoMenuBreadcrumbs"}</CosmoCode>{" renders the trail to the current page. Both use the same definition, so they never get out of sync."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"let items = vec![
+    CosmoMenuItem::new("Gallery", Route::Galleries)
+        .with_route_match(CosmoRouteMatch::Prefix)
+        .with_children(vec![
+            CosmoMenuItem::new("Overview", Route::Galleries),
+            CosmoMenuItem::new("Files", Route::Files),
+        ]),
+    CosmoMenuItem::new("Settings", Route::Settings),
+];
+
+html!(
+    <CosmoMenuBar>
+        <CosmoMenu<Route> items={items.clone()} />
+    </CosmoMenuBar>
+    <CosmoPageBody>
+        <CosmoMenuBreadcrumbs<Route> items={items} />
+    </CosmoPageBody>
+)"#}</CosmoDocsCodeSample>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Breadcrumbs" />
+            <CosmoParagraph>
+                {"Deep pages can show where they are with breadcrumbs. Place them at the top of the page body, right under the sub menu. The last item is marked as the current page. Link items can list their siblings in a small dropdown, and long paths collapse into an ellipsis once they have more than "}<CosmoCode>{"max_items"}</CosmoCode>{" items."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoBreadcrumbs max_items={4}>
+    <CosmoBreadcrumbItemLink<Route> label="Gallery" to={Route::Galleries} />
+    <CosmoBreadcrumbItemLink<Route> label="Holidays" to={Route::Folder { id: 1 }} siblings={vec![CosmoBreadcrumbSibling::new("Family", Route::Folder { id: 2 })]} />
+    <CosmoBreadcrumbItem label="Beach.jpg" />
+</CosmoBreadcrumbs>"#}</CosmoDocsCodeSample>
+            <CosmoParagraph>
+                {"When you use the menu model, "}<CosmoCode>{"CosmoMenuBreadcrumbs"}</CosmoCode>{" renders the same component for the active trail. Set "}<CosmoCode>{"show_siblings"}</CosmoCode>{" to offer the other entries of each level."}
+            </CosmoParagraph>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Back button" />
+            <CosmoParagraph>
+                {"The back button in the menu bar follows the router history. It is enabled once the user navigated inside your app and goes back one step when clicked. A page can replace that behavior, for example to lead from a detail view back to its list. The override is removed again when the page unmounts."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"#[function_component(FileDetails)]
+fn file_details() -> Html {
+    use_cosmo_back_to(Route::Files, Some(AttrValue::from("Back to files")));
+
+    html!(
+        // Your page
+    )
+}"#}</CosmoDocsCodeSample>
+            <CosmoParagraph>
+                {"If you need more control, "}<CosmoCode>{"use_cosmo_back_target"}</CosmoCode>{" takes any callback and "}<CosmoCode>{"use_cosmo_back"}</CosmoCode>{" gives you access to the current back state."}
+            </CosmoParagraph>
+            <CosmoHeader level={CosmoHeaderLevel::H2} header="Responsive layout" />
+            <CosmoParagraph>
+                {"The page layout adapts to the width of the viewport. Below the medium breakpoint the side spacing shrinks. Below the compact breakpoint the main and sub menu collapse into a drawer, the side list shows either the items or the selected item with a back button, and the top and bottom bar reflow. The breakpoints are set in pixels on the page layout. Your own components can read the current viewport with the "}<CosmoCode>{"use_cosmo_viewport"}</CosmoCode>{" hook."}
+            </CosmoParagraph>
+            <CosmoDocsCodeSample>{r#"<CosmoPageLayout breakpoints={CosmoBreakpoints { compact: 640, medium: 1024 }}>
+    // Your app
+</CosmoPageLayout>
+
+let viewport = use_cosmo_viewport();
+if viewport.is_compact() {
+    // Render a compact variant
+}"#}</CosmoDocsCodeSample>
         </>
     )
 }
@@ -45,6 +45,8 @@ pub enum ControlsRoute {
     Toolbar,
     #[at("/controls/dialog")]
     Dialog,
+    #[at("/controls/data-grid")]
+    DataGrid,
 }
 
 #[derive(Routable, Clone, PartialEq)]
@@ -0,0 +1,271 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+#[cfg(feature = "with-yew-router")]
+use yew_router::prelude::*;
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoBackTarget {
+    pub label: Option<AttrValue>,
+    pub on_back: Callback<()>,
+}
+
+impl CosmoBackTarget {
+    pub fn new(on_back: Callback<()>) -> Self {
+        Self {
+            label: None,
+            on_back,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<AttrValue>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+struct CosmoBackState {
+    #[cfg(feature = "with-yew-router")]
+    visited: Vec<Option<u32>>,
+    #[cfg(feature = "with-yew-router")]
+    forward: Vec<Option<u32>>,
+    #[cfg(feature = "with-yew-router")]
+    history_length: u32,
+    target: Option<CosmoBackTarget>,
+}
+
+enum CosmoBackReducerAction {
+    #[cfg(feature = "with-yew-router")]
+    Visit(Option<u32>, u32),
+    SetTarget(Option<CosmoBackTarget>),
+}
+
+impl Reducible for CosmoBackState {
+    type Action = CosmoBackReducerAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            #[cfg(feature = "with-yew-router")]
+            CosmoBackReducerAction::Visit(id, history_length) => {
+                let visited_idx = if id.is_none() {
+                    Some(0)
+                } else {
+                    state.visited.iter().position(|visited| *visited == id)
+                };
+                if state.visited.is_empty() {
+                    state.visited.push(id);
+                } else if let Some(idx) = visited_idx {
+                    let mut forward = state.visited.split_off(idx + 1);
+                    forward.append(&mut state.forward);
+                    state.forward = forward;
+                } else if let Some(idx) = state.forward.iter().position(|forward| *forward == id) {
+                    let forward = state.forward.split_off(idx + 1);
+                    state.visited.append(&mut state.forward);
+                    state.forward = forward;
+                } else if history_length == state.history_length && state.forward.len() != 1 {
+                    state.visited.pop();
+                    state.visited.push(id);
+                } else {
+                    state.visited.push(id);
+                    state.forward.clear();
+                }
+                state.history_length = history_length;
+            }
+            CosmoBackReducerAction::SetTarget(target) => state.target = target,
+        }
+
+        state.into()
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoBackHandle {
+    state: UseReducerHandle<CosmoBackState>,
+    #[cfg(feature = "with-yew-router")]
+    navigator: Option<Navigator>,
+}
+
+impl CosmoBackHandle {
+    pub fn can_go_back(&self) -> bool {
+        self.state.target.is_some() || self.has_history()
+    }
+
+    pub fn label(&self) -> Option<AttrValue> {
+        self.state
+            .target
+            .as_ref()
+            .and_then(|target| target.label.clone())
+    }
+
+    pub fn go_back(&self) {
+        if let Some(target) = &self.state.target {
+            target.on_back.emit(());
+        } else if self.has_history() {
+            #[cfg(feature = "with-yew-router")]
+            if let Some(navigator) = &self.navigator {
+                navigator.back();
+            }
+        }
+    }
+
+    #[cfg(feature = "with-yew-router")]
+    fn has_history(&self) -> bool {
+        self.navigator.is_some() && self.state.visited.len() > 1
+    }
+
+    #[cfg(not(feature = "with-yew-router"))]
+    fn has_history(&self) -> bool {
+        false
+    }
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoBackDispatcher {
+    dispatcher: UseReducerDispatcher<CosmoBackState>,
+}
+
+#[hook]
+pub fn use_cosmo_back() -> Option<CosmoBackHandle> {
+    use_context::<CosmoBackHandle>()
+}
+
+#[hook]
+pub fn use_cosmo_back_target(target: CosmoBackTarget) {
+    let dispatcher = use_context::<CosmoBackDispatcher>();
+
+    use_effect_with((dispatcher, target), |(dispatcher, target)| {
+        let dispatcher = dispatcher.clone();
+        if let Some(dispatcher) = &dispatcher {
+            dispatcher
+                .dispatcher
+                .dispatch(CosmoBackReducerAction::SetTarget(Some(target.clone())));
+        }
+
+        move || {
+            if let Some(dispatcher) = dispatcher {
+                dispatcher
+                    .dispatcher
+                    .dispatch(CosmoBackReducerAction::SetTarget(None));
+            }
+        }
+    });
+}
+
+#[cfg(feature = "with-yew-router")]
+#[hook]
+pub fn use_cosmo_back_to<Route>(to: Route, label: Option<AttrValue>)
+where
+    Route: Routable + 'static,
+{
+    let navigator = use_navigator();
+    let on_back = use_callback((navigator, to), |_: (), (navigator, to)| {
+        if let Some(navigator) = navigator {
+            navigator.push(to);
+        }
+    });
+
+    use_cosmo_back_target(CosmoBackTarget { label, on_back });
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoBackProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[function_component(CosmoBackProvider)]
+pub fn back_provider(props: &CosmoBackProviderProps) -> Html {
+    let state = use_reducer_eq(CosmoBackState::default);
+    let dispatcher = CosmoBackDispatcher {
+        dispatcher: state.dispatcher(),
+    };
+
+    #[cfg(feature = "with-yew-router")]
+    let navigator = use_navigator();
+    #[cfg(feature = "with-yew-router")]
+    {
+        let location = use_location();
+        let entry = location.map(|location| {
+            (
+                location.id(),
+                AttrValue::from(format!("{}{}", location.path(), location.query_str())),
+            )
+        });
+        let dispatcher = state.dispatcher();
+        use_effect_with(entry, move |entry| {
+            if let Some((id, _)) = entry {
+                let history_length = gloo_utils::window()
+                    .history()
+                    .and_then(|history| history.length())
+                    .unwrap_or_default();
+                dispatcher.dispatch(CosmoBackReducerAction::Visit(*id, history_length));
+            }
+        });
+    }
+
+    let handle = CosmoBackHandle {
+        state,
+        #[cfg(feature = "with-yew-router")]
+        navigator,
+    };
+
+    html!(
+        <ContextProvider<CosmoBackDispatcher> context={dispatcher}>
+            <ContextProvider<CosmoBackHandle> context={handle}>
+                {for props.children.iter()}
+            </ContextProvider<CosmoBackHandle>>
+        </ContextProvider<CosmoBackDispatcher>>
+    )
+}
+
+#[cfg(all(test, feature = "with-yew-router"))]
+mod tests {
+    use super::*;
+
+    fn visit(
+        state: Rc<CosmoBackState>,
+        id: Option<u32>,
+        history_length: u32,
+    ) -> Rc<CosmoBackState> {
+        state.reduce(CosmoBackReducerAction::Visit(id, history_length))
+    }
+
+    #[test]
+    fn pushed_entries_are_tracked_by_id() {
+        let state = Rc::new(CosmoBackState::default());
+        let state = visit(state, None, 1);
+        let state = visit(state, Some(1), 2);
+        let state = visit(state, Some(2), 3);
+        assert_eq!(state.visited, vec![None, Some(1), Some(2)]);
+
+        let state = visit(state, Some(1), 3);
+        assert_eq!(state.visited, vec![None, Some(1)]);
+        assert_eq!(state.forward, vec![Some(2)]);
+
+        let state = visit(state, Some(2), 3);
+        assert_eq!(state.visited, vec![None, Some(1), Some(2)]);
+        assert!(state.forward.is_empty());
+
+        let state = visit(state, None, 3);
+        assert_eq!(state.visited, vec![None]);
+        assert_eq!(state.forward, vec![Some(1), Some(2)]);
+
+        let state = visit(state, Some(3), 2);
+        assert_eq!(state.visited, vec![None, Some(3)]);
+        assert!(state.forward.is_empty());
+    }
+
+    #[test]
+    fn replaced_entries_are_dropped() {
+        let state = Rc::new(CosmoBackState::default());
+        let state = visit(state, Some(7), 4);
+        let state = visit(state, Some(8), 4);
+        assert_eq!(state.visited, vec![Some(8)]);
+
+        let state = visit(state, Some(9), 5);
+        let state = visit(state, Some(10), 5);
+        assert_eq!(state.visited, vec![Some(8), Some(10)]);
+    }
+}
@@ -0,0 +1,312 @@
+use stylist::yew::{styled_component, use_style};
+#[cfg(feature = "with-yew-router")]
+use web_sys::wasm_bindgen::JsCast;
+#[cfg(feature = "with-yew-router")]
+use web_sys::{Element, Node};
+use yew::prelude::*;
+#[cfg(feature = "with-yew-router")]
+use yew_router::prelude::*;
+
+use crate::locale::use_cosmo_locale;
+
+#[derive(PartialEq, Clone, Copy)]
+struct CosmoBreadcrumbPosition {
+    is_current: bool,
+}
+
+#[hook]
+fn use_breadcrumb_position() -> CosmoBreadcrumbPosition {
+    use_context::<CosmoBreadcrumbPosition>()
+        .unwrap_or(CosmoBreadcrumbPosition { is_current: false })
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoBreadcrumbsProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or(AttrValue::from("/"))]
+    pub separator: AttrValue,
+    #[prop_or_default]
+    pub max_items: Option<usize>,
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    #[prop_or_default]
+    pub expand_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoBreadcrumbs)]
+pub fn breadcrumbs(props: &CosmoBreadcrumbsProps) -> Html {
+    let breadcrumbs_style = use_style!(
+        r#"
+display: flex;
+flex-flow: row wrap;
+align-items: center;
+gap: 0.5rem;
+list-style: none;
+margin: 0;
+padding: 0;
+font-family: var(--font-family-menu);
+font-weight: var(--font-weight-menu);
+font-size: var(--font-size-sub-menu);
+text-transform: lowercase;
+color: var(--menu-text-color);
+
+li {
+	display: inline-flex;
+	align-items: center;
+	position: relative;
+}
+
+a,
+button {
+	color: inherit;
+	font: inherit;
+	text-transform: inherit;
+	text-decoration: none;
+	background: none;
+	border: 0;
+	padding: 0;
+	cursor: pointer;
+}
+
+a:hover,
+a:focus-visible,
+button:hover,
+button:focus-visible,
+[aria-current='page'] {
+	color: var(--menu-text-selected-color);
+}
+    "#
+    );
+
+    let locale = use_cosmo_locale();
+    let label = props
+        .label
+        .clone()
+        .unwrap_or(locale.labels.breadcrumbs.clone());
+    let expand_label = props
+        .expand_label
+        .clone()
+        .unwrap_or(locale.labels.show_full_path.clone());
+
+    let expanded_state = use_state_eq(|| false);
+    let on_expand = use_callback(expanded_state.clone(), |_: MouseEvent, expanded_state| {
+        expanded_state.set(true)
+    });
+
+    let count = props.children.len();
+    let is_collapsed = !*expanded_state
+        && props
+            .max_items
+            .map(|max_items| count > max_items.max(2))
+            .unwrap_or(false);
+    let hidden = if is_collapsed {
+        1..(count + 1 - props.max_items.unwrap_or_default().max(2))
+    } else {
+        0..0
+    };
+
+    let separator = html!(
+        <li aria-hidden="true">{props.separator.clone()}</li>
+    );
+
+    html!(
+        <nav aria-label={label}>
+            <ol class={breadcrumbs_style}>
+                {for props.children.iter().enumerate().filter(|(idx, _)| !hidden.contains(idx)).map(|(idx, item)| html!(
+                    <>
+                        if idx > 0 {
+                            {separator.clone()}
+                        }
+                        if is_collapsed && idx == hidden.end {
+                            <li>
+                                <button type="button" aria-label={expand_label.clone()} title={expand_label.clone()} onclick={on_expand.clone()}>{"…"}</button>
+                            </li>
+                            {separator.clone()}
+                        }
+                        <li>
+                            <ContextProvider<CosmoBreadcrumbPosition> context={CosmoBreadcrumbPosition { is_current: idx + 1 == count }}>
+                                {item}
+                            </ContextProvider<CosmoBreadcrumbPosition>>
+                        </li>
+                    </>
+                ))}
+            </ol>
+        </nav>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoBreadcrumbItemProps {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub on_click: Option<Callback<()>>,
+}
+
+#[function_component(CosmoBreadcrumbItem)]
+pub fn breadcrumb_item(props: &CosmoBreadcrumbItemProps) -> Html {
+    let position = use_breadcrumb_position();
+    let on_click = use_callback(props.on_click.clone(), |_: MouseEvent, on_click| {
+        if let Some(on_click) = on_click {
+            on_click.emit(());
+        }
+    });
+
+    if position.is_current {
+        html!(
+            <span aria-current="page">{props.label.clone()}</span>
+        )
+    } else if props.on_click.is_some() {
+        html!(
+            <button type="button" onclick={on_click}>{props.label.clone()}</button>
+        )
+    } else {
+        html!(
+            <span>{props.label.clone()}</span>
+        )
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+#[derive(PartialEq, Clone)]
+pub struct CosmoBreadcrumbSibling<Route>
+where
+    Route: Routable + 'static,
+{
+    pub label: AttrValue,
+    pub to: Route,
+}
+
+#[cfg(feature = "with-yew-router")]
+impl<Route> CosmoBreadcrumbSibling<Route>
+where
+    Route: Routable + 'static,
+{
+    pub fn new(label: impl Into<AttrValue>, to: Route) -> Self {
+        Self {
+            label: label.into(),
+            to,
+        }
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoBreadcrumbItemLinkProps<Route>
+where
+    Route: Routable + 'static,
+{
+    pub label: AttrValue,
+    pub to: Route,
+    #[prop_or_default]
+    pub siblings: Vec<CosmoBreadcrumbSibling<Route>>,
+    #[prop_or_default]
+    pub siblings_label: Option<AttrValue>,
+}
+
+#[cfg(feature = "with-yew-router")]
+#[styled_component(CosmoBreadcrumbItemLink)]
+pub fn breadcrumb_item_link<Route>(props: &CosmoBreadcrumbItemLinkProps<Route>) -> Html
+where
+    Route: Routable + 'static,
+{
+    let toggle_style = use_style!(
+        r#"
+margin-inline-start: 0.25rem;
+height: 1em;
+width: 1em;
+position: relative;
+
+&::after {
+	content: '';
+	position: absolute;
+	top: 35%;
+	left: 30%;
+	width: 0.3em;
+	height: 0.3em;
+	border-right: 1px solid currentColor;
+	border-bottom: 1px solid currentColor;
+	transform: rotate(45deg);
+}
+    "#
+    );
+    let flyout_style = use_style!(
+        r#"
+position: absolute;
+top: 100%;
+inset-inline-start: 0;
+z-index: 100;
+min-width: 100%;
+margin: 0.25rem 0 0;
+padding: 0.25rem 0;
+list-style: none;
+background: var(--white);
+border: 1px solid var(--control-border-color);
+font-size: var(--font-size-top-menu);
+
+a {
+	display: block;
+	padding: 0.25rem 0.5rem;
+	white-space: nowrap;
+}
+    "#
+    );
+
+    let position = use_breadcrumb_position();
+    let locale = use_cosmo_locale();
+    let siblings_label = props
+        .siblings_label
+        .clone()
+        .unwrap_or(locale.labels.show_siblings.clone());
+    let flyout_open_state = use_state_eq(|| false);
+    let container_ref = use_node_ref();
+
+    let on_toggle = use_callback(flyout_open_state.clone(), |_: MouseEvent, state| {
+        state.set(!**state)
+    });
+    let on_close = use_callback(flyout_open_state.clone(), |_: MouseEvent, state| {
+        state.set(false)
+    });
+    let on_keydown = use_callback(flyout_open_state.clone(), |evt: KeyboardEvent, state| {
+        if evt.key() == "Escape" {
+            state.set(false);
+        }
+    });
+    let on_focusout = use_callback(
+        (flyout_open_state.clone(), container_ref.clone()),
+        |evt: FocusEvent, (state, container_ref)| {
+            let target = evt
+                .related_target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let is_inside = container_ref
+                .cast::<Element>()
+                .map(|container| container.contains(target.as_ref()))
+                .unwrap_or(false);
+            if !is_inside {
+                state.set(false);
+            }
+        },
+    );
+
+    html!(
+        <span ref={container_ref} onkeydown={on_keydown} onfocusout={on_focusout}>
+            if position.is_current {
+                <span aria-current="page">{props.label.clone()}</span>
+            } else {
+                <Link<Route> to={props.to.clone()}>{props.label.clone()}</Link<Route>>
+            }
+            if !props.siblings.is_empty() {
+                <button class={toggle_style} type="button" aria-label={siblings_label.clone()} title={siblings_label} aria-haspopup="true" aria-expanded={flyout_open_state.to_string()} onclick={on_toggle}></button>
+                if *flyout_open_state {
+                    <ul class={flyout_style} onclick={on_close}>
+                        {for props.siblings.iter().map(|sibling| html!(
+                            <li>
+                                <Link<Route> to={sibling.to.clone()}>{sibling.label.clone()}</Link<Route>>
+                            </li>
+                        ))}
+                    </ul>
+                }
+            }
+        </span>
+    )
+}
@@ -2,17 +2,19 @@ use stylist::yew::{styled_component, use_style};
 use stylist::Style;
 use yew::prelude::*;
 #[cfg(feature = "with-yew-router")]
-use yew_router::history::{BrowserHistory, History};
-#[cfg(feature = "with-yew-router")]
 use yew_router::prelude::*;
 
+use crate::back::use_cosmo_back;
+use crate::locale::use_cosmo_locale;
+
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoBackButtonProps {
-    #[cfg(not(feature = "with-yew-router"))]
-    pub on_click: Callback<()>,
-    #[cfg(not(feature = "with-yew-router"))]
     #[prop_or_default]
-    pub is_enabled: bool,
+    pub on_click: Option<Callback<()>>,
+    #[prop_or_default]
+    pub is_enabled: Option<bool>,
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
 }
 
 #[derive(PartialEq, Clone, Default)]
@@ -27,7 +29,7 @@ pub enum CosmoButtonType {
 }
 
 #[styled_component(CosmoBackButton)]
-pub fn back_button(_props: &CosmoBackButtonProps) -> Html {
+pub fn back_button(props: &CosmoBackButtonProps) -> Html {
     let back_button_style = use_style!(
         r#"
 grid-column: backbutton;
@@ -66,10 +68,16 @@ cursor: pointer;
 	transform: translateY(-50%) translateX(-50%);
 }
 
-&:hover {
+&:hover,
+&:focus-visible {
     border-color: var(--primary-color);
 }
 
+&:focus-visible {
+    outline: var(--back-button-border-width) solid var(--primary-color);
+    outline-offset: var(--back-button-border-width);
+}
+
 &:disabled {
     border-color: var(--control-border-color);
     cursor: not-allowed;
@@ -82,39 +90,45 @@ cursor: pointer;
 &:disabled::before {
     border-color: var(--control-border-color);
 }
+
+&:dir(rtl) {
+    transform: scaleX(-1);
+}
     "#
     );
 
-    #[cfg(feature = "with-yew-router")]
-    let navigator = use_navigator();
-
-    #[cfg(feature = "with-yew-router")]
-    let disabled_state =
-        use_state_eq(|| navigator.is_none() || BrowserHistory::default().is_empty());
+    let back = use_cosmo_back();
+    let locale = use_cosmo_locale();
+    let default_label = props.label.clone().unwrap_or(locale.labels.back.clone());
+    let is_enabled = props.is_enabled.unwrap_or_else(|| {
+        props.on_click.is_some()
+            || back
+                .as_ref()
+                .map(|back| back.can_go_back())
+                .unwrap_or(false)
+    });
+    let label = if props.on_click.is_some() {
+        default_label
+    } else {
+        back.as_ref()
+            .and_then(|back| back.label())
+            .unwrap_or(default_label)
+    };
 
-    #[cfg(feature = "with-yew-router")]
     let on_click = use_callback(
-        (navigator.clone(), disabled_state.clone()),
-        |_: MouseEvent, (navigator, disabled_state)| {
-            if let Some(navigator) = navigator {
-                navigator.back();
-                disabled_state.set(BrowserHistory::default().is_empty());
+        (props.on_click.clone(), back),
+        |_: MouseEvent, (on_click, back)| {
+            if let Some(on_click) = on_click {
+                on_click.emit(());
+            } else if let Some(back) = back {
+                back.go_back();
             }
         },
     );
-    #[cfg(not(feature = "with-yew-router"))]
-    let on_click = use_callback(_props.on_click.clone(), |_: MouseEvent, on_click| {
-        on_click.emit(())
-    });
 
-    #[cfg(feature = "with-yew-router")]
-    return html!(
-        <button onclick={on_click} class={back_button_style} type="button" disabled={*disabled_state}></button>
-    );
-    #[cfg(not(feature = "with-yew-router"))]
-    return html!(
-        <button onclick={on_click} class={back_button_style} type="button" disabled={!_props.is_enabled}></button>
-    );
+    html!(
+        <button onclick={on_click} class={back_button_style} type="button" disabled={!is_enabled} aria-label={label.clone()} title={label}></button>
+    )
 }
 
 #[hook]
@@ -123,48 +137,48 @@ fn use_cosmo_button_style(
     is_circle: bool,
     button_type: CosmoButtonType,
 ) -> Classes {
-    let (color, background, border_color, hover_color, active_color) = match button_type {
+    let (color, background, border_color, hover_shade, active_shade) = match button_type {
         CosmoButtonType::Default => (
             "var(--black)",
             "var(--white)",
             "var(--control-border-color)",
-            "var(--control-border-color-dark)",
-            "var(--control-border-color-darker)",
+            "control-border-color-dark",
+            "control-border-color-darker",
         ),
         CosmoButtonType::Primary => (
-            "var(--white)",
+            "var(--primary-color-foreground)",
             "var(--primary-color)",
             "var(--primary-color)",
-            "var(--primary-color-dark)",
-            "var(--primary-color-darker)",
+            "primary-color-dark",
+            "primary-color-darker",
         ),
         CosmoButtonType::Positive => (
-            "var(--white)",
+            "var(--positive-color-foreground)",
             "var(--positive-color)",
             "var(--positive-color)",
-            "var(--positive-color-dark)",
-            "var(--positive-color-darker)",
+            "positive-color-dark",
+            "positive-color-darker",
         ),
         CosmoButtonType::Negative => (
-            "var(--white)",
+            "var(--negative-color-foreground)",
             "var(--negative-color)",
             "var(--negative-color)",
-            "var(--negative-color-dark)",
-            "var(--negative-color-darker)",
+            "negative-color-dark",
+            "negative-color-darker",
         ),
         CosmoButtonType::Information => (
-            "var(--white)",
+            "var(--information-color-foreground)",
             "var(--information-color)",
             "var(--information-color)",
-            "var(--information-color-dark)",
-            "var(--information-color-darker)",
+            "information-color-dark",
+            "information-color-darker",
         ),
         CosmoButtonType::Warning => (
-            "var(--white)",
+            "var(--warning-color-foreground)",
             "var(--warning-color)",
             "var(--warning-color)",
-            "var(--warning-color-dark)",
-            "var(--warning-color-darker)",
+            "warning-color-dark",
+            "warning-color-darker",
         ),
     };
 
@@ -197,26 +211,26 @@ transition:
 
 &:not(:disabled):hover,
 &:not(:disabled):focus {
-	--button-background: ${hover_color};
-	--button-border-color: ${hover_color};
+	--button-background: var(--${hover_shade});
+	--button-border-color: var(--${hover_shade});
+	--button-color: var(--${hover_shade}-foreground);
 }
 
 &:not(:disabled):active {
-	--button-border-color: ${active_color};
-	--button-background: ${active_color};
+	--button-border-color: var(--${active_shade});
+	--button-background: var(--${active_shade});
+	--button-color: var(--${active_shade}-foreground);
 }
 
 &:not(:disabled):hover,
 &:not(:disabled):focus,
 &:not(:disabled):active {
-	--button-color: var(--white);
-
 	outline: none;
 	box-shadow: none;
 }
     "#,
-        hover_color = hover_color,
-        active_color = active_color,
+        hover_shade = hover_shade,
+        active_shade = active_shade,
     );
 
     let mut full_width_style: Option<Style> = Some(use_style!(
@@ -230,8 +244,8 @@ text-align: center;
     }
     let mut non_circle_style: Option<Style> = Some(use_style!(
         r#"
-padding: var(--button-padding-top) var(--button-padding-right) var(--button-padding-bottom)
-    var(--button-padding-left);
+padding-block: var(--button-padding-top) var(--button-padding-bottom);
+padding-inline: var(--button-padding-left) var(--button-padding-right);
 height: var(--control-height);
 background: var(--button-background);
 color: var(--button-color);
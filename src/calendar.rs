@@ -0,0 +1,481 @@
+use std::rc::Rc;
+
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
+use stylist::yew::{styled_component, use_style};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::button::CosmoButton;
+use crate::form::use_id;
+use crate::locale::use_cosmo_locale;
+
+#[derive(Clone)]
+pub struct CosmoDateFilter {
+    is_disabled: Rc<dyn Fn(&NaiveDate) -> bool>,
+}
+
+impl PartialEq for CosmoDateFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.is_disabled, &other.is_disabled)
+    }
+}
+
+impl CosmoDateFilter {
+    pub fn new(is_disabled: impl Fn(&NaiveDate) -> bool + 'static) -> Self {
+        Self {
+            is_disabled: Rc::new(is_disabled),
+        }
+    }
+
+    pub fn weekends() -> Self {
+        Self::new(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+
+    pub fn is_disabled(&self, date: &NaiveDate) -> bool {
+        (self.is_disabled)(date)
+    }
+}
+
+pub(crate) fn is_date_selectable(
+    date: &NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled_dates: &Option<CosmoDateFilter>,
+) -> bool {
+    min.map(|min| *date >= min).unwrap_or(true)
+        && max.map(|max| *date <= max).unwrap_or(true)
+        && !disabled_dates
+            .as_ref()
+            .map(|filter| filter.is_disabled(date))
+            .unwrap_or(false)
+}
+
+fn clamp_date(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> NaiveDate {
+    let date = min.map(|min| date.max(min)).unwrap_or(date);
+
+    max.map(|max| date.min(max)).unwrap_or(date)
+}
+
+fn month_key(date: &NaiveDate) -> (i32, u32) {
+    (date.year(), date.month())
+}
+
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    if months < 0 {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    } else {
+        date.checked_add_months(Months::new(months as u32))
+    }
+    .unwrap_or(date)
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoCalendarProps {
+    pub on_select: Callback<NaiveDate>,
+    #[prop_or_default]
+    pub value: Option<NaiveDate>,
+    #[prop_or_default]
+    pub range_end: Option<NaiveDate>,
+    #[prop_or_default]
+    pub min: Option<NaiveDate>,
+    #[prop_or_default]
+    pub max: Option<NaiveDate>,
+    #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or(true)]
+    pub show_today: bool,
+    #[prop_or_default]
+    pub today: Option<NaiveDate>,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+}
+
+#[styled_component(CosmoCalendar)]
+pub fn calendar(props: &CosmoCalendarProps) -> Html {
+    let calendar_style = use_style!(
+        r#"
+display: inline-flex;
+flex-flow: column;
+gap: 0.5rem;
+padding: 0.5rem;
+font-family: var(--font-family);
+font-size: var(--font-size);
+color: var(--black);
+background: var(--white);
+    "#
+    );
+    let header_style = use_style!(
+        r#"
+display: grid;
+grid-template-columns: auto auto 1fr auto auto;
+align-items: center;
+gap: 0.25rem;
+text-align: center;
+font-weight: var(--font-weight-bold);
+    "#
+    );
+    let navigation_style = use_style!(
+        r#"
+position: relative;
+width: 1.5rem;
+height: 1.5rem;
+padding: 0;
+border: 0;
+border-radius: var(--border-radius);
+background: none;
+color: var(--black);
+cursor: pointer;
+
+&::before,
+&::after {
+	content: '';
+	position: absolute;
+	top: 50%;
+	left: 50%;
+	width: 0.375rem;
+	height: 0.375rem;
+	border: 0 solid currentColor;
+	border-bottom-width: 0.0625rem;
+	border-left-width: 0.0625rem;
+	transform: translate(-25%, -50%) rotate(45deg);
+}
+
+&::after {
+	display: none;
+}
+
+&.is--double::before {
+	transform: translate(-75%, -50%) rotate(45deg);
+}
+
+&.is--double::after {
+	display: block;
+	transform: translate(0, -50%) rotate(45deg);
+}
+
+&.is--next,
+&:dir(rtl) {
+	transform: scaleX(-1);
+}
+
+&.is--next:dir(rtl) {
+	transform: none;
+}
+
+&:hover,
+&:focus-visible {
+	background: var(--primary-color-alpha-25);
+	outline: none;
+}
+
+&:disabled {
+	background: none;
+	color: var(--disabled-color);
+	cursor: not-allowed;
+}
+    "#
+    );
+    let grid_style = use_style!(
+        r#"
+border-collapse: collapse;
+
+th {
+	padding-block: 0.25rem;
+	font-size: 0.75rem;
+	font-weight: normal;
+	text-align: center;
+	color: var(--control-border-color-darker);
+}
+
+th[scope='row'] {
+	padding-inline-end: 0.25rem;
+}
+
+td {
+	padding: 0.0625rem 0;
+	text-align: center;
+}
+
+td.is--in-range {
+	background: var(--primary-color-alpha-25);
+}
+
+button {
+	width: 2rem;
+	height: 2rem;
+	padding: 0;
+	border: 0;
+	border-radius: var(--border-radius);
+	background: none;
+	color: inherit;
+	font: inherit;
+	cursor: pointer;
+}
+
+td.is--outside button {
+	color: var(--control-border-color-darker);
+}
+
+button:hover,
+button:focus-visible {
+	background: var(--primary-color-alpha-25);
+	outline: none;
+}
+
+button[aria-current='date'] {
+	font-weight: var(--font-weight-bold);
+	text-decoration: underline;
+}
+
+td[aria-selected='true'] button {
+	background: var(--primary-color);
+	color: var(--primary-color-foreground);
+}
+
+button[aria-disabled='true'] {
+	background: none;
+	color: var(--disabled-color);
+	text-decoration: line-through;
+	cursor: not-allowed;
+}
+
+button[aria-disabled='true']:focus-visible {
+	outline: 0.125rem solid var(--primary-color);
+	outline-offset: -0.125rem;
+}
+    "#
+    );
+
+    let locale = use_cosmo_locale();
+    let id = use_id(props.id.clone());
+    let title_id = AttrValue::from(format!("{id}-title"));
+    let today_state = use_state_eq(|| props.today);
+
+    let focused_state = use_state_eq(|| {
+        props
+            .value
+            .or(props.today)
+            .map(|date| clamp_date(date, props.min, props.max))
+    });
+    let is_keyboard_navigation = use_mut_ref(|| false);
+    let grid_ref = use_node_ref();
+
+    {
+        let today_state = today_state.clone();
+        use_effect_with(props.today, move |today| {
+            today_state.set(Some(today.unwrap_or_else(|| Local::now().date_naive())));
+        });
+    }
+    {
+        let focused_state = focused_state.clone();
+        use_effect_with(
+            (props.value, *today_state, props.min, props.max),
+            move |(value, today, min, max)| {
+                let fallback = if focused_state.is_none() {
+                    *today
+                } else {
+                    None
+                };
+                if let Some(date) = value.or(fallback) {
+                    focused_state.set(Some(clamp_date(date, *min, *max)));
+                }
+            },
+        );
+    }
+    {
+        let is_keyboard_navigation = is_keyboard_navigation.clone();
+        let grid_ref = grid_ref.clone();
+        use_effect_with(*focused_state, move |_| {
+            if is_keyboard_navigation.replace(false) {
+                if let Some(button) = grid_ref
+                    .cast::<HtmlElement>()
+                    .and_then(|grid| grid.query_selector("button[tabindex='0']").ok().flatten())
+                    .and_then(|button| button.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = button.focus();
+                }
+            }
+        });
+    }
+
+    let today = *today_state;
+    let first_day_of_week = locale.first_day_of_week;
+    let is_rtl = locale.direction.is_rtl();
+
+    let on_navigate = use_callback(
+        (focused_state.clone(), props.min, props.max),
+        |months: i32, (focused_state, min, max)| {
+            if let Some(focused) = **focused_state {
+                focused_state.set(Some(clamp_date(add_months(focused, months), *min, *max)));
+            }
+        },
+    );
+    let on_keydown = use_callback(
+        (
+            focused_state.clone(),
+            is_keyboard_navigation.clone(),
+            props.min,
+            props.max,
+            first_day_of_week,
+            is_rtl,
+        ),
+        |evt: KeyboardEvent,
+         (focused_state, is_keyboard_navigation, min, max, first_day_of_week, is_rtl)| {
+            let Some(focused) = **focused_state else {
+                return;
+            };
+            let column = (focused.weekday().num_days_from_monday() + 7
+                - first_day_of_week.num_days_from_monday())
+                % 7;
+            let (back, forward) = if *is_rtl { (1, -1) } else { (-1, 1) };
+            let target = match evt.key().as_str() {
+                "ArrowLeft" => focused + Duration::days(back),
+                "ArrowRight" => focused + Duration::days(forward),
+                "ArrowUp" => focused - Duration::days(7),
+                "ArrowDown" => focused + Duration::days(7),
+                "Home" => focused - Duration::days(column as i64),
+                "End" => focused + Duration::days(6 - column as i64),
+                "PageUp" if evt.shift_key() => add_months(focused, -12),
+                "PageUp" => add_months(focused, -1),
+                "PageDown" if evt.shift_key() => add_months(focused, 12),
+                "PageDown" => add_months(focused, 1),
+                _ => return,
+            };
+
+            evt.prevent_default();
+            *is_keyboard_navigation.borrow_mut() = true;
+            focused_state.set(Some(clamp_date(target, *min, *max)));
+        },
+    );
+    let on_select = use_callback(
+        (
+            props.on_select.clone(),
+            focused_state.clone(),
+            props.min,
+            props.max,
+            props.disabled_dates.clone(),
+        ),
+        |date: NaiveDate, (on_select, focused_state, min, max, disabled_dates)| {
+            if is_date_selectable(&date, *min, *max, disabled_dates) {
+                focused_state.set(Some(date));
+                on_select.emit(date);
+            }
+        },
+    );
+
+    let Some(focused) = *focused_state else {
+        return html!(
+            <div class={calendar_style}></div>
+        );
+    };
+
+    let first_of_month = focused.with_day(1).unwrap_or(focused);
+    let offset = (first_of_month.weekday().num_days_from_monday() + 7
+        - first_day_of_week.num_days_from_monday())
+        % 7;
+    let grid_start = first_of_month - Duration::days(offset as i64);
+    let week_number_offset =
+        Duration::days(((7 - first_day_of_week.num_days_from_monday()) % 7) as i64);
+
+    let is_before_min = |date: NaiveDate| {
+        props
+            .min
+            .map(|min| month_key(&date) < month_key(&min))
+            .unwrap_or(false)
+    };
+    let is_after_max = |date: NaiveDate| {
+        props
+            .max
+            .map(|max| month_key(&date) > month_key(&max))
+            .unwrap_or(false)
+    };
+
+    let navigation_button = |months: i32, label: AttrValue| {
+        let target = add_months(focused, months);
+        let enabled = if months < 0 {
+            !is_before_min(target)
+        } else {
+            !is_after_max(target)
+        };
+        let on_navigate = on_navigate.clone();
+
+        html!(
+            <button type="button" class={classes!(navigation_style.clone(), (months.abs() == 12).then_some("is--double"), (months > 0).then_some("is--next"))} aria-label={label.clone()} title={label} disabled={!enabled} onclick={move |_| on_navigate.emit(months)}></button>
+        )
+    };
+
+    let in_range = |date: &NaiveDate| match (props.value, props.range_end) {
+        (Some(start), Some(end)) => *date > start && *date < end,
+        _ => false,
+    };
+    let is_today_selectable = today
+        .map(|today| is_date_selectable(&today, props.min, props.max, &props.disabled_dates))
+        .unwrap_or(false);
+    let on_today = {
+        let on_select = on_select.clone();
+        Callback::from(move |_| {
+            if let Some(today) = today {
+                on_select.emit(today);
+            }
+        })
+    };
+
+    html!(
+        <div class={calendar_style}>
+            <div class={header_style}>
+                {navigation_button(-12, locale.labels.previous_year.clone())}
+                {navigation_button(-1, locale.labels.previous_month.clone())}
+                <span id={title_id.clone()} aria-live="polite">{format!("{} {}", locale.labels.months[focused.month0() as usize], focused.year())}</span>
+                {navigation_button(1, locale.labels.next_month.clone())}
+                {navigation_button(12, locale.labels.next_year.clone())}
+            </div>
+            <table ref={grid_ref} class={grid_style} role="grid" aria-labelledby={title_id} onkeydown={on_keydown}>
+                <thead>
+                    <tr>
+                        if props.show_week_numbers {
+                            <th scope="col">{locale.labels.week.clone()}</th>
+                        }
+                        {for (0..7).map(|column| {
+                            let weekday = (first_day_of_week.num_days_from_monday() + column) % 7;
+
+                            html!(
+                                <th scope="col">{locale.labels.weekdays[weekday as usize].clone()}</th>
+                            )
+                        })}
+                    </tr>
+                </thead>
+                <tbody>
+                    {for (0..6).map(|week| {
+                        let week_start = grid_start + Duration::days(week * 7);
+
+                        html!(
+                            <tr>
+                                if props.show_week_numbers {
+                                    <th scope="row">{(week_start + week_number_offset).iso_week().week()}</th>
+                                }
+                                {for (0..7).map(|day| {
+                                    let date = week_start + Duration::days(day);
+                                    let is_selected = props.value == Some(date) || props.range_end == Some(date);
+                                    let is_selectable = is_date_selectable(&date, props.min, props.max, &props.disabled_dates);
+                                    let on_select = on_select.clone();
+
+                                    html!(
+                                        <td role="gridcell" aria-selected={is_selected.to_string()} class={classes!(in_range(&date).then_some("is--in-range"), (date.month() != focused.month()).then_some("is--outside"))}>
+                                            <button type="button" tabindex={if date == focused { "0" } else { "-1" }} aria-label={locale.format_date(&date)} aria-current={(Some(date) == today).then_some("date")} aria-disabled={(!is_selectable).then_some("true")} onclick={move |_| on_select.emit(date)}>{date.day()}</button>
+                                        </td>
+                                    )
+                                })}
+                            </tr>
+                        )
+                    })}
+                </tbody>
+            </table>
+            if props.show_today {
+                <CosmoButton label={locale.labels.today.clone()} is_full_width={true} enabled={is_today_selectable} on_click={on_today} />
+            }
+        </div>
+    )
+}
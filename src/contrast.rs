@@ -0,0 +1,82 @@
+use color_art::Color;
+
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum CosmoContrastLevel {
+    AaLarge,
+    #[default]
+    Aa,
+    Aaa,
+}
+
+impl CosmoContrastLevel {
+    pub fn min_ratio(&self) -> f64 {
+        match self {
+            CosmoContrastLevel::AaLarge => 3.0,
+            CosmoContrastLevel::Aa => 4.5,
+            CosmoContrastLevel::Aaa => 7.0,
+        }
+    }
+}
+
+fn linearize(channel: u8) -> f64 {
+    let channel = channel as f64 / 255.0;
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn relative_luminance(color: &Color) -> f64 {
+    0.2126 * linearize(color.red())
+        + 0.7152 * linearize(color.green())
+        + 0.0722 * linearize(color.blue())
+}
+
+pub fn contrast_ratio(foreground: &Color, background: &Color) -> f64 {
+    let foreground = relative_luminance(foreground);
+    let background = relative_luminance(background);
+    let (lighter, darker) = if foreground > background {
+        (foreground, background)
+    } else {
+        (background, foreground)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+pub fn meets_contrast(foreground: &Color, background: &Color, level: CosmoContrastLevel) -> bool {
+    contrast_ratio(foreground, background) >= level.min_ratio()
+}
+
+pub fn accessible_foreground(background: &Color, light: &Color, dark: &Color) -> Color {
+    if contrast_ratio(light, background) >= contrast_ratio(dark, background) {
+        *light
+    } else {
+        *dark
+    }
+}
+
+pub fn adjust_for_contrast(
+    foreground: &Color,
+    background: &Color,
+    level: CosmoContrastLevel,
+) -> Color {
+    let lighten = relative_luminance(background) < 0.5;
+    let mut lightness = foreground.lightness();
+    let mut color = *foreground;
+    while !meets_contrast(&color, background, level) {
+        lightness = if lighten {
+            (lightness + 0.01).min(1.0)
+        } else {
+            (lightness - 0.01).max(0.0)
+        };
+        color =
+            Color::from_hsl(foreground.hue(), foreground.saturation(), lightness).unwrap_or(color);
+        if lightness <= 0.0 || lightness >= 1.0 {
+            break;
+        }
+    }
+
+    color
+}
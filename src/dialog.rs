@@ -0,0 +1,289 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures_channel::oneshot;
+use yew::prelude::*;
+
+use crate::button::CosmoButton;
+use crate::form::{CosmoInputGroup, CosmoTextBox};
+use crate::locale::use_cosmo_locale;
+use crate::modal::{CosmoAlert, CosmoConfirm, CosmoModal, CosmoModalType};
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoDialogOptions {
+    pub title: AttrValue,
+    pub message: AttrValue,
+    pub modal_type: CosmoModalType,
+    pub confirm_label: Option<AttrValue>,
+    pub decline_label: Option<AttrValue>,
+}
+
+impl CosmoDialogOptions {
+    pub fn new(title: impl Into<AttrValue>, message: impl Into<AttrValue>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            modal_type: CosmoModalType::default(),
+            confirm_label: None,
+            decline_label: None,
+        }
+    }
+
+    pub fn with_type(mut self, modal_type: CosmoModalType) -> Self {
+        self.modal_type = modal_type;
+        self
+    }
+
+    pub fn with_confirm_label(mut self, confirm_label: impl Into<AttrValue>) -> Self {
+        self.confirm_label = Some(confirm_label.into());
+        self
+    }
+
+    pub fn with_decline_label(mut self, decline_label: impl Into<AttrValue>) -> Self {
+        self.decline_label = Some(decline_label.into());
+        self
+    }
+}
+
+impl<T, M> From<(T, M)> for CosmoDialogOptions
+where
+    T: Into<AttrValue>,
+    M: Into<AttrValue>,
+{
+    fn from((title, message): (T, M)) -> Self {
+        Self::new(title, message)
+    }
+}
+
+type CosmoDialogSender<T> = Rc<RefCell<Option<oneshot::Sender<T>>>>;
+
+fn resolve<T>(sender: &CosmoDialogSender<T>, value: T) {
+    if let Some(sender) = sender.borrow_mut().take() {
+        let _ = sender.send(value);
+    }
+}
+
+#[derive(Clone)]
+enum CosmoDialogKind {
+    Alert(CosmoDialogSender<()>),
+    Confirm(CosmoDialogSender<bool>),
+    Prompt(CosmoDialogSender<Option<String>>, AttrValue),
+}
+
+#[derive(Clone)]
+struct CosmoDialogRequest {
+    id: usize,
+    options: CosmoDialogOptions,
+    kind: CosmoDialogKind,
+}
+
+#[derive(Default)]
+struct CosmoDialogState {
+    dialogs: VecDeque<CosmoDialogRequest>,
+    next_id: usize,
+}
+
+enum CosmoDialogReducerAction {
+    Open(CosmoDialogOptions, CosmoDialogKind),
+    Close(usize),
+}
+
+impl Reducible for CosmoDialogState {
+    type Action = CosmoDialogReducerAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut dialogs = self.dialogs.clone();
+        let mut next_id = self.next_id;
+        match action {
+            CosmoDialogReducerAction::Open(options, kind) => {
+                dialogs.push_back(CosmoDialogRequest {
+                    id: next_id,
+                    options,
+                    kind,
+                });
+                next_id += 1;
+            }
+            CosmoDialogReducerAction::Close(id) => dialogs.retain(|dialog| dialog.id != id),
+        }
+
+        Self { dialogs, next_id }.into()
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoDialogHandle {
+    dispatcher: UseReducerDispatcher<CosmoDialogState>,
+}
+
+impl CosmoDialogHandle {
+    fn open<T>(
+        &self,
+        options: CosmoDialogOptions,
+        kind: impl FnOnce(CosmoDialogSender<T>) -> CosmoDialogKind,
+    ) -> oneshot::Receiver<T> {
+        let (sender, receiver) = oneshot::channel();
+        self.dispatcher.dispatch(CosmoDialogReducerAction::Open(
+            options,
+            kind(Rc::new(RefCell::new(Some(sender)))),
+        ));
+
+        receiver
+    }
+
+    pub fn alert(&self, options: impl Into<CosmoDialogOptions>) -> impl Future<Output = ()> {
+        let receiver = self.open(options.into(), CosmoDialogKind::Alert);
+
+        async move {
+            let _ = receiver.await;
+        }
+    }
+
+    pub fn confirm(&self, options: impl Into<CosmoDialogOptions>) -> impl Future<Output = bool> {
+        let receiver = self.open(options.into(), CosmoDialogKind::Confirm);
+
+        async move { receiver.await.unwrap_or(false) }
+    }
+
+    pub fn prompt(
+        &self,
+        options: impl Into<CosmoDialogOptions>,
+        value: impl Into<AttrValue>,
+    ) -> impl Future<Output = Option<String>> {
+        let value = value.into();
+        let receiver = self.open(options.into(), move |sender| {
+            CosmoDialogKind::Prompt(sender, value)
+        });
+
+        async move { receiver.await.unwrap_or(None) }
+    }
+}
+
+#[hook]
+pub fn use_cosmo_dialogs() -> CosmoDialogHandle {
+    use_context::<CosmoDialogHandle>()
+        .expect("use_cosmo_dialogs must be used inside of a CosmoPageLayout or CosmoDialogProvider")
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoDialogProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub confirm_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub decline_label: Option<AttrValue>,
+}
+
+#[function_component(CosmoDialogProvider)]
+pub fn dialog_provider(props: &CosmoDialogProviderProps) -> Html {
+    let state = use_reducer(CosmoDialogState::default);
+    let locale = use_cosmo_locale();
+    let handle = CosmoDialogHandle {
+        dispatcher: state.dispatcher(),
+    };
+
+    let dialog = state.dialogs.front().cloned().map(|dialog| {
+        let dispatcher = state.dispatcher();
+        let id = dialog.id;
+        let close = move || dispatcher.dispatch(CosmoDialogReducerAction::Close(id));
+        let options = dialog.options;
+        let confirm_label = options
+            .confirm_label
+            .clone()
+            .or(props.confirm_label.clone())
+            .unwrap_or(locale.labels.ok.clone());
+        let decline_label = options
+            .decline_label
+            .clone()
+            .or(props.decline_label.clone())
+            .unwrap_or(locale.labels.cancel.clone());
+
+        match dialog.kind {
+            CosmoDialogKind::Alert(sender) => {
+                let on_close = Callback::from(move |_| {
+                    resolve(&sender, ());
+                    close();
+                });
+
+                html!(
+                    <CosmoAlert key={id} alert_type={options.modal_type} title={options.title} message={options.message} close_label={confirm_label} on_close={on_close.clone()} on_dismiss={on_close} />
+                )
+            }
+            CosmoDialogKind::Confirm(sender) => {
+                let on_confirm = {
+                    let sender = sender.clone();
+                    let close = close.clone();
+                    Callback::from(move |_| {
+                        resolve(&sender, true);
+                        close();
+                    })
+                };
+                let on_decline = Callback::from(move |_| {
+                    resolve(&sender, false);
+                    close();
+                });
+
+                html!(
+                    <CosmoConfirm key={id} confirm_type={options.modal_type} title={options.title} message={options.message} confirm_label={confirm_label} decline_label={decline_label} on_confirm={on_confirm} on_decline={on_decline.clone()} on_dismiss={on_decline} />
+                )
+            }
+            CosmoDialogKind::Prompt(sender, value) => {
+                let on_close = Callback::from(move |value| {
+                    resolve(&sender, value);
+                    close();
+                });
+
+                html!(
+                    <CosmoPrompt key={id} options={options} value={value} confirm_label={confirm_label} decline_label={decline_label} on_close={on_close} />
+                )
+            }
+        }
+    });
+
+    html!(
+        <ContextProvider<CosmoDialogHandle> context={handle}>
+            {for props.children.iter()}
+            if let Some(dialog) = dialog {
+                {dialog}
+            }
+        </ContextProvider<CosmoDialogHandle>>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoPromptProps {
+    options: CosmoDialogOptions,
+    value: AttrValue,
+    confirm_label: AttrValue,
+    decline_label: AttrValue,
+    on_close: Callback<Option<String>>,
+}
+
+#[function_component(CosmoPrompt)]
+fn prompt(props: &CosmoPromptProps) -> Html {
+    let value_state = use_state_eq(|| props.value.clone());
+
+    let on_input = use_callback(value_state.clone(), |value: AttrValue, state| {
+        state.set(value)
+    });
+    let on_confirm = use_callback(
+        (value_state.clone(), props.on_close.clone()),
+        |_, (value_state, on_close)| on_close.emit(Some(value_state.to_string())),
+    );
+    let on_decline = use_callback(props.on_close.clone(), |_, on_close| on_close.emit(None));
+
+    html!(
+        <CosmoModal modal_type={props.options.modal_type.clone()} title={props.options.title.clone()} is_form={true} on_form_submit={on_confirm} on_dismiss={on_decline.clone()} buttons={html!(
+            <>
+                <CosmoButton label={props.decline_label.clone()} on_click={on_decline} />
+                <CosmoButton label={props.confirm_label.clone()} is_submit={true} />
+            </>
+        )}>
+            <CosmoInputGroup>
+                <CosmoTextBox label={props.options.message.clone()} value={(*value_state).clone()} on_input={on_input} />
+            </CosmoInputGroup>
+        </CosmoModal>
+    )
+}
@@ -0,0 +1,247 @@
+use bounce::helmet::Helmet;
+use yew::prelude::*;
+
+const CDN_BASE_URL: &str = "https://fonts.jinya.de";
+
+const UNICODE_RANGE: &str = "U+0000-00FF, U+0131, U+0152-0153, U+02BB-02BC, U+02C6, U+02DA, U+02DC, U+0304, U+0308, U+0329, U+2000-206F, U+2074, U+20AC, U+2122, U+2191, U+2193, U+2212, U+2215, U+FEFF, U+FFFD";
+
+struct CosmoFontFace {
+    family: &'static str,
+    directory: &'static str,
+    variant: &'static str,
+    weight: u16,
+    italic: bool,
+    #[cfg(feature = "embed-fonts")]
+    data: &'static [u8],
+}
+
+macro_rules! font_face {
+    ($family:literal, $directory:literal, $variant:literal, $weight:literal, $italic:literal) => {
+        CosmoFontFace {
+            family: $family,
+            directory: $directory,
+            variant: $variant,
+            weight: $weight,
+            italic: $italic,
+            #[cfg(feature = "embed-fonts")]
+            data: include_bytes!(concat!(
+                "../fonts/",
+                $directory,
+                "/",
+                $family,
+                ".latin.",
+                $variant,
+                ".woff2"
+            )),
+        }
+    };
+}
+
+const FONT_FACES: &[CosmoFontFace] = &[
+    font_face!("Albert Sans", "albert-sans", "100", 100, false),
+    font_face!("Albert Sans", "albert-sans", "100italic", 100, true),
+    font_face!("Albert Sans", "albert-sans", "200", 200, false),
+    font_face!("Albert Sans", "albert-sans", "200italic", 200, true),
+    font_face!("Albert Sans", "albert-sans", "300", 300, false),
+    font_face!("Albert Sans", "albert-sans", "300italic", 300, true),
+    font_face!("Albert Sans", "albert-sans", "regular", 400, false),
+    font_face!("Albert Sans", "albert-sans", "italic", 400, true),
+    font_face!("Albert Sans", "albert-sans", "500", 500, false),
+    font_face!("Albert Sans", "albert-sans", "500italic", 500, true),
+    font_face!("Albert Sans", "albert-sans", "600", 600, false),
+    font_face!("Albert Sans", "albert-sans", "600italic", 600, true),
+    font_face!("Albert Sans", "albert-sans", "700", 700, false),
+    font_face!("Albert Sans", "albert-sans", "700italic", 700, true),
+    font_face!("Albert Sans", "albert-sans", "800", 800, false),
+    font_face!("Albert Sans", "albert-sans", "800italic", 800, true),
+    font_face!("Albert Sans", "albert-sans", "900", 900, false),
+    font_face!("Albert Sans", "albert-sans", "900italic", 900, true),
+    font_face!("Urbanist", "urbanist", "100", 100, false),
+    font_face!("Urbanist", "urbanist", "200", 200, false),
+    font_face!("Urbanist", "urbanist", "300", 300, false),
+    font_face!("Urbanist", "urbanist", "regular", 400, false),
+    font_face!("Urbanist", "urbanist", "500", 500, false),
+    font_face!("Urbanist", "urbanist", "600", 600, false),
+    font_face!("Urbanist", "urbanist", "700", 700, false),
+    font_face!("Urbanist", "urbanist", "800", 800, false),
+    font_face!("Urbanist", "urbanist", "900", 900, false),
+    font_face!("Oxygen Mono", "oxygen-mono", "regular", 400, false),
+];
+
+impl CosmoFontFace {
+    fn to_css(&self, src: String) -> String {
+        format!(
+            "@font-face {{ font-family: '{}'; font-style: {}; font-weight: {}; src: url('{src}') format('woff2'); unicode-range: {UNICODE_RANGE}; font-display: swap; }}",
+            self.family,
+            if self.italic { "italic" } else { "normal" },
+            self.weight,
+        )
+    }
+}
+
+fn encode_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(any(test, feature = "embed-fonts"))]
+fn to_base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let triple = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - index * 6) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub enum CosmoFontSource {
+    #[default]
+    Cdn,
+    CustomUrl(AttrValue),
+    SelfHosted(AttrValue),
+    #[cfg(feature = "embed-fonts")]
+    Embedded,
+    System,
+}
+
+impl CosmoFontSource {
+    fn stylesheets(&self) -> Vec<String> {
+        let base_url = match self {
+            CosmoFontSource::Cdn => CDN_BASE_URL,
+            CosmoFontSource::CustomUrl(base_url) => base_url.trim_end_matches('/'),
+            _ => return vec![],
+        };
+
+        vec![
+            format!("{base_url}/css2?family=Albert Sans:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900"),
+            format!("{base_url}/css2?family=Urbanist:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900"),
+            format!("{base_url}/css2?family=Oxygen Mono"),
+        ]
+    }
+
+    fn font_faces(&self) -> Option<String> {
+        let font_faces = match self {
+            CosmoFontSource::SelfHosted(base_path) => {
+                let base_path = base_path.trim_end_matches('/');
+                FONT_FACES
+                    .iter()
+                    .map(|face| {
+                        face.to_css(format!(
+                            "{base_path}/{}/{}.latin.{}.woff2",
+                            encode_path_segment(face.directory),
+                            encode_path_segment(face.family),
+                            encode_path_segment(face.variant)
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            }
+            #[cfg(feature = "embed-fonts")]
+            CosmoFontSource::Embedded => FONT_FACES
+                .iter()
+                .map(|face| face.to_css(format!("data:font/woff2;base64,{}", to_base64(face.data))))
+                .collect::<Vec<_>>(),
+            _ => return None,
+        };
+
+        Some(font_faces.join("\n"))
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub(crate) struct CosmoFontLoaderProps {
+    pub(crate) source: CosmoFontSource,
+}
+
+#[function_component(CosmoFontLoader)]
+pub(crate) fn font_loader(props: &CosmoFontLoaderProps) -> Html {
+    let font_faces = use_memo(props.source.clone(), |source| source.font_faces());
+
+    if let Some(font_faces) = (*font_faces).clone() {
+        html!(
+            <Helmet>
+                <style>{font_faces}</style>
+            </Helmet>
+        )
+    } else {
+        let stylesheets = props.source.stylesheets();
+        if stylesheets.is_empty() {
+            html!()
+        } else {
+            html!(
+                <Helmet>
+                    {for stylesheets.into_iter().map(|href| html!(<link href={href} rel="stylesheet" type="text/css" />))}
+                </Helmet>
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_matches_the_rfc_4648_vectors() {
+        assert_eq!(to_base64(b""), "");
+        assert_eq!(to_base64(b"f"), "Zg==");
+        assert_eq!(to_base64(b"fo"), "Zm8=");
+        assert_eq!(to_base64(b"foo"), "Zm9v");
+        assert_eq!(to_base64(b"foob"), "Zm9vYg==");
+        assert_eq!(to_base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(to_base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(to_base64(&[0xfb, 0xff, 0xbf]), "+/+/");
+    }
+
+    #[test]
+    fn self_hosted_font_faces_encode_the_file_urls() {
+        let font_faces = CosmoFontSource::SelfHosted(AttrValue::from("/static/fonts/"))
+            .font_faces()
+            .unwrap();
+
+        assert_eq!(font_faces.lines().count(), FONT_FACES.len());
+        assert!(font_faces.contains("@font-face { font-family: 'Albert Sans'; font-style: italic; font-weight: 100; src: url('/static/fonts/albert-sans/Albert%20Sans.latin.100italic.woff2') format('woff2');"));
+        assert!(font_faces
+            .contains("url('/static/fonts/oxygen-mono/Oxygen%20Mono.latin.regular.woff2')"));
+        assert!(font_faces.contains("url('/static/fonts/urbanist/Urbanist.latin.900.woff2')"));
+        assert!(!font_faces.contains("fonts//"));
+    }
+
+    #[test]
+    fn custom_url_loads_stylesheets() {
+        let source = CosmoFontSource::CustomUrl(AttrValue::from("https://fonts.example.com/"));
+
+        assert_eq!(source.font_faces(), None);
+        assert_eq!(
+            source.stylesheets(),
+            vec![
+                String::from("https://fonts.example.com/css2?family=Albert Sans:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900"),
+                String::from("https://fonts.example.com/css2?family=Urbanist:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900"),
+                String::from("https://fonts.example.com/css2?family=Oxygen Mono"),
+            ]
+        );
+        assert!(CosmoFontSource::System.stylesheets().is_empty());
+        assert_eq!(CosmoFontSource::System.font_faces(), None);
+    }
+}
@@ -1,21 +1,42 @@
-use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, TimeZone, Utc,
+};
 use color_art::Color;
 use stylist::yew::{styled_component, use_style};
-use web_sys::{File, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use web_sys::{
+    File, FileList, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement,
+    ScrollIntoViewOptions, ScrollLogicalPosition, Url,
+};
 use yew::html::ChildrenRenderer;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 use yew::virtual_dom::VChild;
-use yew_hooks::use_click_away;
+use yew_hooks::{use_click_away, use_debounce, use_unmount};
 
+use crate::calendar::is_date_selectable;
 use crate::prelude::*;
+use crate::validation::{use_cosmo_field, use_cosmo_form_context, CosmoFormContext};
 
 #[derive(Clone, derive_more::From, PartialEq)]
 pub enum CosmoInputGroupChildren {
     CosmoDateTimePicker(VChild<CosmoDateTimePicker>),
+    CosmoUtcDateTimePicker(VChild<CosmoZonedDateTimePicker<Utc>>),
+    CosmoFixedOffsetDateTimePicker(VChild<CosmoZonedDateTimePicker<FixedOffset>>),
+    CosmoOptionalDateTimePicker(VChild<CosmoOptionalDateTimePicker>),
     CosmoDatePicker(VChild<CosmoDatePicker>),
+    CosmoOptionalDatePicker(VChild<CosmoOptionalDatePicker>),
+    CosmoDateRangePicker(VChild<CosmoDateRangePicker>),
     CosmoTextBox(VChild<CosmoTextBox>),
     CosmoNumberBox(VChild<CosmoNumberBox>),
+    CosmoOptionalNumberBox(VChild<CosmoOptionalNumberBox>),
     CosmoDecimalBox(VChild<CosmoDecimalBox>),
+    CosmoOptionalDecimalBox(VChild<CosmoOptionalDecimalBox>),
     CosmoTextArea(VChild<CosmoTextArea>),
     CosmoCheckbox(VChild<CosmoCheckbox>),
     CosmoRadios(VChild<CosmoRadios>),
@@ -25,6 +46,7 @@ pub enum CosmoInputGroupChildren {
     CosmoDropdown(VChild<CosmoDropdown>),
     CosmoSwitch(VChild<CosmoSwitch>),
     CosmoTimePicker(VChild<CosmoTimePicker>),
+    CosmoOptionalTimePicker(VChild<CosmoOptionalTimePicker>),
     CosmoFilePicker(VChild<CosmoFilePicker>),
     CosmoModernSelect(VChild<CosmoModernSelect>),
 }
@@ -34,10 +56,17 @@ impl Into<Html> for CosmoInputGroupChildren {
     fn into(self) -> Html {
         match self {
             CosmoInputGroupChildren::CosmoDateTimePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoUtcDateTimePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoFixedOffsetDateTimePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoOptionalDateTimePicker(child) => child.into(),
             CosmoInputGroupChildren::CosmoDatePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoOptionalDatePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoDateRangePicker(child) => child.into(),
             CosmoInputGroupChildren::CosmoTextBox(child) => child.into(),
             CosmoInputGroupChildren::CosmoNumberBox(child) => child.into(),
+            CosmoInputGroupChildren::CosmoOptionalNumberBox(child) => child.into(),
             CosmoInputGroupChildren::CosmoDecimalBox(child) => child.into(),
+            CosmoInputGroupChildren::CosmoOptionalDecimalBox(child) => child.into(),
             CosmoInputGroupChildren::CosmoTextArea(child) => child.into(),
             CosmoInputGroupChildren::CosmoCheckbox(child) => child.into(),
             CosmoInputGroupChildren::CosmoRadios(child) => child.into(),
@@ -47,6 +76,7 @@ impl Into<Html> for CosmoInputGroupChildren {
             CosmoInputGroupChildren::CosmoDropdown(child) => child.into(),
             CosmoInputGroupChildren::CosmoSwitch(child) => child.into(),
             CosmoInputGroupChildren::CosmoTimePicker(child) => child.into(),
+            CosmoInputGroupChildren::CosmoOptionalTimePicker(child) => child.into(),
             CosmoInputGroupChildren::CosmoFilePicker(child) => child.into(),
             CosmoInputGroupChildren::CosmoModernSelect(child) => child.into(),
         }
@@ -89,16 +119,15 @@ pub enum CosmoInputWidth {
     Full,
 }
 
-impl ToString for CosmoInputWidth {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for CosmoInputWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             CosmoInputWidth::Auto => "auto",
             CosmoInputWidth::Small => "var(--input-width-small)",
             CosmoInputWidth::Medium => "var(--input-width-medium)",
             CosmoInputWidth::Large => "var(--input-width-large)",
             CosmoInputWidth::Full => "100%",
-        }
-        .to_string()
+        })
     }
 }
 
@@ -126,8 +155,8 @@ color: var(--black);
 border-radius: var(--border-radius);
 line-height: var(--line-height);
 border: var(--input-border-width) solid var(--control-border-color);
-padding: var(--input-padding-top) var(--input-padding-right) var(--input-padding-bottom)
-    var(--input-padding-left);
+padding-block: var(--input-padding-top) var(--input-padding-bottom);
+padding-inline: var(--input-padding-left) var(--input-padding-right);
 border-bottom: var(--input-border-bottom-width) solid var(--border-indicator-color);
 
 &:active,
@@ -158,12 +187,904 @@ border-bottom: var(--input-border-bottom-width) solid var(--border-indicator-col
 }
 
 #[hook]
-fn use_id(id: Option<AttrValue>) -> AttrValue {
+pub(crate) fn use_id(id: Option<AttrValue>) -> AttrValue {
     let id_state = use_state_eq(|| id.unwrap_or(AttrValue::from(uuid::Uuid::new_v4().to_string())));
 
     (*id_state).clone()
 }
 
+const DATE_RANGE_SEPARATOR: &str = " – ";
+
+#[hook]
+fn use_calendar_input_styling(width: CosmoInputWidth) -> (Classes, Classes, Classes, Classes) {
+    let holder_style = use_style!(
+        r#"
+position: relative;
+min-width: ${width};
+width: ${width};
+    "#,
+        width = width.to_string()
+    );
+    let input_style = use_style!(
+        r#"
+padding-inline-end: calc(var(--control-height) + var(--input-padding-right)) !important;
+    "#
+    );
+    let toggle_style = use_style!(
+        r#"
+position: absolute;
+top: 0;
+inset-inline-end: 0;
+width: var(--control-height);
+height: 100%;
+padding: 0;
+border: 0;
+background: none;
+color: var(--primary-color);
+cursor: pointer;
+
+&::before {
+	content: '';
+	position: absolute;
+	top: 50%;
+	left: 50%;
+	width: 0.875rem;
+	height: 0.75rem;
+	box-sizing: border-box;
+	border: 0.0625rem solid currentColor;
+	border-top-width: 0.1875rem;
+	border-radius: 0.125rem;
+	transform: translate(-50%, -50%);
+}
+
+&:focus-visible {
+	outline: 0.125rem solid var(--primary-color);
+	outline-offset: -0.125rem;
+}
+
+&:disabled {
+	color: var(--disabled-color);
+	cursor: not-allowed;
+}
+    "#
+    );
+    let flyout_style = use_style!(
+        r#"
+position: absolute;
+top: 100%;
+inset-inline-start: 0;
+z-index: 1000;
+display: flex;
+flex-flow: column;
+margin-top: 0.25rem;
+background: var(--white);
+border: 0.0625rem solid var(--control-border-color);
+border-radius: var(--border-radius);
+    "#
+    );
+
+    (
+        classes!(holder_style),
+        classes!(input_style),
+        classes!(toggle_style),
+        classes!(flyout_style),
+    )
+}
+
+struct CosmoCalendarFlyout {
+    holder_ref: NodeRef,
+    open_state: UseStateHandle<bool>,
+    on_toggle: Callback<MouseEvent>,
+    on_keydown: Callback<KeyboardEvent>,
+}
+
+#[hook]
+fn use_calendar_flyout(input_ref: NodeRef) -> CosmoCalendarFlyout {
+    let holder_ref = use_node_ref();
+    let open_state = use_state_eq(|| false);
+
+    {
+        let open_state = open_state.clone();
+        use_click_away(holder_ref.clone(), move |_: Event| open_state.set(false));
+    }
+
+    let on_toggle = use_callback(open_state.clone(), |_: MouseEvent, open_state| {
+        open_state.set(!**open_state)
+    });
+    let on_keydown = use_callback(
+        (open_state.clone(), input_ref),
+        |evt: KeyboardEvent, (open_state, input_ref)| match evt.key().as_str() {
+            "Escape" if **open_state => {
+                evt.prevent_default();
+                open_state.set(false);
+                if let Some(input) = input_ref.cast::<HtmlElement>() {
+                    let _ = input.focus();
+                }
+            }
+            "ArrowDown" if evt.alt_key() => {
+                evt.prevent_default();
+                open_state.set(true);
+            }
+            _ => {}
+        },
+    );
+
+    CosmoCalendarFlyout {
+        holder_ref,
+        open_state,
+        on_toggle,
+        on_keydown,
+    }
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoValueBounds<T>
+where
+    T: PartialEq + Clone,
+{
+    min: Option<T>,
+    max: Option<T>,
+    disabled_dates: Option<CosmoDateFilter>,
+}
+
+impl<T> CosmoValueBounds<T>
+where
+    T: PartialEq + PartialOrd + Clone,
+{
+    fn contains(&self, value: &T) -> bool {
+        self.min.as_ref().map(|min| value >= min).unwrap_or(true)
+            && self.max.as_ref().map(|max| value <= max).unwrap_or(true)
+    }
+}
+
+trait CosmoTextValue: PartialEq + Clone + 'static {
+    type Options: PartialEq + Clone + 'static;
+
+    fn format(&self, locale: &CosmoLocale, options: &Self::Options) -> String;
+
+    fn parse(value: &str, locale: &CosmoLocale, options: &Self::Options) -> Option<Self>;
+
+    fn canonical(&self) -> String;
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoNumberFormat {
+    decimal_places: u8,
+    grouping: bool,
+    is_native: bool,
+}
+
+impl CosmoNumberFormat {
+    fn locale(&self, locale: &CosmoLocale) -> CosmoLocale {
+        CosmoLocale {
+            group_separator: locale.group_separator.filter(|_| self.grouping),
+            decimal_separator: if self.is_native {
+                '.'
+            } else {
+                locale.decimal_separator
+            },
+            ..locale.clone()
+        }
+    }
+}
+
+impl CosmoTextValue for i64 {
+    type Options = CosmoNumberFormat;
+
+    fn format(&self, locale: &CosmoLocale, format: &CosmoNumberFormat) -> String {
+        format.locale(locale).format_integer(*self)
+    }
+
+    fn parse(value: &str, locale: &CosmoLocale, format: &CosmoNumberFormat) -> Option<Self> {
+        format.locale(locale).parse_integer(value)
+    }
+
+    fn canonical(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl CosmoTextValue for f64 {
+    type Options = CosmoNumberFormat;
+
+    fn format(&self, locale: &CosmoLocale, format: &CosmoNumberFormat) -> String {
+        format
+            .locale(locale)
+            .format_decimal(*self, format.decimal_places)
+    }
+
+    fn parse(value: &str, locale: &CosmoLocale, format: &CosmoNumberFormat) -> Option<Self> {
+        format.locale(locale).parse_decimal(value)
+    }
+
+    fn canonical(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl CosmoTextValue for NaiveDate {
+    type Options = CosmoValueBounds<NaiveDate>;
+
+    fn format(&self, locale: &CosmoLocale, _: &Self::Options) -> String {
+        locale.format_date(self)
+    }
+
+    fn parse(value: &str, locale: &CosmoLocale, bounds: &Self::Options) -> Option<Self> {
+        locale
+            .parse_date(value)
+            .filter(|date| is_date_selectable(date, bounds.min, bounds.max, &bounds.disabled_dates))
+    }
+
+    fn canonical(&self) -> String {
+        self.format("%F").to_string()
+    }
+}
+
+impl CosmoTextValue for (NaiveDate, NaiveDate) {
+    type Options = CosmoValueBounds<NaiveDate>;
+
+    fn format(&self, locale: &CosmoLocale, _: &Self::Options) -> String {
+        format!(
+            "{}{DATE_RANGE_SEPARATOR}{}",
+            locale.format_date(&self.0),
+            locale.format_date(&self.1)
+        )
+    }
+
+    fn parse(value: &str, locale: &CosmoLocale, bounds: &Self::Options) -> Option<Self> {
+        let (start, end) = value
+            .split_once(DATE_RANGE_SEPARATOR.trim())
+            .or_else(|| value.split_once(" - "))?;
+        let start = NaiveDate::parse(start, locale, bounds)?;
+        let end = NaiveDate::parse(end, locale, bounds)?;
+
+        Some((start.min(end), start.max(end)))
+    }
+
+    fn canonical(&self) -> String {
+        format!("{}/{}", self.0.canonical(), self.1.canonical())
+    }
+}
+
+impl CosmoTextValue for NaiveTime {
+    type Options = CosmoValueBounds<NaiveTime>;
+
+    fn format(&self, _: &CosmoLocale, _: &Self::Options) -> String {
+        self.canonical()
+    }
+
+    fn parse(value: &str, _: &CosmoLocale, bounds: &Self::Options) -> Option<Self> {
+        NaiveTime::parse_from_str(value, "%R")
+            .or_else(|_| NaiveTime::parse_from_str(value, "%T"))
+            .ok()
+            .filter(|time| bounds.contains(time))
+    }
+
+    fn canonical(&self) -> String {
+        self.format("%R").to_string()
+    }
+}
+
+#[derive(Clone)]
+struct CosmoZonedBounds<Tz>
+where
+    Tz: TimeZone,
+{
+    zone: Tz,
+    preferred_offset: FixedOffset,
+    min: Option<DateTime<Tz>>,
+    max: Option<DateTime<Tz>>,
+    disabled_dates: Option<CosmoDateFilter>,
+}
+
+impl<Tz> PartialEq for CosmoZonedBounds<Tz>
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let now = Utc::now().naive_utc();
+
+        self.zone.offset_from_utc_datetime(&now).fix()
+            == other.zone.offset_from_utc_datetime(&now).fix()
+            && self.preferred_offset == other.preferred_offset
+            && self.min == other.min
+            && self.max == other.max
+            && self.disabled_dates == other.disabled_dates
+    }
+}
+
+impl<Tz> CosmoTextValue for DateTime<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    type Options = CosmoZonedBounds<Tz>;
+
+    fn format(&self, locale: &CosmoLocale, _: &Self::Options) -> String {
+        self.naive_local()
+            .format(locale.date_time_format.as_str())
+            .to_string()
+    }
+
+    fn parse(value: &str, locale: &CosmoLocale, bounds: &Self::Options) -> Option<Self> {
+        locale
+            .parse_date_time(value)
+            .and_then(|naive| {
+                resolve_local_date_time(&bounds.zone, &naive, Some(bounds.preferred_offset))
+            })
+            .filter(|value| {
+                bounds.min.as_ref().map(|min| value >= min).unwrap_or(true)
+                    && bounds.max.as_ref().map(|max| value <= max).unwrap_or(true)
+                    && is_date_selectable(&value.date_naive(), None, None, &bounds.disabled_dates)
+            })
+    }
+
+    fn canonical(&self) -> String {
+        self.fixed_offset().format("%FT%R%:z").to_string()
+    }
+}
+
+fn validation_text<T>(text: &str, locale: &CosmoLocale, options: &T::Options) -> Option<String>
+where
+    T: CosmoTextValue,
+{
+    if text.trim().is_empty() {
+        Some(String::new())
+    } else {
+        T::parse(text, locale, options).map(|value| value.canonical())
+    }
+}
+
+struct CosmoTextInput {
+    text_state: UseStateHandle<AttrValue>,
+    field: CosmoField,
+    oninput: Callback<InputEvent>,
+}
+
+#[hook]
+fn use_text_input<T>(
+    value: Option<T>,
+    options: T::Options,
+    on_input: Callback<Option<T>>,
+    is_optional: bool,
+    invalid_label: AttrValue,
+    validators: Vec<CosmoValidator>,
+) -> CosmoTextInput
+where
+    T: CosmoTextValue,
+{
+    let locale = use_cosmo_locale();
+    let text_state = use_state_eq(|| {
+        AttrValue::from(
+            value
+                .as_ref()
+                .map(|value| value.format(&locale, &options))
+                .unwrap_or_default(),
+        )
+    });
+    let bad_input_state = use_state_eq(|| false);
+    let validation_text = validation_text::<T>(text_state.as_str(), &locale, &options);
+    let is_valid = use_mut_ref(|| true);
+    *is_valid.borrow_mut() = !*bad_input_state
+        && match validation_text.as_deref() {
+            Some("") => is_optional,
+            Some(_) => true,
+            None => false,
+        };
+    let input_validator = use_memo(invalid_label, |invalid_label| {
+        let is_valid = is_valid.clone();
+        let invalid_label = invalid_label.clone();
+        CosmoValidator::custom(move |_| {
+            if *is_valid.borrow() {
+                Ok(())
+            } else {
+                Err(invalid_label.clone())
+            }
+        })
+    });
+    let field = use_cosmo_field(
+        validation_text.unwrap_or_else(|| (*text_state).to_string()),
+        validators
+            .into_iter()
+            .chain(std::iter::once((*input_validator).clone()))
+            .collect(),
+    );
+
+    {
+        let text_state = text_state.clone();
+        use_effect_with(
+            (value, options.clone(), locale.clone()),
+            move |(value, options, locale)| {
+                let text = text_state.as_str();
+                let current = if text.trim().is_empty() {
+                    None
+                } else {
+                    T::parse(text, locale, options)
+                };
+                if current != *value {
+                    text_state.set(AttrValue::from(
+                        value
+                            .as_ref()
+                            .map(|value| value.format(locale, options))
+                            .unwrap_or_default(),
+                    ));
+                }
+            },
+        );
+    }
+
+    let oninput = use_callback(
+        (
+            on_input,
+            text_state.clone(),
+            bad_input_state,
+            locale,
+            options,
+            is_optional,
+        ),
+        |evt: InputEvent, (on_input, text_state, bad_input_state, locale, options, is_optional)| {
+            let input = evt.target_unchecked_into::<HtmlInputElement>();
+            let text = input.value();
+            let is_bad_input = input.validity().bad_input();
+            bad_input_state.set(is_bad_input);
+            if text.trim().is_empty() {
+                if *is_optional && !is_bad_input {
+                    on_input.emit(None);
+                }
+            } else if let Some(value) = T::parse(text.as_str(), locale, options) {
+                on_input.emit(Some(value));
+            }
+            text_state.set(AttrValue::from(text));
+        },
+    );
+
+    CosmoTextInput {
+        text_state,
+        field,
+        oninput,
+    }
+}
+
+#[hook]
+fn use_required_input<T>(on_input: Callback<T>) -> Callback<Option<T>>
+where
+    T: 'static,
+{
+    use_callback(on_input, |value: Option<T>, on_input| {
+        if let Some(value) = value {
+            on_input.emit(value);
+        }
+    })
+}
+
+#[derive(Clone)]
+pub struct CosmoTimeZone<Tz>
+where
+    Tz: TimeZone,
+{
+    pub name: AttrValue,
+    pub zone: Tz,
+}
+
+impl<Tz> PartialEq for CosmoTimeZone<Tz>
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<Tz> CosmoTimeZone<Tz>
+where
+    Tz: TimeZone,
+{
+    pub fn new(name: impl Into<AttrValue>, zone: Tz) -> Self {
+        Self {
+            name: name.into(),
+            zone,
+        }
+    }
+
+    pub fn from_local_datetime(&self, local: &NaiveDateTime) -> Option<DateTime<Tz>> {
+        resolve_local_date_time(&self.zone, local, None)
+    }
+}
+
+fn resolve_local_date_time<Tz>(
+    zone: &Tz,
+    local: &NaiveDateTime,
+    preferred_offset: Option<FixedOffset>,
+) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+{
+    match zone.from_local_datetime(local) {
+        LocalResult::Single(value) => Some(value),
+        LocalResult::Ambiguous(earliest, latest) => {
+            if preferred_offset == Some(latest.offset().fix()) {
+                Some(latest)
+            } else {
+                Some(earliest)
+            }
+        }
+        LocalResult::None => {
+            let offset_before = zone
+                .offset_from_local_datetime(&(*local - Duration::days(1)))
+                .earliest()?;
+
+            Some(zone.from_utc_datetime(&(*local - offset_before.fix())))
+        }
+    }
+}
+
+#[derive(Clone, Properties)]
+struct CosmoDateTimeInputProps<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    on_input: Callback<Option<DateTime<Tz>>>,
+    value: Option<DateTime<Tz>>,
+    zone: Tz,
+    is_optional: bool,
+    label: AttrValue,
+    required: bool,
+    readonly: bool,
+    min: Option<DateTime<Tz>>,
+    max: Option<DateTime<Tz>>,
+    disabled_dates: Option<CosmoDateFilter>,
+    show_week_numbers: bool,
+    id: Option<AttrValue>,
+    width: CosmoInputWidth,
+    validators: Vec<CosmoValidator>,
+    calendar_label: Option<AttrValue>,
+    time_label: Option<AttrValue>,
+    invalid_label: Option<AttrValue>,
+    show_zone: bool,
+    zone_label: Option<AttrValue>,
+    zones: Vec<CosmoTimeZone<Tz>>,
+    zones_label: Option<AttrValue>,
+}
+
+impl<Tz> PartialEq for CosmoDateTimeInputProps<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let now = Utc::now().naive_utc();
+
+        self.on_input == other.on_input
+            && self.value == other.value
+            && self.value.as_ref().map(|value| value.offset().fix())
+                == other.value.as_ref().map(|value| value.offset().fix())
+            && self.zone.offset_from_utc_datetime(&now).fix()
+                == other.zone.offset_from_utc_datetime(&now).fix()
+            && self.is_optional == other.is_optional
+            && self.label == other.label
+            && self.required == other.required
+            && self.readonly == other.readonly
+            && self.min == other.min
+            && self.max == other.max
+            && self.disabled_dates == other.disabled_dates
+            && self.show_week_numbers == other.show_week_numbers
+            && self.id == other.id
+            && self.width == other.width
+            && self.validators == other.validators
+            && self.calendar_label == other.calendar_label
+            && self.time_label == other.time_label
+            && self.invalid_label == other.invalid_label
+            && self.show_zone == other.show_zone
+            && self.zone_label == other.zone_label
+            && self.zones == other.zones
+            && self.zones_label == other.zones_label
+    }
+}
+
+#[styled_component(CosmoDateTimeInput)]
+fn date_time_input<Tz>(props: &CosmoDateTimeInputProps<Tz>) -> Html
+where
+    Tz: TimeZone + 'static,
+    Tz::Offset: std::fmt::Display,
+{
+    let id = use_id(props.id.clone());
+    let locale = use_cosmo_locale();
+    let calendar_label = props
+        .calendar_label
+        .clone()
+        .unwrap_or(locale.labels.calendar.clone());
+    let time_label = props
+        .time_label
+        .clone()
+        .unwrap_or(locale.labels.time.clone());
+    let zones_label = props
+        .zones_label
+        .clone()
+        .unwrap_or(locale.labels.time_zone.clone());
+    let invalid_label = props
+        .invalid_label
+        .clone()
+        .unwrap_or(locale.labels.invalid_date.clone());
+    let selected_zone_state = use_state_eq(|| None as Option<usize>);
+
+    let zone = props
+        .value
+        .as_ref()
+        .map(|value| value.timezone())
+        .or_else(|| {
+            selected_zone_state
+                .and_then(|index| props.zones.get(index))
+                .map(|zone| zone.zone.clone())
+        })
+        .unwrap_or(props.zone.clone());
+    let current = props
+        .value
+        .clone()
+        .unwrap_or_else(|| Utc::now().with_timezone(&zone));
+    let bounds = CosmoZonedBounds {
+        zone: zone.clone(),
+        preferred_offset: current.offset().fix(),
+        min: props.min.clone(),
+        max: props.max.clone(),
+        disabled_dates: props.disabled_dates.clone(),
+    };
+
+    let emit = use_callback(
+        (
+            props.on_input.clone(),
+            props.min.clone(),
+            props.max.clone(),
+            props.disabled_dates.clone(),
+            current.offset().fix(),
+        ),
+        |(naive, zone): (NaiveDateTime, Tz),
+         (on_input, min, max, disabled_dates, current_offset)| {
+            if let Some(value) = resolve_local_date_time(&zone, &naive, Some(*current_offset)) {
+                if min.as_ref().map(|min| value >= *min).unwrap_or(true)
+                    && max.as_ref().map(|max| value <= *max).unwrap_or(true)
+                    && is_date_selectable(&value.date_naive(), None, None, disabled_dates)
+                {
+                    on_input.emit(Some(value));
+                }
+            }
+        },
+    );
+    let input = use_text_input(
+        props.value.clone(),
+        bounds,
+        props.on_input.clone(),
+        props.is_optional,
+        invalid_label,
+        props.validators.clone(),
+    );
+    let field = input.field;
+    let flyout = use_calendar_flyout(field.node_ref.clone());
+
+    let on_select_date = use_callback(
+        (emit.clone(), current.clone(), props.value.is_some()),
+        |date: NaiveDate, (emit, current, has_value)| {
+            let time = if *has_value {
+                current.time()
+            } else {
+                NaiveTime::MIN
+            };
+            emit.emit((date.and_time(time), current.timezone()))
+        },
+    );
+    let on_time_input = use_callback(
+        (emit.clone(), current.clone()),
+        |evt: InputEvent, (emit, current)| {
+            if let Ok(time) = NaiveTime::parse_from_str(
+                evt.target_unchecked_into::<HtmlInputElement>()
+                    .value()
+                    .as_str(),
+                "%R",
+            ) {
+                emit.emit((current.date_naive().and_time(time), current.timezone()));
+            }
+        },
+    );
+    let on_zone_change = use_callback(
+        (
+            emit,
+            props.value.clone(),
+            props.zones.clone(),
+            selected_zone_state.clone(),
+        ),
+        |evt: Event, (emit, value, zones, selected_zone_state)| {
+            let index = evt
+                .target_unchecked_into::<HtmlSelectElement>()
+                .selected_index();
+            if let Some(zone) = usize::try_from(index)
+                .ok()
+                .and_then(|index| zones.get(index).map(|zone| (index, zone)))
+            {
+                selected_zone_state.set(Some(zone.0));
+                if let Some(value) = value {
+                    emit.emit((value.naive_local(), zone.1.zone.clone()));
+                }
+            }
+        },
+    );
+
+    let (label_style, input_style) = use_input_styling(CosmoInputWidth::Full);
+    let (_, time_input_style) = use_input_styling(CosmoInputWidth::Auto);
+    let (_, zone_select_style) = use_input_styling(CosmoInputWidth::Auto);
+    let (holder_style, calendar_input_style, toggle_style, flyout_style) =
+        use_calendar_input_styling(props.width.clone());
+    let row_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+gap: 0.5rem;
+    "#
+    );
+    let field_style = use_style!(
+        r#"
+position: relative;
+flex: 1 1 auto;
+min-width: 0;
+    "#
+    );
+    let zone_style = use_style!(
+        r#"
+flex: 0 0 auto;
+font-size: var(--font-size);
+color: var(--control-border-color-darker);
+white-space: nowrap;
+    "#
+    );
+    let time_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+justify-content: space-between;
+gap: 0.5rem;
+padding: 0 0.5rem 0.5rem;
+font-size: var(--font-size);
+    "#
+    );
+    let calendar_id = AttrValue::from(format!("{id}-calendar"));
+    let time_id = AttrValue::from(format!("{id}-time"));
+    let zone_id = AttrValue::from(format!("{id}-zone"));
+    let is_open = *flyout.open_state && !props.readonly;
+
+    let offset = current.offset().fix();
+    let utc_offset = AttrValue::from(current.format("UTC%:z").to_string());
+    let instant = current.naive_utc();
+    let is_current_zone =
+        |zone: &CosmoTimeZone<Tz>| zone.zone.offset_from_utc_datetime(&instant).fix() == offset;
+    let selected_zone = selected_zone_state
+        .filter(|index| {
+            props
+                .zones
+                .get(*index)
+                .map(is_current_zone)
+                .unwrap_or(false)
+        })
+        .or_else(|| props.zones.iter().position(is_current_zone));
+    let today = Utc::now().with_timezone(&zone).date_naive();
+    let time_value = props
+        .value
+        .as_ref()
+        .map(|value| value.format("%R").to_string())
+        .unwrap_or_default();
+
+    html!(
+        <>
+            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
+            <div ref={flyout.holder_ref.clone()} class={holder_style} onkeydown={flyout.on_keydown.clone()}>
+                <div class={row_style}>
+                    <div class={field_style}>
+                        <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} type="text" autocomplete="off" lang={locale.language.clone()} class={classes!(input_style, calendar_input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} value={(*input.text_state).clone()} oninput={input.oninput} />
+                        <button type="button" class={toggle_style} aria-label={calendar_label.clone()} title={calendar_label.clone()} aria-haspopup="dialog" aria-expanded={is_open.to_string()} aria-controls={calendar_id.clone()} disabled={props.readonly} onclick={flyout.on_toggle.clone()}></button>
+                    </div>
+                    if !props.zones.is_empty() {
+                        <select id={zone_id} aria-label={zones_label.clone()} title={utc_offset.clone()} class={zone_select_style} disabled={props.readonly} onchange={on_zone_change}>
+                            {for props.zones.iter().enumerate().map(|(index, zone)| html!(
+                                <option key={zone.name.to_string()} selected={selected_zone == Some(index)}>{zone.name.clone()}</option>
+                            ))}
+                        </select>
+                    } else if props.show_zone {
+                        <span id={zone_id} class={zone_style} title={utc_offset.clone()}>{props.zone_label.clone().unwrap_or(utc_offset.clone())}</span>
+                    }
+                </div>
+                if is_open {
+                    <div id={calendar_id} role="dialog" aria-label={calendar_label} class={flyout_style}>
+                        <CosmoCalendar value={props.value.as_ref().map(|value| value.date_naive())} min={props.min.as_ref().map(|min| min.date_naive())} max={props.max.as_ref().map(|max| max.date_naive())} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} today={today} on_select={on_select_date} />
+                        <div class={time_style}>
+                            <label for={time_id.clone()}>{time_label}</label>
+                            <input id={time_id} type="time" lang={locale.language.clone()} class={time_input_style} value={time_value} oninput={on_time_input} />
+                        </div>
+                    </div>
+                }
+            </div>
+            {field.error_message(&id)}
+        </>
+    )
+}
+
+#[derive(Clone, Properties)]
+pub struct CosmoZonedDateTimePickerProps<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    pub on_input: Callback<DateTime<Tz>>,
+    pub value: DateTime<Tz>,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub min: Option<DateTime<Tz>>,
+    #[prop_or_default]
+    pub max: Option<DateTime<Tz>>,
+    #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub time_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+    #[prop_or(true)]
+    pub show_zone: bool,
+    #[prop_or_default]
+    pub zone_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub zones: Vec<CosmoTimeZone<Tz>>,
+    #[prop_or_default]
+    pub zones_label: Option<AttrValue>,
+}
+
+impl<Tz> PartialEq for CosmoZonedDateTimePickerProps<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.on_input == other.on_input
+            && self.value == other.value
+            && self.value.offset().fix() == other.value.offset().fix()
+            && self.label == other.label
+            && self.required == other.required
+            && self.readonly == other.readonly
+            && self.min == other.min
+            && self.max == other.max
+            && self.disabled_dates == other.disabled_dates
+            && self.show_week_numbers == other.show_week_numbers
+            && self.id == other.id
+            && self.width == other.width
+            && self.validators == other.validators
+            && self.calendar_label == other.calendar_label
+            && self.time_label == other.time_label
+            && self.invalid_label == other.invalid_label
+            && self.show_zone == other.show_zone
+            && self.zone_label == other.zone_label
+            && self.zones == other.zones
+            && self.zones_label == other.zones_label
+    }
+}
+
+#[function_component(CosmoZonedDateTimePicker)]
+pub fn zoned_date_time_picker<Tz>(props: &CosmoZonedDateTimePickerProps<Tz>) -> Html
+where
+    Tz: TimeZone + 'static,
+    Tz::Offset: std::fmt::Display,
+{
+    let on_input = use_required_input(props.on_input.clone());
+
+    html!(
+        <CosmoDateTimeInput<Tz> on_input={on_input} value={Some(props.value.clone())} zone={props.value.timezone()} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min.clone()} max={props.max.clone()} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} calendar_label={props.calendar_label.clone()} time_label={props.time_label.clone()} invalid_label={props.invalid_label.clone()} show_zone={props.show_zone} zone_label={props.zone_label.clone()} zones={props.zones.clone()} zones_label={props.zones_label.clone()} />
+    )
+}
+
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoDateTimePickerProps {
     pub on_input: Callback<DateTime<Local>>,
@@ -178,38 +1099,157 @@ pub struct CosmoDateTimePickerProps {
     #[prop_or_default]
     pub max: Option<DateTime<Local>>,
     #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub time_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+    #[prop_or(false)]
+    pub show_zone: bool,
 }
 
-#[styled_component(CosmoDateTimePicker)]
+#[function_component(CosmoDateTimePicker)]
 pub fn date_time_picker(props: &CosmoDateTimePickerProps) -> Html {
+    let on_input = use_required_input(props.on_input.clone());
+
+    html!(
+        <CosmoDateTimeInput<Local> on_input={on_input} value={Some(props.value)} zone={Local} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} calendar_label={props.calendar_label.clone()} time_label={props.time_label.clone()} invalid_label={props.invalid_label.clone()} show_zone={props.show_zone} zone_label={None::<AttrValue>} zones={vec![]} zones_label={None::<AttrValue>} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoOptionalDateTimePickerProps {
+    pub on_input: Callback<Option<DateTime<Local>>>,
+    pub value: Option<DateTime<Local>>,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub min: Option<DateTime<Local>>,
+    #[prop_or_default]
+    pub max: Option<DateTime<Local>>,
+    #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub time_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+    #[prop_or(false)]
+    pub show_zone: bool,
+}
+
+#[function_component(CosmoOptionalDateTimePicker)]
+pub fn optional_date_time_picker(props: &CosmoOptionalDateTimePickerProps) -> Html {
+    html!(
+        <CosmoDateTimeInput<Local> on_input={props.on_input.clone()} value={props.value} zone={Local} is_optional={true} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} calendar_label={props.calendar_label.clone()} time_label={props.time_label.clone()} invalid_label={props.invalid_label.clone()} show_zone={props.show_zone} zone_label={None::<AttrValue>} zones={vec![]} zones_label={None::<AttrValue>} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoDateInputProps {
+    on_input: Callback<Option<NaiveDate>>,
+    value: Option<NaiveDate>,
+    is_optional: bool,
+    label: AttrValue,
+    required: bool,
+    readonly: bool,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    disabled_dates: Option<CosmoDateFilter>,
+    show_week_numbers: bool,
+    id: Option<AttrValue>,
+    width: CosmoInputWidth,
+    validators: Vec<CosmoValidator>,
+    calendar_label: Option<AttrValue>,
+    invalid_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoDateInput)]
+fn date_input(props: &CosmoDateInputProps) -> Html {
     let id = use_id(props.id.clone());
-    let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
-        let naive = NaiveDateTime::parse_from_str(
-            evt.target_unchecked_into::<HtmlInputElement>()
-                .value()
-                .as_str(),
-            "%FT%R",
-        );
+    let locale = use_cosmo_locale();
+    let calendar_label = props
+        .calendar_label
+        .clone()
+        .unwrap_or(locale.labels.calendar.clone());
+    let invalid_label = props
+        .invalid_label
+        .clone()
+        .unwrap_or(locale.labels.invalid_date.clone());
+    let input = use_text_input(
+        props.value,
+        CosmoValueBounds {
+            min: props.min,
+            max: props.max,
+            disabled_dates: props.disabled_dates.clone(),
+        },
+        props.on_input.clone(),
+        props.is_optional,
+        invalid_label,
+        props.validators.clone(),
+    );
+    let field = input.field;
+    let flyout = use_calendar_flyout(field.node_ref.clone());
 
-        if let Ok(naive) = naive {
-            let local = Local {};
-            props
-                .on_input
-                .emit(local.from_local_datetime(&naive).unwrap())
-        }
-    });
+    let on_select = use_callback(
+        (
+            props.on_input.clone(),
+            input.text_state.clone(),
+            flyout.open_state.clone(),
+            field.node_ref.clone(),
+            locale.clone(),
+        ),
+        |date: NaiveDate, (on_input, text_state, open_state, input_ref, locale)| {
+            text_state.set(AttrValue::from(locale.format_date(&date)));
+            open_state.set(false);
+            on_input.emit(Some(date));
+            if let Some(input) = input_ref.cast::<HtmlElement>() {
+                let _ = input.focus();
+            }
+        },
+    );
 
-    let (label_style, input_style) = use_input_styling(props.width.clone());
-    let min = props.min.map(|min| min.clone().format("%FT%R").to_string());
-    let max = props.max.map(|max| max.clone().format("%FT%R").to_string());
+    let (label_style, input_style) = use_input_styling(CosmoInputWidth::Full);
+    let (holder_style, calendar_input_style, toggle_style, flyout_style) =
+        use_calendar_input_styling(props.width.clone());
+    let calendar_id = AttrValue::from(format!("{id}-calendar"));
+    let is_open = *flyout.open_state && !props.readonly;
 
     html!(
         <>
             <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input min={min} max={max} type="datetime-local" class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} value={props.value.clone().format("%FT%R").to_string()} oninput={oninput} />
+            <div ref={flyout.holder_ref.clone()} class={holder_style} onkeydown={flyout.on_keydown.clone()}>
+                <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} type="text" autocomplete="off" lang={locale.language.clone()} class={classes!(input_style, calendar_input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} value={(*input.text_state).clone()} oninput={input.oninput} />
+                <button type="button" class={toggle_style} aria-label={calendar_label.clone()} title={calendar_label.clone()} aria-haspopup="dialog" aria-expanded={is_open.to_string()} aria-controls={calendar_id.clone()} disabled={props.readonly} onclick={flyout.on_toggle.clone()}></button>
+                if is_open {
+                    <div id={calendar_id} role="dialog" aria-label={calendar_label} class={flyout_style}>
+                        <CosmoCalendar value={props.value} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} on_select={on_select} />
+                    </div>
+                }
+            </div>
+            {field.error_message(&id)}
         </>
     )
 }
@@ -228,42 +1268,271 @@ pub struct CosmoDatePickerProps {
     #[prop_or_default]
     pub max: Option<NaiveDate>,
     #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
 }
 
-#[styled_component(CosmoDatePicker)]
+#[function_component(CosmoDatePicker)]
 pub fn date_picker(props: &CosmoDatePickerProps) -> Html {
+    let on_input = use_required_input(props.on_input.clone());
+
+    html!(
+        <CosmoDateInput on_input={on_input} value={Some(props.value)} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} calendar_label={props.calendar_label.clone()} invalid_label={props.invalid_label.clone()} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoOptionalDatePickerProps {
+    pub on_input: Callback<Option<NaiveDate>>,
+    pub value: Option<NaiveDate>,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub min: Option<NaiveDate>,
+    #[prop_or_default]
+    pub max: Option<NaiveDate>,
+    #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+}
+
+#[function_component(CosmoOptionalDatePicker)]
+pub fn optional_date_picker(props: &CosmoOptionalDatePickerProps) -> Html {
+    html!(
+        <CosmoDateInput on_input={props.on_input.clone()} value={props.value} is_optional={true} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} calendar_label={props.calendar_label.clone()} invalid_label={props.invalid_label.clone()} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoDateRangePickerProps {
+    pub on_input: Callback<(NaiveDate, NaiveDate)>,
+    pub value: (NaiveDate, NaiveDate),
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub min: Option<NaiveDate>,
+    #[prop_or_default]
+    pub max: Option<NaiveDate>,
+    #[prop_or_default]
+    pub disabled_dates: Option<CosmoDateFilter>,
+    #[prop_or(false)]
+    pub show_week_numbers: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub calendar_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoDateRangePicker)]
+pub fn date_range_picker(props: &CosmoDateRangePickerProps) -> Html {
     let id = use_id(props.id.clone());
-    let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
-        props.on_input.emit(
-            NaiveDate::parse_from_str(
-                evt.target_unchecked_into::<HtmlInputElement>()
-                    .value()
-                    .as_str(),
-                "%F",
-            )
-            .unwrap_or(props.value),
-        )
-    });
+    let locale = use_cosmo_locale();
+    let calendar_label = props
+        .calendar_label
+        .clone()
+        .unwrap_or(locale.labels.calendar.clone());
+    let invalid_label = props
+        .invalid_label
+        .clone()
+        .unwrap_or(locale.labels.invalid_date.clone());
+    let bounds = CosmoValueBounds {
+        min: props.min,
+        max: props.max,
+        disabled_dates: props.disabled_dates.clone(),
+    };
+    let on_input = use_required_input(props.on_input.clone());
+    let input = use_text_input(
+        Some(props.value),
+        bounds.clone(),
+        on_input,
+        false,
+        invalid_label,
+        props.validators.clone(),
+    );
+    let field = input.field;
+    let pending_state = use_state_eq(|| None as Option<NaiveDate>);
+    let flyout = use_calendar_flyout(field.node_ref.clone());
+
+    {
+        let pending_state = pending_state.clone();
+        use_effect_with(*flyout.open_state, move |_| pending_state.set(None));
+    }
+
+    let on_select = use_callback(
+        (
+            props.on_input.clone(),
+            pending_state.clone(),
+            input.text_state.clone(),
+            flyout.open_state.clone(),
+            field.node_ref.clone(),
+            locale.clone(),
+            bounds,
+        ),
+        move |date: NaiveDate,
+              (on_input, pending_state, text_state, open_state, input_ref, locale, bounds)| {
+            if let Some(start) = **pending_state {
+                let value = (start.min(date), start.max(date));
+                text_state.set(AttrValue::from(value.format(locale, bounds)));
+                pending_state.set(None);
+                open_state.set(false);
+                on_input.emit(value);
+                if let Some(input) = input_ref.cast::<HtmlElement>() {
+                    let _ = input.focus();
+                }
+            } else {
+                pending_state.set(Some(date));
+            }
+        },
+    );
+
+    let (label_style, input_style) = use_input_styling(CosmoInputWidth::Full);
+    let (holder_style, calendar_input_style, toggle_style, flyout_style) =
+        use_calendar_input_styling(props.width.clone());
+    let calendar_id = AttrValue::from(format!("{id}-calendar"));
+    let is_open = *flyout.open_state && !props.readonly;
+    let (calendar_value, calendar_range_end) = match *pending_state {
+        Some(start) => (start, None),
+        None => (props.value.0, Some(props.value.1)),
+    };
+
+    html!(
+        <>
+            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
+            <div ref={flyout.holder_ref.clone()} class={holder_style} onkeydown={flyout.on_keydown.clone()}>
+                <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} type="text" autocomplete="off" lang={locale.language.clone()} class={classes!(input_style, calendar_input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} value={(*input.text_state).clone()} oninput={input.oninput} />
+                <button type="button" class={toggle_style} aria-label={calendar_label.clone()} title={calendar_label.clone()} aria-haspopup="dialog" aria-expanded={is_open.to_string()} aria-controls={calendar_id.clone()} disabled={props.readonly} onclick={flyout.on_toggle.clone()}></button>
+                if is_open {
+                    <div id={calendar_id} role="dialog" aria-label={calendar_label} class={flyout_style}>
+                        <CosmoCalendar value={calendar_value} range_end={calendar_range_end} min={props.min} max={props.max} disabled_dates={props.disabled_dates.clone()} show_week_numbers={props.show_week_numbers} show_today={false} on_select={on_select} />
+                    </div>
+                }
+            </div>
+            {field.error_message(&id)}
+        </>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoTimeInputProps {
+    on_input: Callback<Option<NaiveTime>>,
+    value: Option<NaiveTime>,
+    is_optional: bool,
+    label: AttrValue,
+    required: bool,
+    readonly: bool,
+    min: Option<NaiveTime>,
+    max: Option<NaiveTime>,
+    id: Option<AttrValue>,
+    width: CosmoInputWidth,
+    validators: Vec<CosmoValidator>,
+    invalid_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoTimeInput)]
+fn time_input(props: &CosmoTimeInputProps) -> Html {
+    let id = use_id(props.id.clone());
+    let locale = use_cosmo_locale();
+    let invalid_label = props
+        .invalid_label
+        .clone()
+        .unwrap_or(locale.labels.invalid_time.clone());
+    let input = use_text_input(
+        props.value,
+        CosmoValueBounds {
+            min: props.min,
+            max: props.max,
+            disabled_dates: None,
+        },
+        props.on_input.clone(),
+        props.is_optional,
+        invalid_label,
+        props.validators.clone(),
+    );
+    let field = input.field;
+
+    let (label_style, input_style) = use_input_styling(props.width.clone());
+    let min = props.min.map(|min| min.canonical());
+    let max = props.max.map(|max| max.canonical());
+
+    html!(
+        <>
+            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
+            <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} min={min} max={max} type="time" lang={locale.language.clone()} class={classes!(input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} value={(*input.text_state).clone()} oninput={input.oninput} />
+            {field.error_message(&id)}
+        </>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoTimePickerProps {
+    pub on_input: Callback<NaiveTime>,
+    pub value: NaiveTime,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub min: Option<NaiveTime>,
+    #[prop_or_default]
+    pub max: Option<NaiveTime>,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+}
 
-    let (label_style, input_style) = use_input_styling(props.width.clone());
-    let min = props.min.map(|min| min.clone().format("%F").to_string());
-    let max = props.max.map(|max| max.clone().format("%F").to_string());
+#[function_component(CosmoTimePicker)]
+pub fn time_picker(props: &CosmoTimePickerProps) -> Html {
+    let on_input = use_required_input(props.on_input.clone());
 
     html!(
-        <>
-            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input min={min} max={max} type="date" class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} value={props.value.clone().format("%F").to_string()} oninput={oninput} />
-        </>
+        <CosmoTimeInput on_input={on_input} value={Some(props.value)} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
     )
 }
 
 #[derive(PartialEq, Clone, Properties)]
-pub struct CosmoTimePickerProps {
-    pub on_input: Callback<NaiveTime>,
-    pub value: NaiveTime,
+pub struct CosmoOptionalTimePickerProps {
+    pub on_input: Callback<Option<NaiveTime>>,
+    pub value: Option<NaiveTime>,
     pub label: AttrValue,
     #[prop_or(false)]
     pub required: bool,
@@ -277,32 +1546,16 @@ pub struct CosmoTimePickerProps {
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
 }
 
-#[styled_component(CosmoTimePicker)]
-pub fn time_picker(props: &CosmoTimePickerProps) -> Html {
-    let id = use_id(props.id.clone());
-    let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
-        props.on_input.emit(
-            NaiveTime::parse_from_str(
-                evt.target_unchecked_into::<HtmlInputElement>()
-                    .value()
-                    .as_str(),
-                "%R",
-            )
-            .unwrap_or(props.value),
-        )
-    });
-
-    let (label_style, input_style) = use_input_styling(props.width.clone());
-    let min = props.min.map(|min| min.clone().format("%R").to_string());
-    let max = props.max.map(|max| max.clone().format("%R").to_string());
-
+#[function_component(CosmoOptionalTimePicker)]
+pub fn optional_time_picker(props: &CosmoOptionalTimePickerProps) -> Html {
     html!(
-        <>
-            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input min={min} max={max} type="time" class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} value={props.value.clone().format("%R").to_string()} oninput={oninput} />
-        </>
+        <CosmoTimeInput on_input={props.on_input.clone()} value={props.value} is_optional={true} label={props.label.clone()} required={props.required} readonly={props.readonly} min={props.min} max={props.max} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
     )
 }
 
@@ -317,17 +1570,16 @@ pub enum CosmoTextBoxType {
     Url,
 }
 
-impl ToString for CosmoTextBoxType {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for CosmoTextBoxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             CosmoTextBoxType::Email => "email",
             CosmoTextBoxType::Password => "password",
             CosmoTextBoxType::Search => "search",
             CosmoTextBoxType::Tel => "tel",
             CosmoTextBoxType::Text => "text",
             CosmoTextBoxType::Url => "url",
-        }
-        .to_string()
+        })
     }
 }
 
@@ -346,11 +1598,14 @@ pub struct CosmoTextBoxProps {
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[styled_component(CosmoTextBox)]
 pub fn textbox(props: &CosmoTextBoxProps) -> Html {
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(props.value.to_string(), props.validators.clone());
     let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
         props.on_input.emit(
             evt.target_unchecked_into::<HtmlInputElement>()
@@ -364,14 +1619,102 @@ pub fn textbox(props: &CosmoTextBoxProps) -> Html {
     html!(
         <>
             <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} type={props.input_type.to_string()} value={props.value.clone()} oninput={oninput} />
+            <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} class={classes!(input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} type={props.input_type.to_string()} value={props.value.clone()} oninput={oninput} />
+            {field.error_message(&id)}
         </>
     )
 }
 
+#[derive(PartialEq, Clone)]
+pub enum CosmoFileRejectReason {
+    Type,
+    Size,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoRejectedFile {
+    pub file: File,
+    pub reason: CosmoFileRejectReason,
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoSelectedFile {
+    key: usize,
+    file: File,
+    preview: Option<String>,
+}
+
+impl CosmoSelectedFile {
+    fn new(key: usize, file: File) -> Self {
+        let preview = if file.type_().starts_with("image/") {
+            Url::create_object_url_with_blob(&file).ok()
+        } else {
+            None
+        };
+
+        Self { key, file, preview }
+    }
+
+    fn revoke_preview(&self) {
+        if let Some(preview) = &self.preview {
+            let _ = Url::revoke_object_url(preview);
+        }
+    }
+}
+
+fn is_file_accepted(file: &File, accept: &str) -> bool {
+    let name = file.name().to_lowercase();
+    let mime_type = file.type_().to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(pattern.as_str())
+        } else if let Some(group) = pattern.strip_suffix("/*") {
+            mime_type.starts_with(format!("{group}/").as_str())
+        } else {
+            mime_type == pattern
+        }
+    })
+}
+
+fn format_file_size(size: f64) -> String {
+    let units = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = size;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{size} {}", units[unit])
+    } else {
+        format!("{size:.1} {}", units[unit])
+    }
+}
+
+fn file_list_to_vec(files: FileList) -> Vec<File> {
+    (0..files.length())
+        .filter_map(|idx| files.get(idx))
+        .collect()
+}
+
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoFilePickerProps {
+    #[prop_or_default]
     pub on_select: Callback<File>,
+    #[prop_or_default]
+    pub on_select_files: Option<Callback<Vec<File>>>,
+    #[prop_or_default]
+    pub on_reject: Option<Callback<Vec<CosmoRejectedFile>>>,
     pub label: AttrValue,
     #[prop_or(false)]
     pub required: bool,
@@ -382,19 +1725,197 @@ pub struct CosmoFilePickerProps {
     #[prop_or_default]
     pub accept: AttrValue,
     #[prop_or_default]
+    pub max_file_size: Option<u64>,
+    #[prop_or(false)]
+    pub multiple: bool,
+    #[prop_or(false)]
+    pub is_drop_zone: bool,
+    #[prop_or_default]
+    pub drop_zone_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub remove_label: Option<AttrValue>,
+    #[prop_or_default]
     pub width: CosmoInputWidth,
 }
 
 #[styled_component(CosmoFilePicker)]
 pub fn file_picker(props: &CosmoFilePickerProps) -> Html {
     let id = use_id(props.id.clone());
-    let onchange = use_callback(props.clone(), |evt: Event, props| {
-        if let Some(files) = evt.target_unchecked_into::<HtmlInputElement>().files() {
-            if let Some(file) = files.get(0) {
-                props.on_select.emit(file);
+    let locale = use_cosmo_locale();
+    let drop_zone_label = props
+        .drop_zone_label
+        .clone()
+        .unwrap_or(locale.labels.drop_files.clone());
+    let remove_label = props
+        .remove_label
+        .clone()
+        .unwrap_or(locale.labels.remove.clone());
+    let selected_files = use_mut_ref(Vec::<CosmoSelectedFile>::new);
+    let next_key = use_mut_ref(|| 0usize);
+    let drag_over_state = use_state_eq(|| false);
+    let update = use_state(|| ());
+
+    let show_file_list = props.multiple || props.is_drop_zone;
+
+    {
+        let selected_files = selected_files.clone();
+        use_unmount(move || {
+            for selected_file in selected_files.borrow().iter() {
+                selected_file.revoke_preview();
             }
-        }
+        });
+    }
+
+    let on_files = use_callback(
+        (
+            props.clone(),
+            selected_files.clone(),
+            next_key.clone(),
+            update.clone(),
+        ),
+        |files: Vec<File>, (props, selected_files, next_key, update)| {
+            let (accepted, rejected): (Vec<_>, Vec<_>) = files
+                .into_iter()
+                .map(|file| {
+                    if !is_file_accepted(&file, props.accept.as_str()) {
+                        Err(CosmoRejectedFile {
+                            file,
+                            reason: CosmoFileRejectReason::Type,
+                        })
+                    } else if props
+                        .max_file_size
+                        .map(|max_file_size| file.size() > max_file_size as f64)
+                        .unwrap_or(false)
+                    {
+                        Err(CosmoRejectedFile {
+                            file,
+                            reason: CosmoFileRejectReason::Size,
+                        })
+                    } else {
+                        Ok(file)
+                    }
+                })
+                .partition(Result::is_ok);
+            let mut accepted = accepted
+                .into_iter()
+                .filter_map(Result::ok)
+                .collect::<Vec<_>>();
+            let rejected = rejected
+                .into_iter()
+                .filter_map(Result::err)
+                .collect::<Vec<_>>();
+
+            if !props.multiple {
+                accepted.truncate(1);
+            }
+
+            let show_file_list = props.multiple || props.is_drop_zone;
+
+            if !rejected.is_empty() {
+                if let Some(on_reject) = props.on_reject.clone() {
+                    on_reject.emit(rejected);
+                }
+            }
+
+            if accepted.is_empty() {
+                return;
+            }
+
+            props.on_select.emit(accepted[0].clone());
+            if show_file_list {
+                let mut selected_files = selected_files.borrow_mut();
+                if !props.multiple {
+                    for selected_file in selected_files.drain(..) {
+                        selected_file.revoke_preview();
+                    }
+                }
+                for file in accepted.iter() {
+                    let mut next_key = next_key.borrow_mut();
+                    selected_files.push(CosmoSelectedFile::new(*next_key, file.clone()));
+                    *next_key += 1;
+                }
+                update.set(());
+            }
+
+            if let Some(on_select_files) = props.on_select_files.clone() {
+                if show_file_list {
+                    on_select_files.emit(
+                        selected_files
+                            .borrow()
+                            .iter()
+                            .map(|selected_file| selected_file.file.clone())
+                            .collect(),
+                    );
+                } else {
+                    on_select_files.emit(accepted);
+                }
+            }
+        },
+    );
+    let on_remove = use_callback(
+        (
+            props.on_select_files.clone(),
+            selected_files.clone(),
+            update.clone(),
+        ),
+        |key: usize, (on_select_files, selected_files, update)| {
+            selected_files.borrow_mut().retain(|selected_file| {
+                if selected_file.key == key {
+                    selected_file.revoke_preview();
+                    false
+                } else {
+                    true
+                }
+            });
+            update.set(());
+
+            if let Some(on_select_files) = on_select_files {
+                on_select_files.emit(
+                    selected_files
+                        .borrow()
+                        .iter()
+                        .map(|selected_file| selected_file.file.clone())
+                        .collect(),
+                );
+            }
+        },
+    );
+    let onchange = use_callback(
+        (on_files.clone(), show_file_list),
+        |evt: Event, (on_files, show_file_list)| {
+            let input = evt.target_unchecked_into::<HtmlInputElement>();
+            if let Some(files) = input.files() {
+                on_files.emit(file_list_to_vec(files));
+            }
+            if *show_file_list {
+                input.set_value("");
+            }
+        },
+    );
+    let ondragover = use_callback(
+        (drag_over_state.clone(), props.readonly),
+        |evt: DragEvent, (drag_over_state, readonly)| {
+            evt.prevent_default();
+            drag_over_state.set(!*readonly);
+        },
+    );
+    let ondragleave = use_callback(drag_over_state.clone(), |_: DragEvent, drag_over_state| {
+        drag_over_state.set(false)
     });
+    let ondrop = use_callback(
+        (drag_over_state.clone(), on_files.clone(), props.readonly),
+        |evt: DragEvent, (drag_over_state, on_files, readonly)| {
+            evt.prevent_default();
+            drag_over_state.set(false);
+            if *readonly {
+                return;
+            }
+
+            if let Some(files) = evt.data_transfer().and_then(|transfer| transfer.files()) {
+                on_files.emit(file_list_to_vec(files));
+            }
+        },
+    );
 
     let (label_style, input_style) = use_input_styling(props.width.clone());
     let file_picker_style = use_style!(
@@ -408,16 +1929,16 @@ padding: 0;
 	--button-color: var(--black);
 
 	border-top: 0;
-	border-left: 0;
+	border-inline-start: 0;
 	border-bottom: 0;
 	cursor: pointer;
 	font-family: var(--font-family);
 	font-size: var(--font-size);
-	padding: var(--button-padding-top) var(--button-padding-right) var(--button-padding-bottom)
-		var(--button-padding-left);
+	padding-block: var(--button-padding-top) var(--button-padding-bottom);
+	padding-inline: var(--button-padding-left) var(--button-padding-right);
 	box-sizing: border-box;
 	border: none;
-	border-right: var(--button-border-width) solid var(--button-border-color);
+	border-inline-end: var(--button-border-width) solid var(--button-border-color);
 	background: var(--button-background);
 	color: var(--button-color);
 	line-height: var(--line-height);
@@ -459,7 +1980,7 @@ padding: 0;
 &:not(:disabled):active::-webkit-file-upload-button {
 	--button-border-color: var(--control-border-color-darker);
 	--button-background: var(--control-border-color-darker);
-	--button-color: var(--white);
+	--button-color: var(--control-border-color-darker-foreground);
 }
 
 &:not(:disabled):active {
@@ -467,11 +1988,215 @@ padding: 0;
 }
     "#
     );
+    let drop_zone_style = use_style!(
+        r#"
+display: flex;
+flex-direction: column;
+align-items: center;
+justify-content: center;
+gap: var(--input-group-special-gap);
+min-width: ${width};
+width: ${width};
+min-height: var(--file-drop-zone-min-height);
+box-sizing: border-box;
+padding-block: var(--input-padding-top) var(--input-padding-bottom);
+padding-inline: var(--input-padding-left) var(--input-padding-right);
+border: var(--input-border-width) dashed var(--control-border-color);
+border-radius: var(--border-radius);
+background: var(--white);
+color: var(--black);
+font-family: var(--font-family);
+font-size: var(--font-size);
+cursor: pointer;
+transition:
+    border-color var(--transition-duration),
+    background-color var(--transition-duration);
+
+&:focus-within {
+    border-color: var(--primary-color);
+}
+
+input {
+    position: absolute;
+    width: 1px;
+    height: 1px;
+    opacity: 0;
+    overflow: hidden;
+}
+    "#,
+        width = props.width.to_string()
+    );
+    let drop_zone_active_style = use_style!(
+        r#"
+border-color: var(--primary-color);
+background: var(--primary-color-alpha-25);
+    "#
+    );
+    let drop_zone_disabled_style = use_style!(
+        r#"
+cursor: not-allowed;
+border-color: var(--disabled-color);
+    "#
+    );
+    let file_list_style = use_style!(
+        r#"
+grid-column: 2 / 3;
+display: flex;
+flex-direction: column;
+gap: var(--input-group-special-gap);
+margin: 0;
+padding: 0;
+list-style: none;
+    "#
+    );
+    let file_list_item_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+gap: var(--input-group-special-gap);
+font-family: var(--font-family);
+font-size: var(--font-size);
+    "#
+    );
+    let file_preview_style = use_style!(
+        r#"
+width: var(--file-preview-size);
+height: var(--file-preview-size);
+object-fit: cover;
+border-radius: var(--border-radius);
+border: var(--input-border-width) solid var(--control-border-color);
+box-sizing: border-box;
+flex: 0 0 auto;
+    "#
+    );
+    let file_details_style = use_style!(
+        r#"
+display: flex;
+flex-direction: column;
+flex: 1 1 auto;
+min-width: 0;
+overflow: hidden;
+text-overflow: ellipsis;
+    "#
+    );
+    let file_meta_style = use_style!(
+        r#"
+font-size: var(--input-error-font-size);
+color: var(--control-border-color-darker);
+    "#
+    );
+
+    let input = html!(
+        <input class={classes!((!props.is_drop_zone).then_some(input_style), (!props.is_drop_zone).then_some(file_picker_style))} accept={props.accept.clone()} onchange={onchange} readonly={props.readonly} disabled={props.readonly && props.is_drop_zone} id={id.clone()} required={props.required} multiple={props.multiple} type="file" />
+    );
+
+    html!(
+        <>
+            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
+            if props.is_drop_zone {
+                <label for={id.clone()} class={classes!(drop_zone_style, (*drag_over_state).then_some(drop_zone_active_style), props.readonly.then_some(drop_zone_disabled_style))} ondragover={ondragover} ondragleave={ondragleave} ondrop={ondrop}>
+                    {input}
+                    <span>{drop_zone_label}</span>
+                </label>
+            } else {
+                {input}
+            }
+            if show_file_list && !selected_files.borrow().is_empty() {
+                <ul class={file_list_style}>
+                    {for selected_files.borrow().iter().map(|selected_file| {
+                        let on_remove = on_remove.clone();
+                        let key = selected_file.key;
+
+                        html!(
+                            <li key={key} class={file_list_item_style.clone()}>
+                                if let Some(preview) = selected_file.preview.clone() {
+                                    <img class={file_preview_style.clone()} src={preview} alt="" />
+                                }
+                                <div class={file_details_style.clone()}>
+                                    <span>{selected_file.file.name()}</span>
+                                    <span class={file_meta_style.clone()}>
+                                        {format_file_size(selected_file.file.size())}
+                                        if !selected_file.file.type_().is_empty() {
+                                            {format!(", {}", selected_file.file.type_())}
+                                        }
+                                    </span>
+                                </div>
+                                <CosmoButton label={remove_label.clone()} enabled={!props.readonly} on_click={move |_| on_remove.emit(key)} />
+                            </li>
+                        )
+                    })}
+                </ul>
+            }
+        </>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoNumericInputProps<T>
+where
+    T: CosmoTextValue<Options = CosmoNumberFormat>,
+{
+    on_input: Callback<Option<T>>,
+    value: Option<T>,
+    decimal_places: Option<u8>,
+    grouping: bool,
+    is_optional: bool,
+    label: AttrValue,
+    required: bool,
+    readonly: bool,
+    id: Option<AttrValue>,
+    width: CosmoInputWidth,
+    validators: Vec<CosmoValidator>,
+    invalid_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoNumericInput)]
+fn numeric_input<T>(props: &CosmoNumericInputProps<T>) -> Html
+where
+    T: CosmoTextValue<Options = CosmoNumberFormat>,
+{
+    let id = use_id(props.id.clone());
+    let locale = use_cosmo_locale();
+    let invalid_label = props
+        .invalid_label
+        .clone()
+        .unwrap_or(locale.labels.invalid_number.clone());
+    let is_native =
+        !props.grouping && (props.decimal_places.is_none() || locale.decimal_separator == '.');
+    let input = use_text_input(
+        props.value.clone(),
+        CosmoNumberFormat {
+            decimal_places: props.decimal_places.unwrap_or(0),
+            grouping: props.grouping,
+            is_native,
+        },
+        props.on_input.clone(),
+        props.is_optional,
+        invalid_label,
+        props.validators.clone(),
+    );
+    let field = input.field;
+
+    let (label_style, input_style) = use_input_styling(props.width.clone());
+    let step =
+        props
+            .decimal_places
+            .filter(|_| is_native)
+            .map(|decimal_places| match decimal_places {
+                0 => String::from("1"),
+                decimal_places => format!("0.{}1", "0".repeat(decimal_places as usize - 1)),
+            });
+    let (input_type, inputmode) = match (is_native, props.decimal_places) {
+        (true, _) => ("number", None),
+        (false, Some(_)) => ("text", Some("decimal")),
+        (false, None) => ("text", Some("numeric")),
+    };
 
     html!(
         <>
             <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input class={classes!(input_style, file_picker_style)} accept={props.accept.clone()} onchange={onchange} readonly={props.readonly} id={id.clone()} required={props.required} type="file" />
+            <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} step={step} class={classes!(input_style, field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} type={input_type} inputmode={inputmode} value={(*input.text_state).clone()} oninput={input.oninput} />
+            {field.error_message(&id)}
         </>
     )
 }
@@ -487,29 +2212,50 @@ pub struct CosmoNumberBoxProps {
     pub readonly: bool,
     #[prop_or_default]
     pub id: Option<AttrValue>,
+    #[prop_or(false)]
+    pub grouping: bool,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
 }
 
-#[styled_component(CosmoNumberBox)]
+#[function_component(CosmoNumberBox)]
 pub fn number_box(props: &CosmoNumberBoxProps) -> Html {
-    let id = use_id(props.id.clone());
-    let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
-        props.on_input.emit(
-            evt.target_unchecked_into::<HtmlInputElement>()
-                .value()
-                .as_str()
-                .parse::<i64>()
-                .unwrap_or(props.value),
-        )
-    });
+    let on_input = use_required_input(props.on_input.clone());
 
-    let (label_style, input_style) = use_input_styling(props.width.clone());
     html!(
-        <>
-            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} type="number" value={props.value.to_string()} oninput={oninput} />
-        </>
+        <CosmoNumericInput<i64> on_input={on_input} value={Some(props.value)} decimal_places={None::<u8>} grouping={props.grouping} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoOptionalNumberBoxProps {
+    pub on_input: Callback<Option<i64>>,
+    pub value: Option<i64>,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or(false)]
+    pub grouping: bool,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+}
+
+#[function_component(CosmoOptionalNumberBox)]
+pub fn optional_number_box(props: &CosmoOptionalNumberBoxProps) -> Html {
+    html!(
+        <CosmoNumericInput<i64> on_input={props.on_input.clone()} value={props.value} decimal_places={None::<u8>} grouping={props.grouping} is_optional={true} label={props.label.clone()} required={props.required} readonly={props.readonly} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
     )
 }
 
@@ -526,37 +2272,52 @@ pub struct CosmoDecimalBoxProps {
     pub id: Option<AttrValue>,
     #[prop_or(2)]
     pub decimal_places: u8,
+    #[prop_or(false)]
+    pub grouping: bool,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
 }
 
-#[styled_component(CosmoDecimalBox)]
+#[function_component(CosmoDecimalBox)]
 pub fn decimal_box(props: &CosmoDecimalBoxProps) -> Html {
-    let id = use_id(props.id.clone());
-    let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
-        props.on_input.emit(
-            evt.target_unchecked_into::<HtmlInputElement>()
-                .value()
-                .as_str()
-                .parse::<f64>()
-                .unwrap_or(props.value),
-        )
-    });
+    let on_input = use_required_input(props.on_input.clone());
 
-    let (label_style, input_style) = use_input_styling(props.width.clone());
-    let decimal_places = format!(
-        "0.{}1",
-        (0..props.decimal_places - 1)
-            .map(|_| "0")
-            .collect::<Vec<&str>>()
-            .join("")
-    );
+    html!(
+        <CosmoNumericInput<f64> on_input={on_input} value={Some(props.value)} decimal_places={props.decimal_places} grouping={props.grouping} is_optional={false} label={props.label.clone()} required={props.required} readonly={props.readonly} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoOptionalDecimalBoxProps {
+    pub on_input: Callback<Option<f64>>,
+    pub value: Option<f64>,
+    pub label: AttrValue,
+    #[prop_or(false)]
+    pub required: bool,
+    #[prop_or(false)]
+    pub readonly: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or(2)]
+    pub decimal_places: u8,
+    #[prop_or(false)]
+    pub grouping: bool,
+    #[prop_or_default]
+    pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub invalid_label: Option<AttrValue>,
+}
 
+#[function_component(CosmoOptionalDecimalBox)]
+pub fn optional_decimal_box(props: &CosmoOptionalDecimalBoxProps) -> Html {
     html!(
-        <>
-            <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <input step={decimal_places} class={input_style} readonly={props.readonly} id={id.clone()} required={props.required} type="number" value={props.value.to_string()} oninput={oninput} />
-        </>
+        <CosmoNumericInput<f64> on_input={props.on_input.clone()} value={props.value} decimal_places={props.decimal_places} grouping={props.grouping} is_optional={true} label={props.label.clone()} required={props.required} readonly={props.readonly} id={props.id.clone()} width={props.width.clone()} validators={props.validators.clone()} invalid_label={props.invalid_label.clone()} />
     )
 }
 
@@ -577,11 +2338,14 @@ pub struct CosmoTextAreaProps {
     pub is_monospace: bool,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[styled_component(CosmoTextArea)]
 pub fn textarea(props: &CosmoTextAreaProps) -> Html {
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(props.value.to_string(), props.validators.clone());
     let oninput = use_callback(props.clone(), |evt: InputEvent, props| {
         props.on_input.emit(
             evt.target_unchecked_into::<HtmlTextAreaElement>()
@@ -601,19 +2365,17 @@ align-self: baseline;
 height: unset;
     "#
     );
-    let mut textarea_monospace_style = Some(use_style!(
+    let textarea_monospace_style = use_style!(
         r#"
 font-family: var(--font-family-code);
     "#
-    ));
-    if !props.is_monospace {
-        textarea_monospace_style = None;
-    }
+    );
 
     html!(
         <>
             <label class={classes!(label_style, textarea_label_style)} for={id.clone()}>{props.label.clone()}</label>
-            <textarea rows={AttrValue::from(props.rows.to_string())} class={classes!(input_style, textarea_style, textarea_monospace_style)} readonly={props.readonly} id={id.clone()} required={props.required} oninput={oninput} value={props.value.clone()}></textarea>
+            <textarea ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} rows={AttrValue::from(props.rows.to_string())} class={classes!(input_style, textarea_style, props.is_monospace.then_some(textarea_monospace_style), field.invalid_class.clone())} readonly={props.readonly} id={id.clone()} required={props.required} oninput={oninput} value={props.value.clone()}></textarea>
+            {field.error_message(&id)}
         </>
     )
 }
@@ -717,6 +2479,8 @@ pub struct CosmoCheckboxProps {
     pub readonly: bool,
     #[prop_or_default]
     pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[function_component(CosmoCheckbox)]
@@ -746,7 +2510,7 @@ pub fn checkbox(props: &CosmoCheckboxProps) -> Html {
 	border: var(--input-border-width) solid var(--border-indicator-color);
 	height: var(--checkbox-size);
 	width: var(--checkbox-size);
-	margin-right: calc(var(--checkbox-size) / 2);
+	margin-inline-end: calc(var(--checkbox-size) / 2);
 	border-radius: var(--border-radius);
 	background: var(--white);
 }
@@ -783,6 +2547,10 @@ pub fn checkbox(props: &CosmoCheckboxProps) -> Html {
     );
 
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(
+        if props.checked { "true" } else { "" }.to_string(),
+        props.validators.clone(),
+    );
     let onclick = use_callback(
         props.on_check.clone(),
         |evt: MouseEvent, on_check_change| {
@@ -791,10 +2559,13 @@ pub fn checkbox(props: &CosmoCheckboxProps) -> Html {
     );
 
     html!(
-        <div class={group_style}>
-            <input type="checkbox" required={props.required} checked={props.checked} id={id.clone()} onclick={onclick} readonly={props.readonly} class={classes!(base_style, checkbox_style)} />
-            <label for={id.clone()}>{props.label.clone()}</label>
-        </div>
+        <>
+            <div class={group_style}>
+                <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} type="checkbox" required={props.required} checked={props.checked} id={id.clone()} onclick={onclick} readonly={props.readonly} class={classes!(base_style, checkbox_style, field.invalid_class.clone())} />
+                <label for={id.clone()}>{props.label.clone()}</label>
+            </div>
+            {field.error_message(&id)}
+        </>
     )
 }
 
@@ -809,6 +2580,8 @@ pub struct CosmoSwitchProps {
     pub readonly: bool,
     #[prop_or_default]
     pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[function_component(CosmoSwitch)]
@@ -858,7 +2631,7 @@ pub fn switch(props: &CosmoSwitchProps) -> Html {
 	width: var(--switch-thumb-size);
 	background: var(--border-indicator-color);
 	margin-top: var(--switch-thumb-margin);
-	margin-left: var(--switch-thumb-margin);
+	margin-inline-start: var(--switch-thumb-margin);
 }
 
 &::before {
@@ -866,18 +2639,22 @@ pub fn switch(props: &CosmoSwitchProps) -> Html {
 	border: var(--switch-rail-border-width) solid var(--border-indicator-color);
 	height: var(--switch-rail-height);
 	width: var(--switch-rail-width);
-	margin-right: calc(var(--switch-rail-height) / 2);
+	margin-inline-end: calc(var(--switch-rail-height) / 2);
 }
 
 &:checked::after,
 &:disabled:checked::after {
-	margin-left: calc((var(--switch-thumb-margin) * 3) + var(--switch-thumb-size));
+	margin-inline-start: calc((var(--switch-thumb-margin) * 3) + var(--switch-thumb-size));
 	background: var(--white);
 }
     "#
     );
 
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(
+        if props.checked { "true" } else { "" }.to_string(),
+        props.validators.clone(),
+    );
     let onclick = use_callback(
         props.on_check.clone(),
         |evt: MouseEvent, on_check_change| {
@@ -886,10 +2663,13 @@ pub fn switch(props: &CosmoSwitchProps) -> Html {
     );
 
     html!(
-        <div class={group_style}>
-            <input type="checkbox" required={props.required} checked={props.checked} id={id.clone()} onclick={onclick} readonly={props.readonly} class={classes!(base_style, switch_style)} />
-            <label for={id.clone()}>{props.label.clone()}</label>
-        </div>
+        <>
+            <div class={group_style}>
+                <input ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} type="checkbox" required={props.required} checked={props.checked} id={id.clone()} onclick={onclick} readonly={props.readonly} class={classes!(base_style, switch_style, field.invalid_class.clone())} />
+                <label for={id.clone()}>{props.label.clone()}</label>
+            </div>
+            {field.error_message(&id)}
+        </>
     )
 }
 
@@ -908,11 +2688,17 @@ pub struct CosmoDropdownProps {
     pub items: Vec<(Option<AttrValue>, AttrValue)>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[styled_component(CosmoDropdown)]
 pub fn dropdown(props: &CosmoDropdownProps) -> Html {
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(
+        props.value.clone().unwrap_or_default().to_string(),
+        props.validators.clone(),
+    );
     let onchange = use_callback(props.clone(), |evt: Event, props| {
         let val = evt.target_unchecked_into::<HtmlSelectElement>().value();
         if val == *"None" {
@@ -932,17 +2718,22 @@ background-image: var(--dropdown-background);
 background-repeat: no-repeat;
 background-position-x: right;
 background-position-y: center;
-padding-right: calc(var(--input-padding-right) * 4);
+padding-inline-end: calc(var(--input-padding-right) * 4);
 border-color: var(--border-indicator-color);
+
+&:dir(rtl) {
+	background-position-x: left;
+}
     "#
     );
 
     html!(
         <>
             <label class={label_style} for={id.clone()}>{props.label.clone()}</label>
-            <select class={classes!(input_style, select_style)} disabled={props.readonly} id={id.clone()} required={props.required} onchange={onchange}>
+            <select ref={field.node_ref.clone()} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} onblur={field.on_blur.clone()} class={classes!(input_style, select_style, field.invalid_class.clone())} disabled={props.readonly} id={id.clone()} required={props.required} onchange={onchange}>
                 {for props.items.iter().map(|(id, label)| html!(<option key={if let Some(id) = id { id.to_string() } else { uuid::Uuid::new_v4().to_string() }} selected={props.value.clone() == id.clone()} value={id.clone()}>{label.clone()}</option>))}
             </select>
+            {field.error_message(&id)}
         </>
     )
 }
@@ -964,6 +2755,105 @@ impl CosmoModernSelectItem {
     }
 }
 
+#[derive(PartialEq, Clone, Default)]
+pub struct CosmoModernSelectPage {
+    pub items: Vec<CosmoModernSelectItem>,
+    pub has_more: bool,
+}
+
+impl CosmoModernSelectPage {
+    pub fn new(items: Vec<CosmoModernSelectItem>, has_more: bool) -> Self {
+        Self { items, has_more }
+    }
+}
+
+type CosmoModernSelectFuture = Pin<Box<dyn Future<Output = CosmoModernSelectPage>>>;
+
+#[derive(Clone)]
+pub struct CosmoModernSelectLoader {
+    load: Rc<dyn Fn(AttrValue, usize) -> CosmoModernSelectFuture>,
+}
+
+impl PartialEq for CosmoModernSelectLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.load, &other.load)
+    }
+}
+
+impl CosmoModernSelectLoader {
+    pub fn new<F, R>(load: F) -> Self
+    where
+        F: Fn(AttrValue, usize) -> R + 'static,
+        R: Future<Output = CosmoModernSelectPage> + 'static,
+    {
+        Self {
+            load: Rc::new(move |search, page| Box::pin(load(search, page))),
+        }
+    }
+
+    pub async fn load(&self, search: impl Into<AttrValue>, page: usize) -> CosmoModernSelectPage {
+        (self.load)(search.into(), page).await
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+struct CosmoModernSelectLoadState {
+    items: Vec<CosmoModernSelectItem>,
+    search: AttrValue,
+    page: usize,
+    has_more: bool,
+    is_loading: bool,
+    generation: usize,
+}
+
+enum CosmoModernSelectLoadAction {
+    Start(usize, AttrValue, usize),
+    Loaded(usize, CosmoModernSelectPage),
+}
+
+impl Reducible for CosmoModernSelectLoadState {
+    type Action = CosmoModernSelectLoadAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            CosmoModernSelectLoadAction::Start(generation, search, page) => {
+                if page == 0 {
+                    state.items.clear();
+                }
+                state.search = search;
+                state.page = page;
+                state.is_loading = true;
+                state.generation = generation;
+            }
+            CosmoModernSelectLoadAction::Loaded(generation, page) => {
+                if generation != state.generation {
+                    return self;
+                }
+
+                state.items.extend(page.items);
+                state.has_more = page.has_more;
+                state.is_loading = false;
+            }
+        }
+
+        state.into()
+    }
+}
+
+fn virtualized_range(
+    scroll_top: i32,
+    client_height: i32,
+    option_height: usize,
+    option_count: usize,
+) -> (usize, usize) {
+    let first = scroll_top.max(0) as usize / option_height;
+    let visible = (client_height.max(0) as usize / option_height).max(10) + 1;
+    let end = (first + visible + 5).min(option_count);
+
+    (first.saturating_sub(5).min(end), end)
+}
+
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoModernSelectProps {
     pub label: AttrValue,
@@ -981,11 +2871,35 @@ pub struct CosmoModernSelectProps {
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub width: CosmoInputWidth,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
+    #[prop_or_default]
+    pub loader: Option<CosmoModernSelectLoader>,
+    #[prop_or(300)]
+    pub loader_debounce: u32,
+    #[prop_or(28)]
+    pub option_height: u32,
+    #[prop_or(100)]
+    pub virtualize_threshold: usize,
+    #[prop_or_default]
+    pub remove_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub no_results_label: Option<AttrValue>,
 }
 
 #[styled_component(CosmoModernSelect)]
 pub fn modern_select(props: &CosmoModernSelectProps) -> Html {
     let id = use_id(props.id.clone());
+    let field = use_cosmo_field(
+        props
+            .items
+            .iter()
+            .filter(|item| item.selected)
+            .map(|item| item.value.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+        props.validators.clone(),
+    );
 
     let flyout_open_state = use_state_eq(|| false);
     let flyout_up_state = use_state_eq(|| false);
@@ -994,7 +2908,103 @@ pub fn modern_select(props: &CosmoModernSelectProps) -> Html {
 
     let is_multiple = props.on_deselect.is_some();
 
+    let has_filter = props.on_filter.is_some() || props.loader.is_some();
+    let locale = use_cosmo_locale();
+    let remove_label = props
+        .remove_label
+        .clone()
+        .unwrap_or(locale.labels.remove.clone());
+    let no_results_label = props
+        .no_results_label
+        .clone()
+        .unwrap_or(locale.labels.no_results.clone());
+
     let select_node = use_node_ref();
+    let flyout_node = use_node_ref();
+    let active_index_state = use_state_eq(|| None as Option<usize>);
+    let scroll_state = use_state_eq(|| (0, 0));
+
+    let load_state = use_reducer_eq(CosmoModernSelectLoadState::default);
+    let load_generation = use_mut_ref(|| 0usize);
+
+    let load = use_callback(
+        (props.loader.clone(), load_state.dispatcher()),
+        move |(search, page): (AttrValue, usize), (loader, dispatcher)| {
+            if let Some(loader) = loader.clone() {
+                let generation = {
+                    let mut generation = load_generation.borrow_mut();
+                    *generation += 1;
+                    *generation
+                };
+                dispatcher.dispatch(CosmoModernSelectLoadAction::Start(
+                    generation,
+                    search.clone(),
+                    page,
+                ));
+
+                let dispatcher = dispatcher.clone();
+                spawn_local(async move {
+                    let result = loader.load(search, page).await;
+                    dispatcher.dispatch(CosmoModernSelectLoadAction::Loaded(generation, result));
+                });
+            }
+        },
+    );
+    let debounce_load = {
+        let load = load.clone();
+        let search = (*search_state).clone();
+        let is_open = *flyout_open_state;
+        use_debounce(
+            move || {
+                if is_open {
+                    load.emit((search, 0));
+                }
+            },
+            props.loader_debounce,
+        )
+    };
+
+    {
+        let load = load.clone();
+        let search = (*search_state).clone();
+        let scroll_state = scroll_state.clone();
+        use_effect_with(*flyout_open_state, move |is_open| {
+            if *is_open {
+                scroll_state.set((0, 0));
+                load.emit((search, 0));
+            }
+        });
+    }
+    use_effect_with(
+        ((*search_state).clone(), props.loader.is_some()),
+        move |(_, has_loader)| {
+            if *has_loader {
+                debounce_load.run();
+            }
+        },
+    );
+
+    let on_flyout_scroll = use_callback(
+        (
+            scroll_state.clone(),
+            load.clone(),
+            load_state.clone(),
+            props.option_height,
+        ),
+        |evt: Event, (scroll_state, load, load_state, option_height)| {
+            let flyout = evt.target_unchecked_into::<HtmlElement>();
+            let scroll_top = flyout.scroll_top();
+            let client_height = flyout.client_height();
+            scroll_state.set((scroll_top, client_height));
+
+            if load_state.has_more
+                && !load_state.is_loading
+                && flyout.scroll_height() - scroll_top - client_height < *option_height as i32 * 3
+            {
+                load.emit((load_state.search.clone(), load_state.page + 1));
+            }
+        },
+    );
 
     let on_open_flyout = use_callback(
         (flyout_open_state.clone(), flyout_up_state.clone()),
@@ -1037,7 +3047,11 @@ pub fn modern_select(props: &CosmoModernSelectProps) -> Html {
     let on_filter = use_callback(
         (props.on_filter.clone(), search_state.clone()),
         |evt: InputEvent, (on_filter, search_state)| {
-            let search = AttrValue::from(evt.target_unchecked_into::<HtmlInputElement>().value());
+            let search = AttrValue::from(
+                evt.target_unchecked_into::<HtmlElement>()
+                    .text_content()
+                    .unwrap_or_default(),
+            );
             search_state.set(search.clone());
 
             if let Some(on) = on_filter.clone() {
@@ -1047,10 +3061,147 @@ pub fn modern_select(props: &CosmoModernSelectProps) -> Html {
     );
 
     let selected_items = props.items.iter().filter(|item| item.selected);
-    let available_items = props
-        .items
+    let options = if props.loader.is_some() {
+        load_state
+            .items
+            .iter()
+            .map(|item| CosmoModernSelectItem {
+                selected: selected_items
+                    .clone()
+                    .any(|selected| selected.value == item.value),
+                ..item.clone()
+            })
+            .collect::<Vec<_>>()
+    } else {
+        props.items.clone()
+    };
+    let available_items = options
+        .into_iter()
+        .filter(|item| !is_multiple || !item.selected)
+        .collect::<Vec<_>>();
+    let available_values = available_items
         .iter()
-        .filter(|item| !is_multiple || !item.selected);
+        .map(|item| item.value.clone())
+        .collect::<Vec<_>>();
+    let selected_values = selected_items
+        .clone()
+        .map(|item| item.value.clone())
+        .collect::<Vec<_>>();
+    let active_index = (*active_index_state).filter(|index| *index < available_values.len());
+
+    let on_keydown = use_callback(
+        (
+            available_values,
+            selected_values,
+            flyout_open_state.clone(),
+            active_index_state.clone(),
+            search_state.clone(),
+            on_select.clone(),
+            props.on_deselect.clone(),
+            props.readonly,
+            has_filter,
+        ),
+        |evt: KeyboardEvent,
+         (
+            available_values,
+            selected_values,
+            flyout_open_state,
+            active_index_state,
+            search_state,
+            on_select,
+            on_deselect,
+            readonly,
+            has_filter,
+        )| {
+            if *readonly {
+                return;
+            }
+
+            let count = available_values.len();
+            let is_open = **flyout_open_state;
+            let active_index = (**active_index_state).filter(|index| *index < count);
+            match evt.key().as_str() {
+                "ArrowDown" => {
+                    evt.prevent_default();
+                    flyout_open_state.set(true);
+                    if count > 0 {
+                        active_index_state.set(Some(match (is_open, active_index) {
+                            (true, Some(index)) => (index + 1) % count,
+                            _ => 0,
+                        }));
+                    }
+                }
+                "ArrowUp" => {
+                    evt.prevent_default();
+                    flyout_open_state.set(true);
+                    if count > 0 {
+                        active_index_state.set(Some(match (is_open, active_index) {
+                            (true, Some(index)) => (index + count - 1) % count,
+                            _ => count - 1,
+                        }));
+                    }
+                }
+                "Home" if is_open && count > 0 => {
+                    evt.prevent_default();
+                    active_index_state.set(Some(0));
+                }
+                "End" if is_open && count > 0 => {
+                    evt.prevent_default();
+                    active_index_state.set(Some(count - 1));
+                }
+                "Enter" | " " if evt.key() == "Enter" || !*has_filter => {
+                    evt.prevent_default();
+                    match (is_open, active_index) {
+                        (true, Some(index)) => on_select.emit(available_values[index].clone()),
+                        _ => flyout_open_state.set(true),
+                    }
+                }
+                "Escape" if is_open => {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                    flyout_open_state.set(false);
+                    active_index_state.set(None);
+                }
+                "Backspace" if search_state.is_empty() => {
+                    if let (Some(on_deselect), Some(value)) = (on_deselect, selected_values.last())
+                    {
+                        evt.prevent_default();
+                        on_deselect.emit(value.clone());
+                    }
+                }
+                "Tab" => {
+                    flyout_open_state.set(false);
+                    active_index_state.set(None);
+                }
+                _ => {}
+            }
+        },
+    );
+    let on_option_hover = use_callback(active_index_state.clone(), |index: usize, state| {
+        state.set(Some(index))
+    });
+
+    {
+        let id = id.clone();
+        let flyout_node = flyout_node.clone();
+        let option_height = props.option_height;
+        use_effect_with(
+            (active_index, *flyout_open_state),
+            move |(active_index, _)| {
+                if let Some(index) = *active_index {
+                    if let Some(option) = gloo_utils::document()
+                        .get_element_by_id(format!("{id}-option-{index}").as_str())
+                    {
+                        let options = ScrollIntoViewOptions::new();
+                        options.set_block(ScrollLogicalPosition::Nearest);
+                        option.scroll_into_view_with_scroll_into_view_options(&options);
+                    } else if let Some(flyout) = flyout_node.cast::<HtmlElement>() {
+                        flyout.set_scroll_top(index as i32 * option_height as i32);
+                    }
+                }
+            },
+        );
+    }
 
     use_click_away(select_node.clone(), move |_: Event| {
         on_close_flyout.emit(());
@@ -1066,7 +3217,7 @@ border-color: var(--negative-color);
 border-bottom-width: var(--input-border-width) !important;
 appearance: none;
 position: relative;
-padding-right: 0 !important;
+padding-inline-end: 0 !important;
     "#
     );
     let chip_style = use_style!(
@@ -1091,8 +3242,33 @@ align-items: center;
     let chip_close_style = use_style!(
         r#"
 cursor: pointer;
-text-decoration: none;
-margin-left: 0.125rem;
+position: relative;
+margin-inline-start: 0.125rem;
+padding: 0;
+border: 0;
+background: none;
+color: inherit;
+width: 0.75em;
+height: 0.75em;
+
+&::before,
+&::after {
+	content: '';
+	position: absolute;
+	top: 50%;
+	left: 0;
+	width: 100%;
+	height: 1px;
+	background: currentColor;
+}
+
+&::before {
+	transform: rotate(45deg);
+}
+
+&::after {
+	transform: rotate(-45deg);
+}
     "#
     );
     let holder_style = use_style!(
@@ -1113,6 +3289,10 @@ background-image: var(--dropdown-background);
 background-repeat: no-repeat;
 background-position-x: right;
 background-position-y: center;
+
+&:dir(rtl) {
+	background-position-x: left;
+}
     "#
     );
     let search_style = use_style!(
@@ -1136,14 +3316,14 @@ display: flex;
 width: 100%;
 background: var(--white);
 border: 0.0625rem solid var(--control-border-color);
-left: -0.0625rem;
+inset-inline-start: -0.0625rem;
 top: 26px;
 max-height: 10rem;
 overflow-y: auto;
 flex-flow: row wrap;
 z-index: 1000;
-border-bottom-left-radius: var(--border-radius);
-border-bottom-right-radius: var(--border-radius);
+border-end-start-radius: var(--border-radius);
+border-end-end-radius: var(--border-radius);
     "#
     );
     let flyout_up_style = use_style!(
@@ -1161,14 +3341,43 @@ font-family: var(--font-family);
 box-sizing: border-box;
 cursor: pointer;
 
-&:hover {
+&:hover,
+&.is--active {
     background: var(--primary-color);
-    color: var(--white);
+    color: var(--primary-color-foreground);
+}
 
-    @media screen and (prefers-color-scheme: dark) {
-        color: var(--black);
-    }
+&[aria-selected="true"] {
+    font-weight: var(--font-weight-bold);
 }
+    "#
+    );
+    let flyout_item_fixed_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+white-space: nowrap;
+overflow: hidden;
+text-overflow: ellipsis;
+    "#
+    );
+    let flyout_spacer_style = use_style!(
+        r#"
+flex: 0 0 100%;
+    "#
+    );
+    let flyout_loading_style = use_style!(
+        r#"
+flex: 0 0 100%;
+display: flex;
+justify-content: center;
+    "#
+    );
+    let flyout_empty_style = use_style!(
+        r#"
+flex: 0 0 100%;
+padding: 0.25rem 0.5rem;
+color: var(--control-border-color-darker);
     "#
     );
     let (label_style, input_style) = use_input_styling(props.width.clone());
@@ -1176,7 +3385,7 @@ cursor: pointer;
     let classes = if props.required && selected_items.clone().count() == 0 {
         classes!(input_style, select_style, invalid_style)
     } else {
-        classes!(input_style, select_style)
+        classes!(input_style, select_style, field.invalid_class.clone())
     };
 
     let flyout_classes = if *flyout_up_state {
@@ -1185,11 +3394,43 @@ cursor: pointer;
         classes!(flyout_style)
     };
 
+    let label_id = AttrValue::from(format!("{id}-label"));
+    let listbox_id = AttrValue::from(format!("{id}-listbox"));
+    let expanded = AttrValue::from(flyout_open_state.to_string());
+    let option_height = props.option_height.max(1) as usize;
+    let option_count = available_items.len();
+    let is_virtualized = option_count > props.virtualize_threshold;
+    let (render_start, render_end) = if is_virtualized {
+        let (scroll_top, client_height) = *scroll_state;
+        virtualized_range(scroll_top, client_height, option_height, option_count)
+    } else {
+        (0, option_count)
+    };
+    {
+        let scroll_state = scroll_state.clone();
+        let flyout_node = flyout_node.clone();
+        use_effect_with(((*search_state).clone(), option_count), move |_| {
+            scroll_state.set((0, 0));
+            if let Some(flyout) = flyout_node.cast::<HtmlElement>() {
+                flyout.set_scroll_top(0);
+            }
+        });
+    }
+    let set_size = if load_state.has_more {
+        AttrValue::from("-1")
+    } else {
+        AttrValue::from(option_count.to_string())
+    };
+
+    let active_descendant = active_index
+        .filter(|_| *flyout_open_state)
+        .map(|index| AttrValue::from(format!("{id}-option-{index}")));
+
     html!(
         <>
-            <label for={id.clone()} class={label_style} onclick={on_open_flyout.clone()}>{props.label.clone()}</label>
-            <div ref={select_node} class={classes}>
-                <div disabled={props.readonly} class={holder_style} onclick={on_open_flyout.clone()}>
+            <label id={label_id.clone()} for={id.clone()} class={label_style} onclick={on_open_flyout.clone()}>{props.label.clone()}</label>
+            <div ref={select_node} class={classes} onfocusout={field.on_blur.clone()} onkeydown={on_keydown}>
+                <div ref={if has_filter { NodeRef::default() } else { field.node_ref.clone() }} id={(!has_filter).then(|| id.clone())} tabindex={(!has_filter).then_some("0")} role={(!has_filter).then_some("combobox")} aria-expanded={(!has_filter).then(|| expanded.clone())} aria-controls={(!has_filter).then(|| listbox_id.clone())} aria-haspopup={(!has_filter).then_some("listbox")} aria-labelledby={(!has_filter).then(|| label_id.clone())} aria-activedescendant={(!has_filter).then(|| active_descendant.clone()).flatten()} aria-required={(!has_filter && props.required).then_some("true")} aria-disabled={(!has_filter && props.readonly).then_some("true")} aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&id)} disabled={props.readonly} class={holder_style} onclick={on_open_flyout.clone()}>
                     {if is_multiple {
                         html!(
                             {for selected_items.clone().map(|item| {
@@ -1199,7 +3440,7 @@ cursor: pointer;
                                 html!(
                                     <div class={chip_style.clone()} key={item.value.to_string()}>
                                         {item.label.clone()}
-                                        <a class={chip_close_style.clone()} onclick={move |_| on_deselect.emit(deselect_item.value.clone())}>{"×"}</a>
+                                        <button class={chip_close_style.clone()} type="button" aria-label={remove_label.clone()} title={remove_label.clone()} onclick={move |_| on_deselect.emit(deselect_item.value.clone())}></button>
                                     </div>
                                 )
                             })}
@@ -1216,23 +3457,41 @@ cursor: pointer;
                             )
                         }
                     }}
-                    if props.on_filter.is_some() {
-                        <span contenteditable="plaintext-only" id={id.clone()} class={search_style.clone()} oninput={on_filter}>{(*search_state).clone()}</span>
+                    if has_filter {
+                        <span ref={field.node_ref.clone()} contenteditable="plaintext-only" id={id.clone()} role="combobox" aria-autocomplete="list" aria-expanded={expanded.clone()} aria-controls={listbox_id.clone()} aria-haspopup="listbox" aria-labelledby={label_id.clone()} aria-activedescendant={active_descendant.clone()} aria-required={props.required.then_some("true")} aria-disabled={props.readonly.then_some("true")} class={search_style.clone()} oninput={on_filter}>{(*search_state).clone()}</span>
                     }
                 </div>
-                if *flyout_open_state && !available_items.clone().count() > 0 {
-                    <div class={flyout_classes}>
-                        {for available_items.clone().map(|item| {
+                if *flyout_open_state && (!available_items.is_empty() || load_state.is_loading || has_filter) {
+                    <div ref={flyout_node} id={listbox_id.clone()} role="listbox" aria-labelledby={label_id.clone()} aria-multiselectable={is_multiple.then_some("true")} aria-busy={load_state.is_loading.then_some("true")} class={flyout_classes} onscroll={on_flyout_scroll}>
+                        if is_virtualized {
+                            <div class={flyout_spacer_style.clone()} style={format!("height: {}px;", render_start * option_height)}></div>
+                        }
+                        {for available_items[render_start..render_end].iter().enumerate().map(|(offset, item)| {
+                            let index = render_start + offset;
                             let select_item = item.clone();
                             let on_select = on_select.clone();
+                            let on_option_hover = on_option_hover.clone();
+                            let is_active = active_index == Some(index);
 
                             html!(
-                                <span key={item.value.to_string()} onclick={move |_| on_select.emit(select_item.value.clone())} class={flyout_item_style.clone()}>{item.label.clone()}</span>
+                                <span key={item.value.to_string()} id={format!("{id}-option-{index}")} role="option" aria-selected={item.selected.to_string()} aria-setsize={is_virtualized.then(|| set_size.clone())} aria-posinset={is_virtualized.then(|| (index + 1).to_string())} style={is_virtualized.then(|| format!("height: {option_height}px;"))} onclick={move |_| on_select.emit(select_item.value.clone())} onmouseenter={move |_| on_option_hover.emit(index)} class={classes!(flyout_item_style.clone(), is_virtualized.then(|| flyout_item_fixed_style.clone()), is_active.then_some("is--active"))}>{item.label.clone()}</span>
                             )
                         })}
+                        if is_virtualized {
+                            <div class={flyout_spacer_style} style={format!("height: {}px;", (option_count - render_end) * option_height)}></div>
+                        }
+                        if available_items.is_empty() && !load_state.is_loading {
+                            <div class={flyout_empty_style} role="status">{no_results_label}</div>
+                        }
+                        if load_state.is_loading {
+                            <div class={flyout_loading_style}>
+                                <CosmoProgressRing />
+                            </div>
+                        }
                     </div>
                 }
             </div>
+            {field.error_message(&id)}
         </>
     )
 }
@@ -1247,6 +3506,8 @@ pub struct CosmoRadiosProps {
     #[prop_or(false)]
     pub readonly: bool,
     pub options: Vec<(AttrValue, AttrValue)>,
+    #[prop_or_default]
+    pub validators: Vec<CosmoValidator>,
 }
 
 #[styled_component(CosmoRadios)]
@@ -1284,7 +3545,7 @@ pub fn radio_group(props: &CosmoRadiosProps) -> Html {
 	border: 1px solid var(--border-indicator-color);
 	height: var(--radio-size);
 	width: var(--radio-size);
-	margin-right: calc(var(--radio-size) / 2);
+	margin-inline-end: calc(var(--radio-size) / 2);
 }
 
 &:checked::after {
@@ -1315,12 +3576,14 @@ pub fn radio_group(props: &CosmoRadiosProps) -> Html {
     );
 
     let name = uuid::Uuid::new_v4().to_string();
+    let error_id = use_id(None);
+    let field = use_cosmo_field(props.value.to_string(), props.validators.clone());
 
     html!(
         <>
             <label class={classes!(label_style, label_additional_style)}>{props.label.clone()}</label>
-            <div class={group_style}>
-                {for props.options.iter().map(|(option, label)|{
+            <div class={group_style} role="radiogroup" aria-invalid={field.aria_invalid()} aria-describedby={field.error_id(&error_id)} onfocusout={field.on_blur.clone()}>
+                {for props.options.iter().enumerate().map(|(index, (option, label))|{
                     let id = uuid::Uuid::new_v4().to_string();
                     let name = name.clone();
                     let radio_style = radio_style.clone();
@@ -1329,15 +3592,17 @@ pub fn radio_group(props: &CosmoRadiosProps) -> Html {
                     let readonly = props.readonly;
                     let required = props.required;
                     let checked = props.value.clone() == option.clone();
+                    let node_ref = if index == 0 { field.node_ref.clone() } else { NodeRef::default() };
 
                     html!(
                         <>
-                            <input checked={checked} required={required} readonly={readonly} onclick={on_change.clone()} value={option.clone()} name={name.clone()} type="radio" id={id.clone()} class={classes!(base_style.clone(), radio_style.clone())} />
+                            <input ref={node_ref} checked={checked} required={required} readonly={readonly} onclick={on_change.clone()} value={option.clone()} name={name.clone()} type="radio" id={id.clone()} class={classes!(base_style.clone(), radio_style.clone())} />
                             <label for={id.clone()}>{label.clone()}</label>
                         </>
                     )
                 })}
             </div>
+            {field.error_message(&error_id)}
         </>
     )
 }
@@ -1365,8 +3630,8 @@ pub fn slider(props: &CosmoSliderProps) -> Html {
     let style = use_style!(
         r#"
 border: none;
-padding-left: 0;
-padding-right: 0;
+padding-inline-start: 0;
+padding-inline-end: 0;
 margin: 0;
 appearance: none;
 background: transparent;
@@ -1496,29 +3761,232 @@ margin-bottom: var(--input-group-gap);
 }
 
 #[derive(PartialEq, Clone, Properties)]
-pub struct CosmoFormProps {
+pub struct CosmoFormProps<T: PartialEq + Clone + Default + 'static> {
     #[prop_or_default]
     pub children: ChildrenRenderer<CosmoInputGroupChildren>,
     #[prop_or_default]
     pub buttons: Children,
-    pub on_submit: Callback<()>,
+    pub on_submit: Callback<T>,
+    #[prop_or_default]
+    pub validation_mode: CosmoValidationMode,
+    #[prop_or_default]
+    pub form: Option<CosmoFormHandle<T>>,
 }
 
-#[styled_component(CosmoForm)]
-pub fn form(props: &CosmoFormProps) -> Html {
-    let on_submit = use_callback(props.clone(), |evt: SubmitEvent, props| {
-        evt.prevent_default();
-        props.on_submit.emit(());
-    });
+pub struct CosmoForm<T: PartialEq + Clone + Default + 'static = ()> {
+    _marker: PhantomData<T>,
+}
 
-    html!(
-        <form onsubmit={on_submit.clone()}>
-            <CosmoInputGroup>
+impl<T: PartialEq + Clone + Default + 'static> Component for CosmoForm<T> {
+    type Message = ();
+    type Properties = CosmoFormProps<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let on_submit = {
+            let on_submit = props.on_submit.clone();
+            let form = props.form.clone();
+            Callback::from(move |_| {
+                on_submit.emit(form.as_ref().map(|form| form.value()).unwrap_or_default())
+            })
+        };
+
+        html!(
+            <CosmoFormBody on_submit={on_submit} validation_mode={props.validation_mode.clone()} buttons={props.buttons.clone()}>
                 {for props.children.iter()}
-            </CosmoInputGroup>
-            <CosmoButtonContainer>
-                {for props.buttons.iter()}
-            </CosmoButtonContainer>
-        </form>
+            </CosmoFormBody>
+        )
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoFormBodyProps {
+    #[prop_or_default]
+    children: ChildrenRenderer<CosmoInputGroupChildren>,
+    #[prop_or_default]
+    buttons: Children,
+    on_submit: Callback<()>,
+    validation_mode: CosmoValidationMode,
+}
+
+#[styled_component(CosmoFormBody)]
+fn form_body(props: &CosmoFormBodyProps) -> Html {
+    let form_context = use_cosmo_form_context(props.validation_mode.clone());
+    let on_submit = use_callback(
+        (form_context.clone(), props.on_submit.clone()),
+        |evt: SubmitEvent, (form_context, on_submit)| {
+            evt.prevent_default();
+            form_context.submit(on_submit.clone());
+        },
+    );
+
+    html!(
+        <ContextProvider<CosmoFormContext> context={form_context}>
+            <form onsubmit={on_submit.clone()}>
+                <CosmoInputGroup>
+                    {for props.children.iter()}
+                </CosmoInputGroup>
+                <CosmoButtonContainer>
+                    {for props.buttons.iter()}
+                </CosmoButtonContainer>
+            </form>
+        </ContextProvider<CosmoFormContext>>
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn virtualized_range_stays_in_bounds_when_the_list_shrinks() {
+        let items = (0..1000).collect::<Vec<_>>();
+        let (start, end) = virtualized_range(20_000, 400, 40, items.len());
+        assert_eq!((start, end), (495, 516));
+        assert_eq!(items[start..end].len(), 21);
+
+        let items = &items[..20];
+        let (start, end) = virtualized_range(20_000, 400, 40, items.len());
+        assert_eq!((start, end), (20, 20));
+        assert!(items[start..end].is_empty());
+    }
+
+    #[test]
+    fn zoned_date_times_are_bounded_by_instant() {
+        let locale = CosmoLocale::english();
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let london = FixedOffset::east_opt(0).unwrap();
+        let bounds = CosmoZonedBounds {
+            zone: london,
+            preferred_offset: london,
+            min: Some(tokyo.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap()),
+            max: Some(tokyo.with_ymd_and_hms(2024, 1, 2, 18, 0, 0).unwrap()),
+            disabled_dates: None,
+        };
+
+        let parsed = DateTime::<FixedOffset>::parse("01/02/2024 08:30 AM", &locale, &bounds);
+        assert_eq!(
+            parsed,
+            Some(london.with_ymd_and_hms(2024, 1, 2, 8, 30, 0).unwrap())
+        );
+        assert_eq!(parsed.unwrap().canonical(), "2024-01-02T08:30+00:00");
+        assert_eq!(
+            DateTime::<FixedOffset>::parse("01/02/2024 10:00 AM", &locale, &bounds),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn validators_receive_the_canonical_number() {
+        let german = CosmoLocale::german();
+        let range = CosmoValidator::range(Some(18.0), None, "Too young");
+
+        let format = CosmoNumberFormat {
+            decimal_places: 2,
+            grouping: true,
+            is_native: false,
+        };
+
+        let grouped = validation_text::<i64>("1.000", &german, &format).unwrap();
+        assert_eq!(grouped, "1000");
+        assert_eq!(range.validate(grouped).await, Ok(()));
+
+        let decimal = validation_text::<f64>("18,5", &german, &format).unwrap();
+        assert_eq!(decimal, "18.5");
+        assert_eq!(range.validate(decimal).await, Ok(()));
+
+        assert_eq!(validation_text::<f64>("18,5x", &german, &format), None);
+        assert_eq!(
+            validation_text::<i64>("", &german, &format),
+            Some(String::new())
+        );
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct Berlin;
+
+    impl Berlin {
+        fn winter() -> FixedOffset {
+            FixedOffset::east_opt(3600).unwrap()
+        }
+
+        fn summer() -> FixedOffset {
+            FixedOffset::east_opt(2 * 3600).unwrap()
+        }
+    }
+
+    impl TimeZone for Berlin {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            Berlin
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let offsets = [Berlin::summer(), Berlin::winter()]
+                .into_iter()
+                .filter(|offset| self.offset_from_utc_datetime(&(*local - *offset)) == *offset)
+                .collect::<Vec<_>>();
+
+            match offsets[..] {
+                [offset] => LocalResult::Single(offset),
+                [earliest, latest] => LocalResult::Ambiguous(earliest, latest),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            let summer_start = NaiveDate::from_ymd_opt(2024, 3, 31)
+                .and_then(|date| date.and_hms_opt(1, 0, 0))
+                .unwrap();
+            let summer_end = NaiveDate::from_ymd_opt(2024, 10, 27)
+                .and_then(|date| date.and_hms_opt(1, 0, 0))
+                .unwrap();
+
+            if *utc >= summer_start && *utc < summer_end {
+                Berlin::summer()
+            } else {
+                Berlin::winter()
+            }
+        }
+    }
+
+    #[test]
+    fn time_zone_resolves_local_times_across_dst() {
+        let berlin = CosmoTimeZone::new("Berlin", Berlin);
+        let local = |month, day, hour, minute| {
+            NaiveDate::from_ymd_opt(2024, month, day)
+                .and_then(|date| date.and_hms_opt(hour, minute, 0))
+                .unwrap()
+        };
+
+        let summer = berlin.from_local_datetime(&local(7, 1, 12, 0)).unwrap();
+        assert_eq!(summer.offset(), &Berlin::summer());
+        assert_eq!(summer.naive_local(), local(7, 1, 12, 0));
+
+        let skipped = berlin.from_local_datetime(&local(3, 31, 2, 30)).unwrap();
+        assert_eq!(skipped.naive_local(), local(3, 31, 3, 30));
+        assert_eq!(skipped.offset(), &Berlin::summer());
+
+        let repeated = berlin.from_local_datetime(&local(10, 27, 2, 30)).unwrap();
+        assert_eq!(repeated.naive_local(), local(10, 27, 2, 30));
+        assert_eq!(repeated.offset(), &Berlin::summer());
+
+        let winter = berlin.from_local_datetime(&local(12, 24, 18, 0)).unwrap();
+        assert_eq!(winter.offset(), &Berlin::winter());
+    }
+}
@@ -0,0 +1,210 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+pub trait CosmoFormModel {
+    type Fields;
+
+    fn fields() -> Self::Fields;
+}
+
+pub struct CosmoFormLens<T, V> {
+    name: &'static str,
+    get: fn(&T) -> V,
+    set: fn(&mut T, V),
+}
+
+impl<T, V> Clone for CosmoFormLens<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, V> Copy for CosmoFormLens<T, V> {}
+
+impl<T, V> CosmoFormLens<T, V> {
+    pub const fn new(name: &'static str, get: fn(&T) -> V, set: fn(&mut T, V)) -> Self {
+        Self { name, get, set }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoFormState<T> {
+    value: T,
+    initial_value: T,
+    touched: Vec<&'static str>,
+}
+
+enum CosmoFormStateAction<T> {
+    Update(&'static str, Box<dyn FnOnce(&mut T)>),
+    Reset(Option<T>),
+}
+
+impl<T: PartialEq + Clone> Reducible for CosmoFormState<T> {
+    type Action = CosmoFormStateAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            CosmoFormStateAction::Update(name, update) => {
+                update(&mut state.value);
+                if !state.touched.contains(&name) {
+                    state.touched.push(name);
+                }
+            }
+            CosmoFormStateAction::Reset(value) => {
+                if let Some(value) = value {
+                    state.initial_value = value;
+                }
+                state.value = state.initial_value.clone();
+                state.touched.clear();
+            }
+        }
+
+        state.into()
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoFormHandle<T: PartialEq + Clone + 'static> {
+    state: UseReducerHandle<CosmoFormState<T>>,
+}
+
+impl<T: PartialEq + Clone + 'static> CosmoFormHandle<T> {
+    pub fn value(&self) -> T {
+        self.state.value.clone()
+    }
+
+    pub fn initial_value(&self) -> T {
+        self.state.initial_value.clone()
+    }
+
+    pub fn fields(&self) -> T::Fields
+    where
+        T: CosmoFormModel,
+    {
+        T::fields()
+    }
+
+    pub fn get<V>(&self, lens: CosmoFormLens<T, V>) -> V {
+        (lens.get)(&self.state.value)
+    }
+
+    pub fn set<V: 'static>(&self, lens: CosmoFormLens<T, V>, value: V) {
+        self.state.dispatch(CosmoFormStateAction::Update(
+            lens.name,
+            Box::new(move |model| (lens.set)(model, value)),
+        ));
+    }
+
+    pub fn on_input<V: 'static>(&self, lens: CosmoFormLens<T, V>) -> Callback<V> {
+        let form = self.clone();
+        Callback::from(move |value| form.set(lens, value))
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.state.value != self.state.initial_value
+    }
+
+    pub fn is_field_dirty<V: PartialEq>(&self, lens: CosmoFormLens<T, V>) -> bool {
+        (lens.get)(&self.state.value) != (lens.get)(&self.state.initial_value)
+    }
+
+    pub fn is_touched(&self) -> bool {
+        !self.state.touched.is_empty()
+    }
+
+    pub fn is_field_touched<V>(&self, lens: CosmoFormLens<T, V>) -> bool {
+        self.state.touched.contains(&lens.name)
+    }
+
+    pub fn reset(&self) {
+        self.state.dispatch(CosmoFormStateAction::Reset(None));
+    }
+
+    pub fn reset_to(&self, value: T) {
+        self.state
+            .dispatch(CosmoFormStateAction::Reset(Some(value)));
+    }
+}
+
+#[hook]
+pub fn use_cosmo_form<T>() -> CosmoFormHandle<T>
+where
+    T: Default + PartialEq + Clone + 'static,
+{
+    use_cosmo_form_with(T::default)
+}
+
+#[hook]
+pub fn use_cosmo_form_with<T, F>(init_fn: F) -> CosmoFormHandle<T>
+where
+    T: PartialEq + Clone + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer(move || {
+        let value = init_fn();
+        CosmoFormState {
+            initial_value: value.clone(),
+            value,
+            touched: vec![],
+        }
+    });
+
+    CosmoFormHandle { state }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> Rc<CosmoFormState<(i64, i64)>> {
+        Rc::new(CosmoFormState {
+            value: (1, 2),
+            initial_value: (1, 2),
+            touched: vec![],
+        })
+    }
+
+    #[test]
+    fn touched_fields_are_tracked_even_without_changes() {
+        let state = state().reduce(CosmoFormStateAction::Update(
+            "first",
+            Box::new(|value| value.0 = 1),
+        ));
+        assert_eq!(state.touched, vec!["first"]);
+        assert_eq!(state.value, state.initial_value);
+
+        let state = state.reduce(CosmoFormStateAction::Update(
+            "second",
+            Box::new(|value| value.1 = 3),
+        ));
+        let state = state.reduce(CosmoFormStateAction::Update(
+            "first",
+            Box::new(|value| value.0 = 4),
+        ));
+        assert_eq!(state.touched, vec!["first", "second"]);
+        assert_eq!(state.value, (4, 3));
+    }
+
+    #[test]
+    fn reset_clears_touched_fields() {
+        let state = state().reduce(CosmoFormStateAction::Update(
+            "first",
+            Box::new(|value| value.0 = 4),
+        ));
+
+        let reset = state.clone().reduce(CosmoFormStateAction::Reset(None));
+        assert!(reset.touched.is_empty());
+        assert_eq!(reset.value, (1, 2));
+
+        let reset = state.reduce(CosmoFormStateAction::Reset(Some((5, 6))));
+        assert!(reset.touched.is_empty());
+        assert_eq!(reset.initial_value, (5, 6));
+        assert_eq!(reset.value, (5, 6));
+    }
+}
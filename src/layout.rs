@@ -1,6 +1,7 @@
 use bounce::helmet::{Helmet, HelmetBridge};
 use bounce::BounceRoot;
 use std::str::FromStr;
+use stylist::manager::StyleManager;
 use stylist::yew::{styled_component, use_style};
 use stylist::{GlobalStyle, Style};
 use yew::html::ChildrenRenderer;
@@ -9,7 +10,10 @@ use yew::virtual_dom::VChild;
 #[cfg(feature = "with-yew-router")]
 use yew_router::prelude::*;
 
+use crate::fonts::CosmoFontLoader;
 use crate::prelude::*;
+#[cfg(feature = "ssr")]
+use crate::ssr::CosmoSsrContext;
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoTitleProps {
@@ -29,14 +33,14 @@ vertical-align: text-top;
 font-size: var(--title-font-size);
 
 + .cosmo-title {
-	margin-left: var(--title-gap);
-	border-left: 1px solid var(--black);
-	padding-left: var(--title-gap);
+	margin-inline-start: var(--title-gap);
+	border-inline-start: 1px solid var(--black);
+	padding-inline-start: var(--title-gap);
 	box-sizing: border-box;
 }
 
 small {
-    margin-left: 1rem;
+    margin-inline-start: 1rem;
 }
     "#
     );
@@ -89,40 +93,74 @@ pub type CosmoPageLayoutFormatTitle = Callback<AttrValue, AttrValue>;
 pub struct CosmoPageLayoutProps {
     #[prop_or_default]
     pub children: Children,
-    #[prop_or(AttrValue::from("#19324c"))]
-    pub primary_color: AttrValue,
-    #[prop_or(AttrValue::from("#0f1e2e"))]
-    pub primary_color_dark: AttrValue,
+    #[prop_or_default]
+    pub primary_color: Option<AttrValue>,
+    #[prop_or_default]
+    pub primary_color_dark: Option<AttrValue>,
+    #[prop_or_default]
+    pub tokens: CosmoThemeTokens,
+    #[prop_or_default]
+    pub font_source: CosmoFontSource,
     #[prop_or(Callback::from(| _ | AttrValue::from("")))]
     pub format_title: CosmoPageLayoutFormatTitle,
     #[prop_or_default]
     pub default_title: AttrValue,
+    #[prop_or(5)]
+    pub max_toasts: usize,
+    #[prop_or_default]
+    pub toast_close_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub dialog_confirm_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub dialog_decline_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub default_theme: CosmoTheme,
+    #[prop_or(AttrValue::from("cosmo-theme"))]
+    pub theme_storage_key: AttrValue,
+    #[prop_or_default]
+    pub breakpoints: CosmoBreakpoints,
+    #[prop_or_default]
+    pub locale: CosmoLocale,
+    #[prop_or_default]
+    pub dir: Option<CosmoTextDirection>,
 }
 
 #[styled_component(CosmoPageLayout)]
 pub fn page_layout(props: &CosmoPageLayoutProps) -> Html {
-    let primary_color = if let Ok(color) = Color::from_str(props.primary_color.to_string().as_str())
-    {
-        color
-    } else {
-        color!(#19324c)
+    let locale = CosmoLocale {
+        direction: props.dir.unwrap_or(props.locale.direction),
+        ..props.locale.clone()
     };
-    let primary_color_dark =
-        if let Ok(color) = Color::from_str(props.primary_color_dark.to_string().as_str()) {
-            color
-        } else {
-            color!(#0f1e2e)
-        };
-
-    let primary_color_hue = primary_color.hue();
-    let primary_color_saturation = primary_color.saturation() * 100.0;
-    let primary_color_lightness = primary_color.lightness() * 100.0;
-
-    let primary_color_dark_hue = primary_color_dark.hue();
-    let primary_color_dark_saturation = primary_color_dark.saturation() * 100.0;
-    let primary_color_dark_lightness = primary_color_dark.lightness() * 100.0;
-
-    let style = GlobalStyle::new(css!(r#"
+    let mut tokens = props.tokens.clone();
+    if let Some(color) = props
+        .primary_color
+        .as_ref()
+        .and_then(|color| Color::from_str(color.as_str()).ok())
+    {
+        tokens.light.primary = color;
+    }
+    if let Some(color) = props
+        .primary_color_dark
+        .as_ref()
+        .and_then(|color| Color::from_str(color.as_str()).ok())
+    {
+        tokens.dark.primary = color;
+    }
+    use_effect_with(tokens.clone(), |tokens| {
+        #[cfg(debug_assertions)]
+        for warning in tokens.contrast_warnings() {
+            web_sys::console::warn_1(&warning.into());
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = tokens;
+    });
+
+    let style_manager = use_context::<StyleManager>().unwrap_or_default();
+    #[cfg(feature = "ssr")]
+    let ssr_context = use_context::<CosmoSsrContext>().unwrap_or_default();
+    let style = GlobalStyle::new_with_manager(
+        css!(
+            r#"
 *,
 ::before,
 ::after {
@@ -227,239 +265,26 @@ summary {
 
 ::selection {
     background: var(--primary-color);
-    color: var(--white);
+    color: var(--primary-color-foreground);
 }
 
 :root {
-	--primary-hue: ${primary_color_hue};
-	--primary-saturation: ${primary_color_saturation}%;
-	--primary-lightness-base: ${primary_color_lightness}%;
-
-	--negative-hue: 2;
-	--negative-saturation: 67%;
-	--negative-lightness-base: 57%;
-
-	--positive-hue: 146;
-	--positive-saturation: 35%;
-	--positive-lightness-base: 46%;
-
-	--information-hue: 205;
-	--information-saturation: 64%;
-	--information-lightness-base: 48%;
-
-	--warning-hue: 42;
-	--warning-saturation: 54%;
-	--warning-lightness-base: 54%;
-
-	--gray-hue: 0;
-	--gray-saturation: 0%;
-	--gray-lightness-base: 80%;
-
-	--light-step: 10%;
-	--lighter-step: 20%;
-
-	--dark-step: -10%;
-	--darker-step: -20%;
-
-	--white-base: 255;
-	--black-base: 51;
-
-	--border-radius: 0.25rem;
-
 	--width-small: 15rem;
 	--width-medium: calc(var(--input-width-small) * 2);
 	--width-large: calc(var(--input-width-small) * 3);
 
-	--font-size: 1rem;
 	--font-weight-bold: bold;
 	--font-weight-normal: normal;
 	--font-weight-light: 300;
-	--font-family: 'Albert Sans', sans-serif;
 
-	--font-family-menu: Urbanist, sans-serif;
 	--font-weight-menu: 200;
 	--font-weight-sub-menu-active: 600;
 	--font-size-main-menu: 2.5rem;
 	--font-size-sub-menu: 1.25rem;
 	--font-size-top-menu: 1rem;
 
-	--font-family-modal-title: Urbanist, sans-serif;
-
-	--font-family-heading: Urbanist, sans-serif;
 	--font-weight-heading: 200;
 
-	--font-family-code: 'Oxygen Mono', monospace;
-
-	--line-height: 1.1875;
-
-	--transition-duration: 0.3s;
-
-	--control-height: 1.875rem;
-
-	--gradient-multiplier: 4;
-
-	--dropdown-background: url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMDAwMDAwO3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=");
-
-	--primary-color: hsl(var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base));
-	--primary-color-light: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--light-step))
-	);
-	--primary-color-lighter: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--lighter-step))
-	);
-	--primary-color-dark: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--dark-step))
-	);
-	--primary-color-darker: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--darker-step))
-	);
-	--primary-color-alpha-25: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 25%
-	);
-	--primary-color-alpha-50: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 50%
-	);
-
-	--negative-color: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base)
-	);
-	--negative-color-light: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--light-step))
-	);
-	--negative-color-lighter: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--lighter-step))
-	);
-	--negative-color-dark: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--dark-step))
-	);
-	--negative-color-darker: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--darker-step))
-	);
-	--negative-color-alpha-25: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 25%
-	);
-	--negative-color-alpha-50: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 50%
-	);
-
-	--positive-color: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base)
-	);
-	--positive-color-light: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--light-step))
-	);
-	--positive-color-lighter: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--lighter-step))
-	);
-	--positive-color-dark: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--dark-step))
-	);
-	--positive-color-darker: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--darker-step))
-	);
-	--positive-color-alpha-25: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 25%
-	);
-	--positive-color-alpha-50: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 50%
-	);
-
-	--information-color: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base)
-	);
-	--information-color-light: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--light-step))
-	);
-	--information-color-lighter: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--lighter-step))
-	);
-	--information-color-dark: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--dark-step))
-	);
-	--information-color-darker: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--darker-step))
-	);
-	--information-color-alpha-25: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 25%
-	);
-	--information-color-alpha-50: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 50%
-	);
-
-	--warning-color: hsl(var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base));
-	--warning-color-light: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--light-step))
-	);
-	--warning-color-lighter: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--lighter-step))
-	);
-	--warning-color-dark: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--dark-step))
-	);
-	--warning-color-darker: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--darker-step))
-	);
-	--warning-color-alpha-25: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 25%
-	);
-	--warning-color-alpha-50: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 50%
-	);
-
-	--gray: hsl(var(--gray-hue), var(--gray-saturation), var(--gray-lightness-base));
-	--gray-light: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--light-step))
-	);
-	--gray-lighter: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--lighter-step))
-	);
-	--gray-dark: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--dark-step))
-	);
-	--gray-darker: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--darker-step))
-	);
-
-	--white: rgb(var(--white-base) var(--white-base) var(--white-base));
-	--black: rgb(var(--black-base) var(--black-base) var(--black-base));
-
-	--menu-text-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 25%);
-	--gradient-top-color: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) * var(--gradient-multiplier))
-	);
-	--modal-backdrop: rgba(var(--white-base) var(--white-base) var(--white-base) / 30%);
-	--modal-background: rgba(var(--white-base) var(--white-base) var(--white-base) / 90%);
-
-	--control-border-color: var(--gray);
-	--control-border-color-dark: var(--gray-dark);
-	--control-border-color-darker: var(--gray-darker);
-	--menu-text-selected-color: var(--black);
-	--disabled-color: var(--gray-darker);
-	--code-color: var(--gray-darker);
-	--gradient-bottom-color: var(--white);
-	--table-stripe-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 20%);
-
 	--message-padding-top: 0.5rem;
 	--message-padding-bottom: 0.5rem;
 	--message-padding-left: 1rem;
@@ -473,25 +298,25 @@ summary {
 
     --message-backdrop-filter: blur(0.5rem) saturate(90%);
 
+	--toast-width: 24rem;
+	--toast-offset: var(--spacing-xl);
+	--toast-gap: var(--spacing-s);
+
 	--button-padding-top: 0.25rem;
 	--button-padding-bottom: 0.25rem;
 	--button-padding-left: 1rem;
 	--button-padding-right: 1rem;
 	--button-border-width: 0.0625rem;
 	--button-disabled-filter: contrast(50%);
-	--button-border-color: var(--control-border-color);
-	--button-background: var(--white);
-	--button-color: var(--black);
 
 	--button-circle-border-width: 0.125rem;
 	--button-circle-size-small: 1.5rem;
 	--button-circle-size-regular: 2rem;
 	--button-circle-size-large: 3rem;
-	--button-circle-background: var(--white);
 	--button-circle-padding: 0.25rem;
 
 	--button-container-margin-top: 0.75rem;
-	--button-container-gap: 1rem;
+	--button-container-gap: var(--spacing-l);
 
 	--input-border-bottom-width: 0.125rem;
 	--input-border-width: 0.0625rem;
@@ -503,10 +328,14 @@ summary {
 	--input-padding-left: 0.5rem;
 	--input-padding-right: 0.5rem;
 
-	--input-group-gap: 0.75rem;
-	--input-group-special-gap: 0.25rem;
+	--input-group-gap: var(--spacing-m);
+	--input-group-special-gap: var(--spacing-xs);
 
 	--input-header-font-size: 1.5rem;
+	--input-error-font-size: 0.875rem;
+
+	--file-drop-zone-min-height: 8rem;
+	--file-preview-size: 3rem;
 
 	--radio-size: 1rem;
 
@@ -521,17 +350,13 @@ summary {
 	--switch-rail-width: calc(var(--switch-rail-height) * 2);
 	--switch-rail-border-width: 0.0625rem;
 
-	--range-track-background: var(--control-border-color);
 	--range-track-height: 0.25rem;
 	--range-track-min-width: 100%;
 
-	--range-thumb-color: var(--control-border-color);
-	--range-thumb-border-color: var(--primary-color);
 	--range-thumb-width: var(--range-track-height);
 	--range-thumb-height: calc(var(--range-track-height) * 5);
 	--range-thumb-border-size: var(--input-border-width);
 	--range-thumb-border-radius: var(--border-radius);
-	--range-thumb-background-color: var(--range-thumb-color);
 	--range-thumb-cursor: pointer;
 
 	--hr-margin-side: 2rem;
@@ -544,7 +369,7 @@ summary {
 	--top-menu-item-divider-height: 1rem;
 	--top-menu-item-divider-width: 0.0625rem;
 
-	--menu-gap: 1rem;
+	--menu-gap: var(--spacing-l);
 	--main-menu-height: var(--font-size-main-menu);
 	--sub-menu-height: var(--font-size-sub-menu);
 
@@ -558,7 +383,7 @@ summary {
 
 	--profile-picture-size: var(--top-menu-height);
 
-	--page-top-spacing: 2rem;
+	--page-top-spacing: var(--spacing-xl);
 	--page-side-spacing: 10rem;
 	--page-height: calc(
 		100vh - var(--top-menu-height) - var(--main-menu-height) - var(--menu-gap) -
@@ -568,9 +393,9 @@ summary {
 	--page-width: calc(100vw - var(--page-side-spacing) - var(--page-side-spacing));
 
 	--bottom-bar-height: 4.5rem;
-	--bottom-bar-spacing: 2rem;
+	--bottom-bar-spacing: var(--spacing-xl);
 
-	--list-spacing: 1rem;
+	--list-spacing: var(--spacing-l);
 
 	--list-items-width: 13.25rem;
 	--list-items-padding-right: 1rem;
@@ -604,15 +429,13 @@ summary {
 
 	--progress-bar-gradient-width-1: 0.5rem;
 	--progress-bar-gradient-width-2: 1rem;
-	--progress-bar-gradient-color: var(--primary-color);
-	--progress-bar-gradient-color-light: var(--primary-color-light);
 	--progress-bar-width-small: var(--width-small);
 	--progress-bar-width-medium: var(--width-medium);
 	--progress-bar-width-large: var(--width-large);
 	--progress-bar-height: 0.5rem;
 
 	--tab-links-height: 1.25rem;
-	--tab-gap: 0.75rem;
+	--tab-gap: var(--spacing-m);
 
 	--tab-links-gap: 1.25rem;
 
@@ -628,10 +451,10 @@ summary {
 	--table-td-padding-bottom: 0.25rem;
 	--table-td-border-bottom-width: 0.0625rem;
 
-	--toolbar-gap: 1rem;
+	--toolbar-gap: var(--spacing-l);
 
 	--title-font-size: 2.25rem;
-	--title-gap: 1rem;
+	--title-gap: var(--spacing-l);
 
 	--h1-font-size: 2rem;
 	--h2-font-size: 1.75rem;
@@ -640,8 +463,6 @@ summary {
 	--h5-font-size: 1.25rem;
 	--h6-font-size: 1rem;
 
-	--a-color: var(--primary-color);
-
 	--kbd-padding-top: 2px;
 	--kbd-padding-bottom: 2px;
 	--kbd-padding-right: 4px;
@@ -654,459 +475,23 @@ summary {
 	font-size: var(--font-size);
 }
 
-@media screen and (prefers-color-scheme: dark) {
-	:root {
-		--primary-hue: ${primary_color_dark_hue};
-		--primary-saturation: ${primary_color_dark_saturation}%;
-		--primary-lightness-base: ${primary_color_dark_lightness}%;
-
-		--negative-hue: 2;
-		--negative-saturation: 66%;
-		--negative-lightness-base: 28%;
-
-		--positive-hue: 146;
-		--positive-saturation: 35%;
-		--positive-lightness-base: 28%;
-
-		--information-hue: 205;
-		--information-saturation: 64%;
-		--information-lightness-base: 28%;
-
-		--warning-hue: 42;
-		--warning-saturation: 79%;
-		--warning-lightness-base: 40%;
-
-		--gray-lightness-base: 20%;
-
-		--light-step: -10%;
-		--lighter-step: -20%;
-
-		--dark-step: 10%;
-		--darker-step: 20%;
-
-		--white-base: 0;
-		--black-base: 204;
-
-		--gradient-multiplier: 0.25;
-
-		--dropdown-background: url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMWE0Zjc1O3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=");
-
-		background: var(--white);
-		color: var(--black);
-	}
-}
-
-.is--light {
-	--primary-hue: ${primary_color_hue};
-	--primary-saturation: ${primary_color_saturation}%;
-	--primary-lightness-base: ${primary_color_lightness}%;
-
-	--negative-hue: 2;
-	--negative-saturation: 67%;
-	--negative-lightness-base: 57%;
-
-	--positive-hue: 146;
-	--positive-saturation: 35%;
-	--positive-lightness-base: 46%;
-
-	--information-hue: 205;
-	--information-saturation: 64%;
-	--information-lightness-base: 48%;
-
-	--warning-hue: 42;
-	--warning-saturation: 54%;
-	--warning-lightness-base: 54%;
-
-	--gray-lightness-base: 80%;
-
-	--light-step: 10%;
-	--lighter-step: 20%;
-
-	--dark-step: -10%;
-	--darker-step: -20%;
-
-	--white-base: 255;
-	--black-base: 51;
-
-	--gradient-multiplier: 4;
-
-	--dropdown-background: url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMDAwMDAwO3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=");
-
-	--primary-color: hsl(var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base));
-	--primary-color-light: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--light-step))
-	);
-	--primary-color-lighter: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--lighter-step))
-	);
-	--primary-color-dark: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--dark-step))
-	);
-	--primary-color-darker: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--darker-step))
-	);
-	--primary-color-alpha-25: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 25%
-	);
-	--primary-color-alpha-50: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 50%
-	);
-
-	--negative-color: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base)
-	);
-	--negative-color-light: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--light-step))
-	);
-	--negative-color-lighter: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--lighter-step))
-	);
-	--negative-color-dark: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--dark-step))
-	);
-	--negative-color-darker: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--darker-step))
-	);
-	--negative-color-alpha-25: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 25%
-	);
-	--negative-color-alpha-50: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 50%
-	);
-
-	--positive-color: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base)
-	);
-	--positive-color-light: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--light-step))
-	);
-	--positive-color-lighter: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--lighter-step))
-	);
-	--positive-color-dark: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--dark-step))
-	);
-	--positive-color-darker: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--darker-step))
-	);
-	--positive-color-alpha-25: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 25%
-	);
-	--positive-color-alpha-50: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 50%
-	);
-
-	--information-color: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base)
-	);
-	--information-color-light: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--light-step))
-	);
-	--information-color-lighter: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--lighter-step))
-	);
-	--information-color-dark: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--dark-step))
-	);
-	--information-color-darker: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--darker-step))
-	);
-	--information-color-alpha-25: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 25%
-	);
-	--information-color-alpha-50: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 50%
-	);
-
-	--warning-color: hsl(var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base));
-	--warning-color-light: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--light-step))
-	);
-	--warning-color-lighter: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--lighter-step))
-	);
-	--warning-color-dark: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--dark-step))
-	);
-	--warning-color-darker: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--darker-step))
-	);
-	--warning-color-alpha-25: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 25%
-	);
-	--warning-color-alpha-50: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 50%
-	);
-
-	--gray: hsl(var(--gray-hue), var(--gray-saturation), var(--gray-lightness-base));
-	--gray-light: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--light-step))
-	);
-	--gray-lighter: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--lighter-step))
-	);
-	--gray-dark: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--dark-step))
-	);
-	--gray-darker: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--darker-step))
-	);
-
-	--white: rgb(var(--white-base) var(--white-base) var(--white-base));
-	--black: rgb(var(--black-base) var(--black-base) var(--black-base));
-
-	--menu-text-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 25%);
-	--gradient-top-color: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) * var(--gradient-multiplier))
-	);
-	--gradient-bottom-color: var(--white);
-	--modal-backdrop: rgba(var(--white-base) var(--white-base) var(--white-base) / 30%);
-	--modal-background: rgba(var(--white-base) var(--white-base) var(--white-base) / 90%);
-	--a-color: var(--primary-color);
-	--control-border-color: var(--gray);
-	--control-border-color-dark: var(--gray-dark);
-	--control-border-color-darker: var(--gray-darker);
-	--range-thumb-background-color: var(--range-thumb-color);
-	--range-thumb-color: var(--control-border-color);
-	--range-thumb-border-color: var(--primary-color);
-	--range-track-background: var(--control-border-color);
-	--button-circle-background: var(--white);
-	--button-border-color: var(--control-border-color);
-	--button-background: var(--white);
-	--button-color: var(--black);
-	--progress-bar-gradient-color: var(--primary-color);
-	--progress-bar-gradient-color-light: var(--primary-color-light);
-
-	background: var(--white);
-	color: var(--black);
+.is--medium {
+	--page-side-spacing: 4rem;
+	--page-width: calc(100vw - var(--page-side-spacing) - var(--page-side-spacing));
 }
 
-.is--dark {
-	--primary-hue: ${primary_color_hue};
-	--primary-saturation: ${primary_color_dark_saturation}%;
-	--primary-lightness-base: ${primary_color_dark_lightness}%;
-
-	--negative-hue: 2;
-	--negative-saturation: 66%;
-	--negative-lightness-base: 28%;
-
-	--positive-hue: 146;
-	--positive-saturation: 35%;
-	--positive-lightness-base: 28%;
-
-	--information-hue: 205;
-	--information-saturation: 64%;
-	--information-lightness-base: 28%;
-
-	--warning-hue: 42;
-	--warning-saturation: 79%;
-	--warning-lightness-base: 40%;
-
-	--gray-lightness-base: 20%;
-
-	--light-step: -10%;
-	--lighter-step: -20%;
-
-	--dark-step: 10%;
-	--darker-step: 20%;
-
-	--white-base: 0;
-	--black-base: 204;
-
-	--gradient-multiplier: 0.25;
-
-	--dropdown-background: url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMWE0Zjc1O3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=");
-
-	--primary-color: hsl(var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base));
-	--primary-color-light: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--light-step))
-	);
-	--primary-color-lighter: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--lighter-step))
-	);
-	--primary-color-dark: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--dark-step))
-	);
-	--primary-color-darker: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) + var(--darker-step))
-	);
-	--primary-color-alpha-25: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 25%
-	);
-	--primary-color-alpha-50: hsl(
-		var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 50%
-	);
-
-	--negative-color: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base)
-	);
-	--negative-color-light: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--light-step))
-	);
-	--negative-color-lighter: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--lighter-step))
-	);
-	--negative-color-dark: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--dark-step))
-	);
-	--negative-color-darker: hsl(
-		var(--negative-hue) var(--negative-saturation)
-			calc(var(--negative-lightness-base) + var(--darker-step))
-	);
-	--negative-color-alpha-25: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 25%
-	);
-	--negative-color-alpha-50: hsl(
-		var(--negative-hue) var(--negative-saturation) var(--negative-lightness-base) / 50%
-	);
-
-	--positive-color: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base)
-	);
-	--positive-color-light: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--light-step))
-	);
-	--positive-color-lighter: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--lighter-step))
-	);
-	--positive-color-dark: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--dark-step))
-	);
-	--positive-color-darker: hsl(
-		var(--positive-hue) var(--positive-saturation)
-			calc(var(--positive-lightness-base) + var(--darker-step))
-	);
-	--positive-color-alpha-25: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 25%
-	);
-	--positive-color-alpha-50: hsl(
-		var(--positive-hue) var(--positive-saturation) var(--positive-lightness-base) / 50%
-	);
-
-	--information-color: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base)
-	);
-	--information-color-light: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--light-step))
-	);
-	--information-color-lighter: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--lighter-step))
-	);
-	--information-color-dark: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--dark-step))
-	);
-	--information-color-darker: hsl(
-		var(--information-hue) var(--information-saturation)
-			calc(var(--information-lightness-base) + var(--darker-step))
-	);
-	--information-color-alpha-25: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 25%
-	);
-	--information-color-alpha-50: hsl(
-		var(--information-hue) var(--information-saturation) var(--information-lightness-base) / 50%
-	);
-
-	--warning-color: hsl(var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base));
-	--warning-color-light: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--light-step))
-	);
-	--warning-color-lighter: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--lighter-step))
-	);
-	--warning-color-dark: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--dark-step))
-	);
-	--warning-color-darker: hsl(
-		var(--warning-hue) var(--warning-saturation)
-			calc(var(--warning-lightness-base) + var(--darker-step))
-	);
-	--warning-color-alpha-25: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 25%
-	);
-	--warning-color-alpha-50: hsl(
-		var(--warning-hue) var(--warning-saturation) var(--warning-lightness-base) / 50%
-	);
-
-	--gray: hsl(var(--gray-hue), var(--gray-saturation), var(--gray-lightness-base));
-	--gray-light: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--light-step))
-	);
-	--gray-lighter: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--lighter-step))
-	);
-	--gray-dark: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--dark-step))
-	);
-	--gray-darker: hsl(
-		var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--darker-step))
-	);
-
-	--white: rgb(var(--white-base) var(--white-base) var(--white-base));
-	--black: rgb(var(--black-base) var(--black-base) var(--black-base));
-
-	--menu-text-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 25%);
-	--gradient-top-color: hsl(
-		var(--primary-hue) var(--primary-saturation)
-			calc(var(--primary-lightness-base) * var(--gradient-multiplier))
-	);
-	--gradient-bottom-color: var(--white);
-	--modal-backdrop: rgba(var(--white-base) var(--white-base) var(--white-base) / 30%);
-	--modal-background: rgba(var(--white-base) var(--white-base) var(--white-base) / 90%);
-
-    --a-color: var(--primary-color-darker);
-	--control-border-color: var(--gray);
-	--control-border-color-dark: var(--gray-dark);
-	--control-border-color-darker: var(--gray-darker);
-    --range-thumb-background-color: var(--range-thumb-color);
-    --range-thumb-color: var(--control-border-color);
-    --range-thumb-border-color: var(--primary-color);
-    --range-track-background: var(--control-border-color);
-    --button-circle-background: var(--white);
-    --button-border-color: var(--control-border-color);
-    --button-background: var(--white);
-    --button-color: var(--black);
-    --progress-bar-gradient-color: var(--primary-color);
-    --progress-bar-gradient-color-light: var(--primary-color-light);
-
-	background: var(--white);
-	color: var(--black);
+.is--compact {
+	--top-menu-height: 3rem;
+	--top-menu-item-margin: 0.5rem;
+	--profile-picture-size: var(--top-menu-height);
+	--page-top-spacing: var(--spacing-l);
+	--page-side-spacing: 1rem;
+	--page-width: calc(100vw - var(--page-side-spacing) - var(--page-side-spacing));
+	--page-height: auto;
+	--bottom-bar-height: auto;
+	--bottom-bar-spacing: var(--spacing-l);
+	--list-items-width: 100%;
+	--title-font-size: 1.75rem;
 }
 
 body {
@@ -1117,14 +502,63 @@ body {
 	color: var(--black);
 	font-size: var(--font-size);
 }
-    "#,
-        primary_color_hue = primary_color_hue,
-        primary_color_saturation = primary_color_saturation,
-        primary_color_lightness = primary_color_lightness,
-        primary_color_dark_hue = primary_color_dark_hue,
-        primary_color_dark_saturation = primary_color_dark_saturation,
-        primary_color_dark_lightness = primary_color_dark_lightness,
-    )).expect("Should insert global styles");
+    "#
+        ),
+        style_manager,
+    )
+    .expect("Should insert global styles");
+
+    #[cfg(feature = "ssr")]
+    let helmet_bridge = html!(
+        <HelmetBridge default_title={props.default_title.clone()} format_title={props.format_title.clone()} writer={ssr_context.take_helmet_writer()} />
+    );
+    #[cfg(not(feature = "ssr"))]
+    let helmet_bridge = html!(
+        <HelmetBridge default_title={props.default_title.clone()} format_title={props.format_title.clone()} />
+    );
+
+    html!(
+        <BounceRoot>
+            {helmet_bridge}
+            <Helmet>
+                <html lang={locale.language.clone()} dir={locale.direction.to_string()} />
+                <style>
+                    {tokens.to_css()}
+                </style>
+                <style>
+                    {style.get_style_str()}
+                </style>
+            </Helmet>
+            <CosmoFontLoader source={props.font_source.clone()} />
+            <ContextProvider<CosmoLocale> context={locale}>
+                <CosmoThemeProvider default_theme={props.default_theme.clone()} storage_key={props.theme_storage_key.clone()}>
+                    <CosmoToastProvider max_toasts={props.max_toasts} close_label={props.toast_close_label.clone()}>
+                        <CosmoDialogProvider confirm_label={props.dialog_confirm_label.clone()} decline_label={props.dialog_decline_label.clone()}>
+                            <CosmoViewportProvider breakpoints={props.breakpoints}>
+                                <CosmoBackProvider>
+                                    <CosmoPageContainer>
+                                        {for props.children.iter()}
+                                    </CosmoPageContainer>
+                                </CosmoBackProvider>
+                            </CosmoViewportProvider>
+                        </CosmoDialogProvider>
+                    </CosmoToastProvider>
+                </CosmoThemeProvider>
+            </ContextProvider<CosmoLocale>>
+        </BounceRoot>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoPageContainerProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[styled_component(CosmoPageContainer)]
+fn page_container(props: &CosmoPageContainerProps) -> Html {
+    let locale = use_cosmo_locale();
+    let viewport = use_cosmo_viewport();
 
     let page_layout_style = use_style!(
         r#"
@@ -1138,22 +572,19 @@ grid-template-rows:
     );
     "#
     );
+    let compact_style = use_style!(
+        r#"
+grid-template-rows:
+    [top-menu] var(--top-menu-height) [main-menu] auto [top-spacing] var(--page-top-spacing) [content] 1fr
+    [bottom-spacing] var(--bottom-bar-spacing) [bottom-bar] auto;
+min-height: 100vh;
+    "#
+    );
 
     html!(
-        <BounceRoot>
-            <HelmetBridge default_title={props.default_title.clone()} format_title={props.format_title.clone()} />
-            <Helmet>
-                <link href="https://fonts.jinya.de/css2?family=Albert Sans:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900" rel="stylesheet" type="text/css" />
-                <link href="https://fonts.jinya.de/css2?family=Urbanist:ital,wght@0,100%3B1,100%3B0,300%3B1,300%3B0,400%3B1,400%3B0,700%3B1,700%3B0,900%3B1,900" rel="stylesheet" type="text/css" />
-                <link href="https://fonts.jinya.de/css2?family=Oxygen Mono" rel="stylesheet" type="text/css" />
-                <style>
-                    {style.get_style_str()}
-                </style>
-            </Helmet>
-            <div class={page_layout_style}>
-                {for props.children.iter()}
-            </div>
-        </BounceRoot>
+        <div class={classes!(page_layout_style, viewport.is_compact().then_some(compact_style), viewport)} dir={locale.direction.to_string()}>
+            {for props.children.iter()}
+        </div>
     )
 }
 
@@ -1173,6 +604,7 @@ pub struct CosmoTopBarProps {
 
 #[styled_component(CosmoTopBar)]
 pub fn top_bar(props: &CosmoTopBarProps) -> Html {
+    let viewport = use_cosmo_viewport();
     let top_bar_style = use_style!(
         r#"
 display: grid;
@@ -1193,8 +625,8 @@ background: linear-gradient(
     let top_bar_item_style = use_top_bar_item_style();
     let top_bar_item_right_style = use_style!(
         r#"
-margin-left: var(--top-menu-item-margin);
-border-right-width: 0;
+margin-inline-start: var(--top-menu-item-margin);
+border-inline-end-width: 0;
     "#
     );
     let profile_picture_style = use_style!(
@@ -1205,8 +637,8 @@ height: var(--profile-picture-size);
 background: var(--primary-color);
 grid-column: profilepicture;
 object-fit: cover;
-border-bottom-left-radius: var(--border-radius);
-border-bottom-right-radius: var(--border-radius);
+border-end-start-radius: var(--border-radius);
+border-end-end-radius: var(--border-radius);
     "#
     );
     let top_bar_menu_row = use_style!(
@@ -1217,11 +649,19 @@ flex-flow: row nowrap;
 grid-column: content;
     "#
     );
+    let top_bar_menu_row_compact = use_style!(
+        r#"
+justify-content: flex-start;
+overflow-x: auto;
+scrollbar-width: none;
+white-space: nowrap;
+    "#
+    );
 
     let on_click = props.right_item_on_click.clone();
     html!(
         <div class={top_bar_style}>
-            <div class={top_bar_menu_row}>
+            <div class={classes!(top_bar_menu_row, viewport.is_compact().then_some(top_bar_menu_row_compact))}>
                 {for props.children.iter()}
             </div>
             <div class={profile_picture_style.clone()}>
@@ -1250,7 +690,7 @@ padding: 0;
 text-transform: uppercase;
 color: var(--black);
 cursor: pointer;
-margin-right: var(--top-menu-item-margin);
+margin-inline-end: var(--top-menu-item-margin);
 font-size: var(--font-size-top-menu);
 
 &:after {
@@ -1259,7 +699,7 @@ font-size: var(--font-size-top-menu);
 	width: var(--top-menu-item-divider-width);
 	background: var(--black);
 	margin-top: var(--top-menu-item-divider-margin-top);
-	margin-left: var(--top-menu-item-divider-margin-left);
+	margin-inline-start: var(--top-menu-item-divider-margin-left);
 }
 
 &:last-child:after {
@@ -1395,6 +835,7 @@ pub struct CosmoBottomBarProps {
 
 #[styled_component(CosmoBottomBar)]
 pub fn bottom_bar(props: &CosmoBottomBarProps) -> Html {
+    let viewport = use_cosmo_viewport();
     let bottom_bar_style = use_style!(
         r#"
 grid-row: bottom-bar;
@@ -1402,15 +843,15 @@ align-items: center;
 display: grid;
 grid-template-columns: [left] 1fr [center] 1fr [right] 1fr;
 gap: 1rem;
-padding-left: var(--page-side-spacing);
-padding-right: var(--page-side-spacing);
+padding-inline-start: var(--page-side-spacing);
+padding-inline-end: var(--page-side-spacing);
 "#
     );
 
     let bottom_bar_item_left = use_style!(
         r#"
 grid-column: left;
-justify-self: left;
+justify-self: start;
 display: flex;
 gap: 1rem;
 align-items: center;
@@ -1428,7 +869,7 @@ justify-items: center;
     let bottom_bar_item_right = use_style!(
         r#"
 grid-column: right;
-justify-self: right;
+justify-self: end;
 display: flex;
 gap: 1rem;
 align-items: center;
@@ -1441,12 +882,30 @@ color: var(--black);
 display: block;
     "#
     );
+    let bottom_bar_compact = use_style!(
+        r#"
+grid-template-columns: [left] 1fr [right] auto;
+padding-top: var(--spacing-m);
+padding-bottom: var(--spacing-m);
+
+> * {
+	grid-row: 2;
+	flex-wrap: wrap;
+}
+
+> :nth-child(2):not(:last-child) {
+	grid-column: left / -1;
+	grid-row: 1;
+	justify-self: stretch;
+}
+    "#
+    );
 
     let left = props.children.iter().find(|item| item.is_left());
     let right = props.children.iter().find(|item| item.is_right());
 
     html!(
-        <div class={bottom_bar_style}>
+        <div class={classes!(bottom_bar_style, viewport.is_compact().then_some(bottom_bar_compact))}>
             <div class={bottom_bar_item_left}>
                 if let Some(left) = left.clone() {
                     {left}
@@ -5,22 +5,39 @@ pub mod prelude {
     pub use chrono::NaiveTime;
     pub use color_art::color;
     pub use color_art::Color;
+    pub use web_sys::File;
     use yew::Classes;
+    #[cfg(feature = "derive")]
+    pub use yew_cosmo_derive::CosmoFormModel;
     #[cfg(feature = "with-icons")]
     pub use yew_icons::IconId;
 
+    pub use crate::back::*;
+    pub use crate::breadcrumbs::*;
     pub use crate::button::*;
+    pub use crate::calendar::*;
+    pub use crate::contrast::*;
+    pub use crate::dialog::*;
+    pub use crate::fonts::*;
     pub use crate::form::*;
+    pub use crate::form_state::*;
     pub use crate::layout::*;
     pub use crate::list::*;
     pub use crate::loader::*;
+    pub use crate::locale::*;
     pub use crate::menu::*;
     pub use crate::message::*;
     pub use crate::modal::*;
+    pub use crate::ssr::*;
     pub use crate::tab::*;
     pub use crate::table::*;
+    pub use crate::theme::*;
+    pub use crate::toast::*;
+    pub use crate::tokens::*;
     pub use crate::toolbar::*;
     pub use crate::typography::*;
+    pub use crate::validation::*;
+    pub use crate::viewport::*;
 
     #[derive(PartialEq, Clone, Default)]
     pub enum CosmoTheme {
@@ -30,13 +47,13 @@ pub mod prelude {
         Dark,
     }
 
-    impl ToString for CosmoTheme {
-        fn to_string(&self) -> String {
-            match self {
-                CosmoTheme::Auto => "".to_string(),
-                CosmoTheme::Light => "is--light".to_string(),
-                CosmoTheme::Dark => "is--dark".to_string(),
-            }
+    impl std::fmt::Display for CosmoTheme {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(match self {
+                CosmoTheme::Auto => "",
+                CosmoTheme::Light => "is--light",
+                CosmoTheme::Dark => "is--dark",
+            })
         }
     }
 
@@ -47,15 +64,29 @@ pub mod prelude {
     }
 }
 
+mod back;
+mod breadcrumbs;
 mod button;
+mod calendar;
+mod contrast;
+mod dialog;
+mod fonts;
 mod form;
+mod form_state;
 mod layout;
 mod list;
 mod loader;
+mod locale;
 mod menu;
 mod message;
 mod modal;
+mod ssr;
 mod tab;
 mod table;
+mod theme;
+mod toast;
+mod tokens;
 mod toolbar;
 mod typography;
+mod validation;
+mod viewport;
@@ -4,6 +4,8 @@ use yew::prelude::*;
 use yew::virtual_dom::VChild;
 
 use crate::button::CosmoButton;
+use crate::locale::use_cosmo_locale;
+use crate::viewport::use_cosmo_viewport;
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoSideListProps {
@@ -19,6 +21,8 @@ pub struct CosmoSideListProps {
     pub selected_index: Option<usize>,
     #[prop_or_default]
     pub on_select_item: Option<Callback<usize>>,
+    #[prop_or_default]
+    pub back_label: Option<AttrValue>,
 }
 
 #[styled_component(CosmoSideList)]
@@ -35,8 +39,8 @@ height: var(--page-height);
 grid-column: items-list;
 display: flex;
 flex-flow: column;
-padding-right: var(--list-items-padding-right);
-border-right: var(--list-items-border-width) solid var(--control-border-color);
+padding-inline-end: var(--list-items-padding-right);
+border-inline-end: var(--list-items-border-width) solid var(--control-border-color);
 box-sizing: border-box;
 height: var(--page-height);
 overflow-y: auto;
@@ -54,13 +58,63 @@ overflow-y: auto;
     "#
     );
 
+    let compact_style = use_style!(
+        r#"
+display: flex;
+flex-direction: column;
+gap: var(--list-spacing);
+height: auto;
+
+> nav {
+	padding-inline-end: 0;
+	border-inline-end: 0;
+	height: auto;
+	gap: 0.25rem;
+}
+
+> nav .cosmo-button {
+	margin-top: var(--list-spacing);
+}
+    "#
+    );
+    let back_style = use_style!(
+        r#"
+align-self: flex-start;
+display: inline-flex;
+align-items: center;
+gap: 0.5rem;
+padding: 0;
+border: 0;
+background: transparent;
+color: var(--primary-color-text);
+font-family: var(--font-family-menu);
+font-size: var(--font-size);
+text-transform: uppercase;
+cursor: pointer;
+
+&::before {
+	content: '';
+	width: 0.5rem;
+	height: 0.5rem;
+	border: var(--back-button-arrow-stroke-width) solid currentColor;
+	border-right: 0;
+	border-bottom: 0;
+	transform: rotate(-45deg);
+}
+
+&:dir(rtl)::before {
+	transform: rotate(135deg);
+}
+    "#
+    );
+
     let item_style = use_style!(
         r#"
 --list-item-color: var(--black);
 
 color: var(--list-item-color);
-padding: var(--list-item-padding-top) var(--list-item-padding-right)
-    var(--list-item-padding-bottom) var(--list-item-padding-left);
+padding-block: var(--list-item-padding-top) var(--list-item-padding-bottom);
+padding-inline: var(--list-item-padding-left) var(--list-item-padding-right);
 overflow-x: hidden;
 white-space: nowrap;
 text-overflow: ellipsis;
@@ -81,23 +135,15 @@ align-items: center;
     let item_active_style = use_style!(
         r#"
 --list-item-background: var(--primary-color);
---list-item-color: var(--white);
+--list-item-color: var(--primary-color-foreground);
 
 font-weight: var(--font-weight-bold);
 
-@media screen and (prefers-color-scheme: dark) {
-    color: var(--black);
-}
-
 &:hover {
 	--list-item-background: var(--primary-color);
-	--list-item-color: var(--white);
+	--list-item-color: var(--primary-color-foreground);
 
 	font-weight: var(--font-weight-bold);
-
-    @media screen and (prefers-color-scheme: dark) {
-		--list-item-color: var(--black);
-    }
 }
 
 &::selection,
@@ -120,49 +166,76 @@ font-weight: var(--font-weight-bold);
     } else {
         (*selected_item_state).unwrap_or(0)
     };
+    let viewport = use_cosmo_viewport();
+    let locale = use_cosmo_locale();
+    let back_label = props
+        .back_label
+        .clone()
+        .unwrap_or(locale.labels.back.clone());
+    let detail_open_state = use_state_eq(|| false);
+    let is_compact = viewport.is_compact();
+    let show_items = !is_compact || !*detail_open_state;
+    let show_content = !is_compact || *detail_open_state;
+
+    let on_back = use_callback(
+        detail_open_state.clone(),
+        |_: MouseEvent, detail_open_state| detail_open_state.set(false),
+    );
 
     html!(
-        <div class={list_style}>
-            <nav class={list_items_style}>
-                {for props.children.iter().enumerate().map(|(idx, child)| {
-                    let label = child.props.label.clone();
-                    let selected_item_state = selected_item_state.clone();
-                    let item_active_style = item_active_style.clone();
-                    let item_style = item_style.clone();
-                    let on_click = {
+        <div class={classes!(list_style, is_compact.then_some(compact_style))}>
+            if show_items {
+                <nav class={list_items_style}>
+                    {for props.children.iter().enumerate().map(|(idx, child)| {
+                        let label = child.props.label.clone();
                         let selected_item_state = selected_item_state.clone();
+                        let item_active_style = item_active_style.clone();
+                        let item_style = item_style.clone();
+                        let on_click = {
+                            let selected_item_state = selected_item_state.clone();
+                            let detail_open_state = detail_open_state.clone();
 
-                        if let Some(on_select) = props.on_select_item.clone() {
-                            Callback::from(move|_| on_select.emit(idx))
+                            if let Some(on_select) = props.on_select_item.clone() {
+                                Callback::from(move |_| {
+                                    detail_open_state.set(true);
+                                    on_select.emit(idx);
+                                })
+                            } else {
+                                Callback::from(move |_| {
+                                    detail_open_state.set(true);
+                                    selected_item_state.set(Some(idx));
+                                })
+                            }
+                        };
+                        let classes = if selected_idx == idx {
+                            classes!(item_style, item_active_style)
                         } else {
-                            Callback::from(move |_| {
-                                selected_item_state.set(Some(idx));
-                            })
-                        }
-                    };
-                    let classes = if selected_idx == idx {
-                        classes!(item_style, item_active_style)
+                            classes!(item_style)
+                        };
+
+                        html!(
+                            <a class={classes} onclick={on_click}>{label.clone()}</a>
+                        )
+                    })}
+                    {if props.has_add_button {
+                        let on_click = props.add_button_on_click.clone();
+
+                        html!(<CosmoButton is_full_width={true} on_click={move |_| on_click.emit(())} label={props.add_button_label.clone()} />)
                     } else {
-                        classes!(item_style)
-                    };
-
-                    html!(
-                        <a class={classes} onclick={on_click}>{label.clone()}</a>
-                    )
-                })}
-                {if props.has_add_button {
-                    let on_click = props.add_button_on_click.clone();
-
-                    html!(<CosmoButton is_full_width={true} on_click={move |_| on_click.emit(())} label={props.add_button_label.clone()} />)
-                } else {
-                    html!()
-                }}
-            </nav>
-            <div class={list_content_style}>
-                if let Some((_, item)) = props.children.iter().enumerate().nth(selected_idx).clone() {
-                    {item}
+                        html!()
+                    }}
+                </nav>
+            }
+            if show_content {
+                if is_compact {
+                    <button class={back_style} type="button" onclick={on_back}>{back_label}</button>
                 }
-            </div>
+                <div class={list_content_style}>
+                    if let Some((_, item)) = props.children.iter().enumerate().nth(selected_idx).clone() {
+                        {item}
+                    }
+                </div>
+            }
         </div>
     )
 }
@@ -0,0 +1,397 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
+use yew::prelude::*;
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum CosmoTextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl CosmoTextDirection {
+    pub fn is_rtl(&self) -> bool {
+        *self == CosmoTextDirection::Rtl
+    }
+}
+
+impl std::fmt::Display for CosmoTextDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CosmoTextDirection::Ltr => "ltr",
+            CosmoTextDirection::Rtl => "rtl",
+        })
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct CosmoLocaleLabels {
+    pub back: AttrValue,
+    pub breadcrumbs: AttrValue,
+    pub calendar: AttrValue,
+    pub cancel: AttrValue,
+    pub clear: AttrValue,
+    pub close: AttrValue,
+    pub drop_files: AttrValue,
+    pub invalid_date: AttrValue,
+    pub invalid_number: AttrValue,
+    pub invalid_time: AttrValue,
+    pub menu: AttrValue,
+    pub months: [AttrValue; 12],
+    pub next: AttrValue,
+    pub next_month: AttrValue,
+    pub next_year: AttrValue,
+    pub no_data: AttrValue,
+    pub no_results: AttrValue,
+    pub ok: AttrValue,
+    pub previous: AttrValue,
+    pub previous_month: AttrValue,
+    pub previous_year: AttrValue,
+    pub remove: AttrValue,
+    pub rows_per_page: AttrValue,
+    pub select_all_rows: AttrValue,
+    pub select_row: AttrValue,
+    pub selected: AttrValue,
+    pub show_full_path: AttrValue,
+    pub show_siblings: AttrValue,
+    pub time: AttrValue,
+    pub time_zone: AttrValue,
+    pub today: AttrValue,
+    pub week: AttrValue,
+    pub weekdays: [AttrValue; 7],
+}
+
+impl CosmoLocaleLabels {
+    pub fn english() -> Self {
+        Self {
+            back: AttrValue::from("Back"),
+            breadcrumbs: AttrValue::from("Breadcrumbs"),
+            calendar: AttrValue::from("Open calendar"),
+            cancel: AttrValue::from("Cancel"),
+            clear: AttrValue::from("Clear"),
+            close: AttrValue::from("Close"),
+            drop_files: AttrValue::from("Drop files here or click to browse"),
+            invalid_date: AttrValue::from("Please enter a valid date"),
+            invalid_number: AttrValue::from("Please enter a valid number"),
+            invalid_time: AttrValue::from("Please enter a valid time"),
+            menu: AttrValue::from("Menu"),
+            months: [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]
+            .map(AttrValue::from),
+            next: AttrValue::from("Next"),
+            next_month: AttrValue::from("Next month"),
+            next_year: AttrValue::from("Next year"),
+            no_data: AttrValue::from("No data available"),
+            no_results: AttrValue::from("No results"),
+            ok: AttrValue::from("OK"),
+            previous: AttrValue::from("Previous"),
+            previous_month: AttrValue::from("Previous month"),
+            previous_year: AttrValue::from("Previous year"),
+            remove: AttrValue::from("Remove"),
+            rows_per_page: AttrValue::from("Rows per page"),
+            select_all_rows: AttrValue::from("Select all rows"),
+            select_row: AttrValue::from("Select row"),
+            selected: AttrValue::from("selected"),
+            show_full_path: AttrValue::from("Show full path"),
+            show_siblings: AttrValue::from("Show siblings"),
+            time: AttrValue::from("Time"),
+            time_zone: AttrValue::from("Time zone"),
+            today: AttrValue::from("Today"),
+            week: AttrValue::from("Wk"),
+            weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"].map(AttrValue::from),
+        }
+    }
+
+    pub fn german() -> Self {
+        Self {
+            back: AttrValue::from("Zurück"),
+            breadcrumbs: AttrValue::from("Brotkrumen"),
+            calendar: AttrValue::from("Kalender öffnen"),
+            cancel: AttrValue::from("Abbrechen"),
+            clear: AttrValue::from("Leeren"),
+            close: AttrValue::from("Schließen"),
+            drop_files: AttrValue::from("Dateien hier ablegen oder klicken zum Auswählen"),
+            invalid_date: AttrValue::from("Bitte ein gültiges Datum eingeben"),
+            invalid_number: AttrValue::from("Bitte eine gültige Zahl eingeben"),
+            invalid_time: AttrValue::from("Bitte eine gültige Uhrzeit eingeben"),
+            menu: AttrValue::from("Menü"),
+            months: [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ]
+            .map(AttrValue::from),
+            next: AttrValue::from("Weiter"),
+            next_month: AttrValue::from("Nächster Monat"),
+            next_year: AttrValue::from("Nächstes Jahr"),
+            no_data: AttrValue::from("Keine Daten vorhanden"),
+            no_results: AttrValue::from("Keine Ergebnisse"),
+            ok: AttrValue::from("OK"),
+            previous: AttrValue::from("Zurück"),
+            previous_month: AttrValue::from("Vorheriger Monat"),
+            previous_year: AttrValue::from("Vorheriges Jahr"),
+            remove: AttrValue::from("Entfernen"),
+            rows_per_page: AttrValue::from("Zeilen pro Seite"),
+            select_all_rows: AttrValue::from("Alle Zeilen auswählen"),
+            select_row: AttrValue::from("Zeile auswählen"),
+            selected: AttrValue::from("ausgewählt"),
+            show_full_path: AttrValue::from("Ganzen Pfad anzeigen"),
+            show_siblings: AttrValue::from("Geschwister anzeigen"),
+            time: AttrValue::from("Uhrzeit"),
+            time_zone: AttrValue::from("Zeitzone"),
+            today: AttrValue::from("Heute"),
+            week: AttrValue::from("KW"),
+            weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"].map(AttrValue::from),
+        }
+    }
+}
+
+impl Default for CosmoLocaleLabels {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct CosmoLocale {
+    pub language: AttrValue,
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+    pub date_format: AttrValue,
+    pub time_format: AttrValue,
+    pub date_time_format: AttrValue,
+    pub first_day_of_week: Weekday,
+    pub direction: CosmoTextDirection,
+    pub labels: CosmoLocaleLabels,
+}
+
+impl CosmoLocale {
+    pub fn english() -> Self {
+        Self {
+            language: AttrValue::from("en"),
+            decimal_separator: '.',
+            group_separator: Some(','),
+            date_format: AttrValue::from("%m/%d/%Y"),
+            time_format: AttrValue::from("%I:%M %p"),
+            date_time_format: AttrValue::from("%m/%d/%Y %I:%M %p"),
+            first_day_of_week: Weekday::Sun,
+            direction: CosmoTextDirection::Ltr,
+            labels: CosmoLocaleLabels::english(),
+        }
+    }
+
+    pub fn german() -> Self {
+        Self {
+            language: AttrValue::from("de"),
+            decimal_separator: ',',
+            group_separator: Some('.'),
+            date_format: AttrValue::from("%d.%m.%Y"),
+            time_format: AttrValue::from("%H:%M"),
+            date_time_format: AttrValue::from("%d.%m.%Y %H:%M"),
+            first_day_of_week: Weekday::Mon,
+            direction: CosmoTextDirection::Ltr,
+            labels: CosmoLocaleLabels::german(),
+        }
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        match self.group_separator {
+            Some(separator) => {
+                let mut grouped = String::new();
+                for (idx, digit) in digits.chars().enumerate() {
+                    if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                        grouped.push(separator);
+                    }
+                    grouped.push(digit);
+                }
+
+                grouped
+            }
+            None => digits.to_string(),
+        }
+    }
+
+    pub fn format_integer(&self, value: i64) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+
+        format!(
+            "{sign}{}",
+            self.group_digits(value.unsigned_abs().to_string().as_str())
+        )
+    }
+
+    pub fn format_decimal(&self, value: f64, decimal_places: u8) -> String {
+        let formatted = format!("{:.*}", decimal_places as usize, value.abs());
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+        let sign = if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            "-"
+        } else {
+            ""
+        };
+
+        if fraction.is_empty() {
+            format!("{sign}{}", self.group_digits(integer))
+        } else {
+            format!(
+                "{sign}{}{}{fraction}",
+                self.group_digits(integer),
+                self.decimal_separator
+            )
+        }
+    }
+
+    fn normalize_number(&self, value: &str) -> String {
+        value
+            .trim()
+            .chars()
+            .filter(|c| Some(*c) != self.group_separator && !c.is_whitespace())
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect()
+    }
+
+    pub fn parse_integer(&self, value: &str) -> Option<i64> {
+        self.normalize_number(value).parse::<i64>().ok()
+    }
+
+    pub fn parse_decimal(&self, value: &str) -> Option<f64> {
+        self.normalize_number(value)
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    pub fn format_date(&self, value: &NaiveDate) -> String {
+        value.format(self.date_format.as_str()).to_string()
+    }
+
+    pub fn parse_date(&self, value: &str) -> Option<NaiveDate> {
+        let value = value.trim();
+        NaiveDate::parse_from_str(value, self.date_format.as_str())
+            .or_else(|_| NaiveDate::parse_from_str(value, "%F"))
+            .ok()
+    }
+
+    pub fn parse_time(&self, value: &str) -> Option<NaiveTime> {
+        let value = value.trim();
+        NaiveTime::parse_from_str(value, self.time_format.as_str())
+            .or_else(|_| NaiveTime::parse_from_str(value, "%R"))
+            .ok()
+    }
+
+    pub fn parse_date_time(&self, value: &str) -> Option<NaiveDateTime> {
+        let value = value.trim();
+        NaiveDateTime::parse_from_str(value, self.date_time_format.as_str())
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%F %R"))
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%FT%R"))
+            .ok()
+    }
+
+    pub fn format_time(&self, value: &NaiveTime) -> String {
+        value.format(self.time_format.as_str()).to_string()
+    }
+
+    pub fn format_date_time<Tz>(&self, value: &DateTime<Tz>) -> String
+    where
+        Tz: TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        value.format(self.date_time_format.as_str()).to_string()
+    }
+}
+
+impl Default for CosmoLocale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+#[hook]
+pub fn use_cosmo_locale() -> CosmoLocale {
+    use_context::<CosmoLocale>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_formats_and_parses_numbers() {
+        let english = CosmoLocale::english();
+        let german = CosmoLocale::german();
+
+        assert_eq!(english.format_integer(-1234567), "-1,234,567");
+        assert_eq!(german.format_integer(1234), "1.234");
+        assert_eq!(english.format_decimal(1234.5, 2), "1,234.50");
+        assert_eq!(german.format_decimal(-0.001, 2), "0,00");
+        assert_eq!(german.format_decimal(-1234.567, 2), "-1.234,57");
+        assert_eq!(german.parse_decimal("1.234,5"), Some(1234.5));
+        assert_eq!(english.parse_decimal("1,234.5"), Some(1234.5));
+        assert_eq!(german.parse_integer("12a"), None);
+        assert_eq!(
+            german.format_date(&NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()),
+            "02.01.2024"
+        );
+    }
+
+    #[test]
+    fn locale_parses_dates() {
+        let english = CosmoLocale::english();
+        let german = CosmoLocale::german();
+
+        assert_eq!(
+            german.parse_date("02.01.2024"),
+            NaiveDate::from_ymd_opt(2024, 1, 2)
+        );
+        assert_eq!(
+            english.parse_date("2024-01-02"),
+            NaiveDate::from_ymd_opt(2024, 1, 2)
+        );
+        assert_eq!(german.parse_date("31.02.2024"), None);
+        assert_eq!(
+            english.parse_date_time("01/02/2024 07:30 PM"),
+            NaiveDate::from_ymd_opt(2024, 1, 2).and_then(|date| date.and_hms_opt(19, 30, 0))
+        );
+    }
+
+    #[test]
+    fn locale_parses_times() {
+        let english = CosmoLocale::english();
+        let german = CosmoLocale::german();
+
+        assert_eq!(
+            german.parse_time("19:30"),
+            NaiveTime::from_hms_opt(19, 30, 0)
+        );
+        assert_eq!(
+            english.parse_time("07:30 PM"),
+            NaiveTime::from_hms_opt(19, 30, 0)
+        );
+        assert_eq!(
+            english.parse_time("19:30"),
+            NaiveTime::from_hms_opt(19, 30, 0)
+        );
+        assert_eq!(german.parse_time("19:"), None);
+    }
+}
@@ -1,4 +1,8 @@
+#[cfg(feature = "with-yew-router")]
+use std::rc::Rc;
+
 use stylist::yew::{styled_component, use_style};
+use web_sys::Element;
 use yew::prelude::*;
 #[cfg(feature = "with-yew-router")]
 use yew_router::prelude::*;
@@ -30,6 +34,10 @@ grid-row: main-menu;
 pub struct CosmoMenuBarProps {
     #[prop_or_default]
     pub children: Children,
+    #[prop_or_default]
+    pub menu_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub back_label: Option<AttrValue>,
 }
 
 #[styled_component(CosmoMenuBar)]
@@ -52,16 +60,93 @@ grid-template-columns:
 	width: var(--menu-left-touch-width);
 	height: 100%;
 	background: var(--primary-color);
-	border-bottom-right-radius: var(--border-radius);
-	border-top-right-radius: var(--border-radius);
+	border-end-end-radius: var(--border-radius);
+	border-start-end-radius: var(--border-radius);
+}
+    "#
+    );
+    let compact_style = use_style!(
+        r#"
+grid-template-columns:
+    [left-touch] var(--menu-left-touch-width) [spacing1] 1rem [backbutton] var(--back-button-width)
+    [spacing2] 1fr [content] var(--back-button-width) [spacing3] var(--page-side-spacing);
+align-items: center;
+    "#
+    );
+    let toggle_style = use_style!(
+        r#"
+grid-column: content;
+height: var(--back-button-width);
+width: var(--back-button-width);
+box-sizing: border-box;
+border: var(--back-button-border-width) solid var(--control-border-color);
+border-radius: 50%;
+background: var(--white);
+cursor: pointer;
+display: flex;
+flex-direction: column;
+justify-content: center;
+align-items: center;
+gap: 0.25rem;
+padding: 0;
+
+span {
+	display: block;
+	width: 1.25rem;
+	height: var(--back-button-arrow-stroke-width);
+	background: var(--primary-color);
+}
+
+&:hover,
+&:focus-visible {
+	border-color: var(--primary-color);
 }
     "#
     );
+    let backdrop_style = use_style!(
+        r#"
+position: fixed;
+inset: 0;
+z-index: 1;
+background: var(--modal-backdrop);
+backdrop-filter: var(--modal-container-backdrop-filter);
+    "#
+    );
+
+    let viewport = use_cosmo_viewport();
+    let locale = use_cosmo_locale();
+    let menu_label = props
+        .menu_label
+        .clone()
+        .unwrap_or(locale.labels.menu.clone());
+    let drawer_id = use_state_eq(|| uuid::Uuid::new_v4().to_string());
+    let drawer_open_state = use_state_eq(|| false);
+    let is_compact = viewport.is_compact();
+    let is_drawer_open = is_compact && *drawer_open_state;
+
+    let on_toggle = use_callback(
+        drawer_open_state.clone(),
+        |_: MouseEvent, drawer_open_state| drawer_open_state.set(!**drawer_open_state),
+    );
+    let on_close = use_callback(drawer_open_state.clone(), |_: (), drawer_open_state| {
+        drawer_open_state.set(false)
+    });
+    let on_backdrop_click = on_close.reform(|_: MouseEvent| ());
 
     html!(
-        <div class={menu_bar_style}>
-            <CosmoBackButton />
-            <CosmoMenuCollection>
+        <div class={classes!(menu_bar_style, is_compact.then_some(compact_style))}>
+            <CosmoBackButton label={props.back_label.clone()} />
+            if is_compact {
+                <button class={toggle_style} type="button" aria-label={menu_label} aria-expanded={is_drawer_open.to_string()} aria-controls={(*drawer_id).clone()} onclick={on_toggle}>
+                    <span></span>
+                    <span></span>
+                    <span></span>
+                </button>
+                if is_drawer_open {
+                    <div class={backdrop_style} onclick={on_backdrop_click}></div>
+                }
+            }
+            <CosmoMenuCollection id={(*drawer_id).clone()} is_drawer={is_compact} is_open={is_drawer_open} on_close={on_close}>
                 {for props.children.iter()}
             </CosmoMenuCollection>
         </div>
@@ -72,6 +157,13 @@ grid-template-columns:
 struct CosmoMenuCollectionProps {
     #[prop_or_default]
     pub children: Children,
+    pub id: AttrValue,
+    #[prop_or(false)]
+    pub is_drawer: bool,
+    #[prop_or(false)]
+    pub is_open: bool,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
 }
 
 #[styled_component(CosmoMenuCollection)]
@@ -84,14 +176,332 @@ grid-template-rows: [main-menu] var(--main-menu-height) [sub-menu] var(--sub-men
 grid-row-gap: var(--menu-gap);
     "#
     );
+    let drawer_style = use_style!(
+        r#"
+position: fixed;
+top: 0;
+bottom: 0;
+inset-inline-start: 0;
+z-index: 2;
+width: min(20rem, 85vw);
+box-sizing: border-box;
+display: flex;
+flex-direction: column;
+gap: var(--menu-gap);
+padding: var(--spacing-l) var(--page-side-spacing);
+overflow-y: auto;
+background: var(--white);
+border-inline-end: var(--menu-left-touch-width) solid var(--primary-color);
+--drawer-offset: -100%;
+transform: translateX(var(--drawer-offset));
+visibility: hidden;
+transition:
+    transform var(--transition-duration),
+    visibility var(--transition-duration);
+
+&:dir(rtl) {
+	--drawer-offset: 100%;
+}
+
+> div {
+	display: flex;
+	flex-direction: column;
+	gap: calc(var(--menu-gap) / 2);
+}
+    "#
+    );
+    let drawer_open_style = use_style!(
+        r#"
+transform: translateX(0);
+visibility: visible;
+    "#
+    );
+
+    let on_click = use_callback(props.on_close.clone(), |evt: MouseEvent, on_close| {
+        let is_link = evt
+            .target_dyn_into::<Element>()
+            .and_then(|target| target.closest("a").ok().flatten())
+            .is_some();
+        if is_link {
+            on_close.emit(());
+        }
+    });
+    let on_keydown = use_callback(props.on_close.clone(), |evt: KeyboardEvent, on_close| {
+        if evt.key() == "Escape" {
+            on_close.emit(());
+        }
+    });
+
+    let classes = if props.is_drawer {
+        classes!(drawer_style, props.is_open.then_some(drawer_open_style))
+    } else {
+        classes!(menu_collection_style)
+    };
 
     html!(
-        <nav class={menu_collection_style}>
+        <nav id={props.id.clone()} class={classes} onclick={on_click} onkeydown={on_keydown}>
             {for props.children.iter()}
         </nav>
     )
 }
 
+#[cfg(feature = "with-yew-router")]
+pub enum CosmoRouteMatch<Route>
+where
+    Route: Routable + 'static,
+{
+    Exact,
+    Prefix,
+    Custom(Rc<dyn Fn(&Route) -> bool>),
+}
+
+#[cfg(feature = "with-yew-router")]
+impl<Route> CosmoRouteMatch<Route>
+where
+    Route: Routable + 'static,
+{
+    pub fn custom(predicate: impl Fn(&Route) -> bool + 'static) -> Self {
+        Self::Custom(Rc::new(predicate))
+    }
+
+    pub fn matches(&self, to: &Route, path: &str) -> bool {
+        match self {
+            CosmoRouteMatch::Exact => Route::recognize(path)
+                .map(|current| current == *to)
+                .unwrap_or(false),
+            CosmoRouteMatch::Prefix => {
+                let to_path = to.to_path();
+                let prefix = to_path.trim_end_matches('/');
+
+                if prefix.is_empty() {
+                    return path == "/";
+                }
+
+                path == to_path
+                    || path
+                        .strip_prefix(prefix)
+                        .map(|rest| rest.starts_with('/'))
+                        .unwrap_or(false)
+            }
+            CosmoRouteMatch::Custom(predicate) => Route::recognize(path)
+                .map(|current| predicate(&current))
+                .unwrap_or(false),
+        }
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+impl<Route> Clone for CosmoRouteMatch<Route>
+where
+    Route: Routable + 'static,
+{
+    fn clone(&self) -> Self {
+        match self {
+            CosmoRouteMatch::Exact => CosmoRouteMatch::Exact,
+            CosmoRouteMatch::Prefix => CosmoRouteMatch::Prefix,
+            CosmoRouteMatch::Custom(predicate) => CosmoRouteMatch::Custom(predicate.clone()),
+        }
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+impl<Route> PartialEq for CosmoRouteMatch<Route>
+where
+    Route: Routable + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CosmoRouteMatch::Exact, CosmoRouteMatch::Exact) => true,
+            (CosmoRouteMatch::Prefix, CosmoRouteMatch::Prefix) => true,
+            (CosmoRouteMatch::Custom(a), CosmoRouteMatch::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+#[hook]
+pub(crate) fn use_current_path() -> Option<String> {
+    let navigator = use_navigator();
+    let location = use_location();
+
+    navigator
+        .zip(location)
+        .map(|(navigator, location)| match navigator.basename() {
+            Some(basename) => {
+                let path = location
+                    .path()
+                    .strip_prefix(basename)
+                    .unwrap_or(location.path());
+                if path.starts_with('/') {
+                    path.to_string()
+                } else {
+                    format!("/{path}")
+                }
+            }
+            None => location.path().to_string(),
+        })
+}
+
+#[cfg(feature = "with-yew-router")]
+#[hook]
+fn use_route_active<Route>(
+    to: Route,
+    route_match: CosmoRouteMatch<Route>,
+    is_active: Option<bool>,
+) -> bool
+where
+    Route: Routable + 'static,
+{
+    let path = use_current_path();
+
+    is_active.unwrap_or_else(|| {
+        path.map(|path| route_match.matches(&to, path.as_str()))
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(feature = "with-yew-router")]
+#[derive(PartialEq, Clone)]
+pub struct CosmoMenuItem<Route>
+where
+    Route: Routable + 'static,
+{
+    pub label: AttrValue,
+    pub to: Route,
+    pub route_match: CosmoRouteMatch<Route>,
+    pub children: Vec<CosmoMenuItem<Route>>,
+}
+
+#[cfg(feature = "with-yew-router")]
+impl<Route> CosmoMenuItem<Route>
+where
+    Route: Routable + 'static,
+{
+    pub fn new(label: impl Into<AttrValue>, to: Route) -> Self {
+        Self {
+            label: label.into(),
+            to,
+            route_match: CosmoRouteMatch::Exact,
+            children: vec![],
+        }
+    }
+
+    pub fn with_route_match(mut self, route_match: CosmoRouteMatch<Route>) -> Self {
+        self.route_match = route_match;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<CosmoMenuItem<Route>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn is_active(&self, path: &str) -> bool {
+        self.route_match.matches(&self.to, path)
+            || self.children.iter().any(|child| child.is_active(path))
+    }
+
+    pub fn active_trail(items: &[CosmoMenuItem<Route>], path: &str) -> Vec<CosmoMenuItem<Route>> {
+        let mut trail = vec![];
+        let mut level = items;
+        while let Some(item) = level.iter().find(|item| item.is_active(path)) {
+            trail.push(item.clone());
+            level = item.children.as_slice();
+        }
+
+        trail
+    }
+}
+
+#[cfg(feature = "with-yew-router")]
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoMenuProps<Route>
+where
+    Route: Routable + 'static,
+{
+    pub items: Vec<CosmoMenuItem<Route>>,
+}
+
+#[cfg(feature = "with-yew-router")]
+#[function_component(CosmoMenu)]
+pub fn menu<Route>(props: &CosmoMenuProps<Route>) -> Html
+where
+    Route: Routable + 'static,
+{
+    let path = use_current_path().unwrap_or_default();
+    let trail = CosmoMenuItem::active_trail(props.items.as_slice(), path.as_str());
+    let active_main_item = trail.first();
+    let active_sub_item = trail.get(1);
+    let sub_items = active_main_item
+        .map(|item| item.children.clone())
+        .unwrap_or_default();
+
+    html!(
+        <>
+            <CosmoMainMenu>
+                {for props.items.iter().map(|item| html!(
+                    <CosmoMainMenuItemLink<Route> label={item.label.clone()} to={item.to.clone()} is_active={active_main_item == Some(item)} />
+                ))}
+            </CosmoMainMenu>
+            <CosmoSubMenuBar>
+                {for sub_items.iter().map(|item| html!(
+                    <CosmoSubMenuItemLink<Route> label={item.label.clone()} to={item.to.clone()} is_active={active_sub_item == Some(item)} />
+                ))}
+            </CosmoSubMenuBar>
+        </>
+    )
+}
+
+#[cfg(feature = "with-yew-router")]
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoMenuBreadcrumbsProps<Route>
+where
+    Route: Routable + 'static,
+{
+    pub items: Vec<CosmoMenuItem<Route>>,
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    #[prop_or(AttrValue::from("/"))]
+    pub separator: AttrValue,
+    #[prop_or_default]
+    pub max_items: Option<usize>,
+    #[prop_or_default]
+    pub show_siblings: bool,
+}
+
+#[cfg(feature = "with-yew-router")]
+#[function_component(CosmoMenuBreadcrumbs)]
+pub fn menu_breadcrumbs<Route>(props: &CosmoMenuBreadcrumbsProps<Route>) -> Html
+where
+    Route: Routable + 'static,
+{
+    let path = use_current_path().unwrap_or_default();
+    let trail = CosmoMenuItem::active_trail(props.items.as_slice(), path.as_str());
+    let levels = std::iter::once(props.items.as_slice())
+        .chain(trail.iter().map(|item| item.children.as_slice()));
+
+    html!(
+        <CosmoBreadcrumbs label={props.label.clone()} separator={props.separator.clone()} max_items={props.max_items}>
+            {for trail.iter().zip(levels).map(|(item, level)| {
+                let siblings = if props.show_siblings {
+                    level
+                        .iter()
+                        .filter(|sibling| *sibling != item)
+                        .map(|sibling| CosmoBreadcrumbSibling::new(sibling.label.clone(), sibling.to.clone()))
+                        .collect()
+                } else {
+                    vec![]
+                };
+
+                html!(
+                    <CosmoBreadcrumbItemLink<Route> label={item.label.clone()} to={item.to.clone()} siblings={siblings} />
+                )
+            })}
+        </CosmoBreadcrumbs>
+    )
+}
+
 #[cfg(feature = "with-yew-router")]
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoMainMenuItemLinkProps<Route>
@@ -100,7 +510,10 @@ where
 {
     pub label: AttrValue,
     pub to: Route,
-    pub is_active: bool,
+    #[prop_or_default]
+    pub is_active: Option<bool>,
+    #[prop_or(CosmoRouteMatch::Prefix)]
+    pub route_match: CosmoRouteMatch<Route>,
 }
 
 #[hook]
@@ -115,19 +528,16 @@ font-size: var(--font-size-main-menu);
 line-height: var(--font-size-main-menu);
 vertical-align: text-top;
 color: var(--menu-text-color);
-margin-right: calc(var(--font-size-main-menu) / 2);
+margin-inline-end: calc(var(--font-size-main-menu) / 2);
     "#
     );
-    let mut active_style = Some(use_style!(
+    let active_style = use_style!(
         r#"
 color: var(--menu-text-selected-color);
     "#
-    ));
-    if !is_active {
-        active_style = None;
-    }
+    );
 
-    classes!(item_style, active_style)
+    classes!(item_style, is_active.then_some(active_style))
 }
 
 #[cfg(feature = "with-yew-router")]
@@ -136,7 +546,8 @@ pub fn main_menu_item_link<Route>(props: &CosmoMainMenuItemLinkProps<Route>) ->
 where
     Route: Routable + 'static,
 {
-    let style = use_main_menu_item_style(props.is_active);
+    let is_active = use_route_active(props.to.clone(), props.route_match.clone(), props.is_active);
+    let style = use_main_menu_item_style(is_active);
 
     html!(
         <Link<Route> to={props.to.clone()} classes={style}>{props.label.clone()}</Link<Route>>
@@ -190,20 +601,17 @@ text-transform: uppercase;
 font-size: var(--font-size-sub-menu);
 line-height: var(--font-size-sub-menu);
 vertical-align: text-top;
-margin-right: var(--font-size-sub-menu);
+margin-inline-end: var(--font-size-sub-menu);
 color: var(--black);
     "#
     );
-    let mut active_style = Some(use_style!(
+    let active_style = use_style!(
         r#"
 font-weight: var(--font-weight-sub-menu-active);
     "#
-    ));
-    if !is_active {
-        active_style = None;
-    }
+    );
 
-    classes!(item_style, active_style)
+    classes!(item_style, is_active.then_some(active_style))
 }
 
 #[cfg(feature = "with-yew-router")]
@@ -214,7 +622,10 @@ where
 {
     pub label: AttrValue,
     pub to: Route,
-    pub is_active: bool,
+    #[prop_or_default]
+    pub is_active: Option<bool>,
+    #[prop_or(CosmoRouteMatch::Exact)]
+    pub route_match: CosmoRouteMatch<Route>,
 }
 
 #[cfg(feature = "with-yew-router")]
@@ -223,7 +634,8 @@ pub fn sub_menu_item_link<Route>(props: &CosmoSubMenuItemLinkProps<Route>) -> Ht
 where
     Route: Routable + 'static,
 {
-    let style = use_sub_menu_item_style(props.is_active);
+    let is_active = use_route_active(props.to.clone(), props.route_match.clone(), props.is_active);
+    let style = use_sub_menu_item_style(is_active);
 
     html!(
         <Link<Route> to={props.to.clone()} classes={style}>{props.label.clone()}</Link<Route>>
@@ -244,3 +656,32 @@ pub fn sub_menu_item(props: &CosmoSubMenuItemProps) -> Html {
         <span class={style}>{props.label.clone()}</span>
     )
 }
+
+#[cfg(all(test, feature = "with-yew-router"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Routable, PartialEq)]
+    enum Route {
+        #[at("/")]
+        Home,
+        #[at("/gallery")]
+        Gallery,
+        #[at("/settings")]
+        Settings,
+    }
+
+    #[test]
+    fn route_match_modes() {
+        assert!(CosmoRouteMatch::Exact.matches(&Route::Gallery, "/gallery"));
+        assert!(!CosmoRouteMatch::Exact.matches(&Route::Gallery, "/gallery/folder"));
+        assert!(CosmoRouteMatch::Prefix.matches(&Route::Gallery, "/gallery/folder"));
+        assert!(!CosmoRouteMatch::Prefix.matches(&Route::Gallery, "/galleryfolder"));
+        assert!(CosmoRouteMatch::Prefix.matches(&Route::Home, "/"));
+        assert!(!CosmoRouteMatch::Prefix.matches(&Route::Home, "/gallery"));
+        assert!(
+            CosmoRouteMatch::custom(|route: &Route| *route == Route::Settings)
+                .matches(&Route::Home, "/settings")
+        );
+    }
+}
@@ -59,6 +59,16 @@ impl CosmoMessageType {
         }
         .to_string()
     }
+
+    pub fn get_message_accent_color_foreground(&self) -> String {
+        match self {
+            CosmoMessageType::Information => "var(--information-color-foreground)",
+            CosmoMessageType::Warning => "var(--warning-color-foreground)",
+            CosmoMessageType::Positive => "var(--positive-color-foreground)",
+            CosmoMessageType::Negative => "var(--negative-color-foreground)",
+        }
+        .to_string()
+    }
 }
 
 #[derive(PartialEq, Clone, Properties)]
@@ -83,8 +93,8 @@ width: 100%;
 background: var(--message-background);
 color: var(--black);
 border-top: var(--message-border-top-width) solid var(--message-border);
-padding: var(--message-padding-top) var(--message-padding-right) var(--message-padding-bottom)
-    var(--message-padding-left);
+padding-block: var(--message-padding-top) var(--message-padding-bottom);
+padding-inline: var(--message-padding-left) var(--message-padding-right);
 margin-bottom: var(--message-margin-bottom);
 box-sizing: border-box;
 border-radius: var(--border-radius);
@@ -103,10 +113,12 @@ backdrop-filter: var(--message-backdrop-filter);
 --modal-accent-color: ${modal_accent_color};
 --modal-accent-color-light: ${modal_accent_color_light};
 --modal-accent-color-lighter: ${modal_accent_color_lighter};
+--modal-accent-color-foreground: ${modal_accent_color_foreground};
     "#,
         modal_accent_color = props.message_type.get_message_accent_color(),
         modal_accent_color_light = props.message_type.get_message_accent_color_light(),
         modal_accent_color_lighter = props.message_type.get_message_accent_color_lighter(),
+        modal_accent_color_foreground = props.message_type.get_message_accent_color_foreground(),
     );
     let header_style = use_style!(
         r#"
@@ -142,11 +154,11 @@ margin-top: var(--modal-button-bar-margin-top);
 gap: var(--button-container-gap);
 
 > .cosmo-button {
-    border-left-width: var(--button-border-width);
+    border-inline-start-width: var(--button-border-width);
 }
 
 .cosmo-button:last-of-type {
-    --button-color: var(--white);
+    --button-color: var(--modal-accent-color-foreground);
     --button-background: var(--modal-accent-color);
     --button-border-color: var(--modal-accent-color);
 }
@@ -163,10 +175,6 @@ gap: var(--button-container-gap);
 }
 
 @media screen and (prefers-color-scheme: dark) {
-    .cosmo-button:last-of-type {
-        --button-color: var(--black);
-    }
-
     .cosmo-button:last-of-type:not(:disabled):hover,
     .cosmo-button:last-of-type:not(:disabled):focus {
         --button-border-color: var(--primary-color-dark);
@@ -1,10 +1,12 @@
 use stylist::yew::{styled_component, use_style};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 use yew::virtual_dom::VNode;
-use yew_hooks::use_unmount;
 
 use crate::button::CosmoButton;
 use crate::prelude::CosmoTheme;
+use crate::theme::use_inherited_theme;
 
 #[derive(PartialEq, Clone, Default)]
 pub enum CosmoModalType {
@@ -51,18 +53,28 @@ impl CosmoModalType {
         }
         .to_string()
     }
-}
 
-impl ToString for CosmoModalType {
-    fn to_string(&self) -> String {
+    pub fn get_modal_accent_color_foreground(&self) -> String {
         match self {
+            CosmoModalType::Primary => "var(--primary-color-foreground)",
+            CosmoModalType::Information => "var(--information-color-foreground)",
+            CosmoModalType::Warning => "var(--warning-color-foreground)",
+            CosmoModalType::Positive => "var(--positive-color-foreground)",
+            CosmoModalType::Negative => "var(--negative-color-foreground)",
+        }
+        .to_string()
+    }
+}
+
+impl std::fmt::Display for CosmoModalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             CosmoModalType::Primary => "primary",
             CosmoModalType::Information => "information",
             CosmoModalType::Warning => "warning",
             CosmoModalType::Positive => "positive",
             CosmoModalType::Negative => "negative",
-        }
-        .into()
+        })
     }
 }
 
@@ -90,6 +102,72 @@ impl From<AttrValue> for CosmoModalType {
     }
 }
 
+const FOCUSABLE_SELECTOR: &str = "a[href], button:not(:disabled), input:not(:disabled):not([type=hidden]), select:not(:disabled), textarea:not(:disabled), [tabindex]:not([tabindex='-1'])";
+
+fn get_focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    container
+        .query_selector_all(FOCUSABLE_SELECTOR)
+        .map(|nodes| {
+            (0..nodes.length())
+                .filter_map(|idx| nodes.item(idx))
+                .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[hook]
+fn use_focus_trap(dialog_ref: NodeRef, is_mounted: bool) {
+    use_effect_with((dialog_ref, is_mounted), |(dialog_ref, _)| {
+        let previous_focus = gloo_utils::document()
+            .active_element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+        if let Some(dialog) = dialog_ref.cast::<HtmlElement>() {
+            let focus_target = get_focusable_elements(&dialog)
+                .into_iter()
+                .next()
+                .unwrap_or(dialog);
+            let _ = focus_target.focus();
+        }
+
+        move || {
+            if let Some(previous_focus) = previous_focus {
+                if previous_focus.is_connected() {
+                    let _ = previous_focus.focus();
+                }
+            }
+        }
+    });
+}
+
+#[hook]
+pub(crate) fn use_portal_host(id: String) -> Option<Element> {
+    let host_state = use_state_eq(|| None::<Element>);
+    {
+        let host_state = host_state.clone();
+        use_effect_with(id, move |id| {
+            let host = if let Some(host) = gloo_utils::document().get_element_by_id(id.as_str()) {
+                host
+            } else {
+                let host = gloo_utils::document()
+                    .create_element("div")
+                    .expect("Failed to create div");
+                host.set_id(id.as_str());
+                gloo_utils::body()
+                    .append_child(&host)
+                    .expect("Failed to append child");
+                host
+            };
+            host_state.set(Some(host.clone()));
+
+            move || host.remove()
+        });
+    }
+
+    (*host_state).clone()
+}
+
 #[derive(Properties, PartialEq, Clone)]
 pub struct CosmoModalProps {
     #[prop_or_default]
@@ -106,11 +184,17 @@ pub struct CosmoModalProps {
     pub classes: Classes,
     #[prop_or_default]
     pub modal_type: CosmoModalType,
+    #[prop_or_default]
+    pub on_dismiss: Option<Callback<()>>,
 }
 
 #[styled_component(CosmoModal)]
 pub fn modal(props: &CosmoModalProps) -> Html {
     let modal_id = use_state_eq(|| uuid::Uuid::new_v4().to_string());
+    let dialog_ref = use_node_ref();
+
+    let modal_host = use_portal_host((*modal_id).clone());
+    use_focus_trap(dialog_ref.clone(), modal_host.is_some());
 
     let modal_container_style = use_style!(
         r#"
@@ -129,12 +213,14 @@ align-items: center;
 z-index: 9999;
 border: none;
 color: var(--black);
+outline: none;
     "#
     );
     let modal_style = use_style!(
         r#"
 border: var(--modal-border-width) solid var(--modal-accent-color);
-padding: var(--modal-padding-top) var(--modal-padding-right) var(--modal-padding-bottom) var(--modal-padding-left);
+padding-block: var(--modal-padding-top) var(--modal-padding-bottom);
+padding-inline: var(--modal-padding-left) var(--modal-padding-right);
 min-width: var(--modal-min-width);
 box-sizing: border-box;
 border-radius: var(--border-radius);
@@ -156,15 +242,18 @@ background: var(--modal-background);
 --modal-accent-color: ${modal_accent_color};
 --modal-accent-color-light: ${modal_accent_color_light};
 --modal-accent-color-lighter: ${modal_accent_color_lighter};
+--modal-accent-color-foreground: ${modal_accent_color_foreground};
     "#,
         modal_accent_color = props.modal_type.get_modal_accent_color(),
         modal_accent_color_light = props.modal_type.get_modal_accent_color_light(),
         modal_accent_color_lighter = props.modal_type.get_modal_accent_color_lighter(),
+        modal_accent_color_foreground = props.modal_type.get_modal_accent_color_foreground(),
     );
     let modal_title_style = use_style!(
         r#"
 padding: 0;
-margin: var(--modal-title-margin-top) var(--modal-title-margin-right) var(--modal-title-margin-bottom) var(--modal-title-margin-left);
+margin-block: var(--modal-title-margin-top) var(--modal-title-margin-bottom);
+margin-inline: var(--modal-title-margin-left) var(--modal-title-margin-right);
 text-transform: uppercase;
 font-size: var(--modal-title-font-size);
 line-height: var(--modal-title-font-size);
@@ -191,11 +280,11 @@ margin-top: var(--modal-button-bar-margin-top);
 gap: var(--button-container-gap);
 
 > .cosmo-button {
-    border-left-width: var(--button-border-width);
+    border-inline-start-width: var(--button-border-width);
 }
 
 .cosmo-button:last-of-type {
-    --button-color: var(--white);
+    --button-color: var(--modal-accent-color-foreground);
     --button-background: var(--modal-accent-color);
     --button-border-color: var(--modal-accent-color);
 }
@@ -212,10 +301,6 @@ gap: var(--button-container-gap);
 }
 
 @media screen and (prefers-color-scheme: dark) {
-    .cosmo-button:last-of-type {
-        --button-color: var(--black);
-    }
-
     .cosmo-button:last-of-type:not(:disabled):hover,
     .cosmo-button:last-of-type:not(:disabled):focus {
         --button-border-color: var(--primary-color-dark);
@@ -236,33 +321,68 @@ gap: var(--button-container-gap);
             on_submit.emit(());
         })
     });
+    let on_keydown = use_callback(
+        (dialog_ref.clone(), props.on_dismiss.clone()),
+        |evt: KeyboardEvent, (dialog_ref, on_dismiss)| match evt.key().as_str() {
+            "Escape" => {
+                if let Some(on_dismiss) = on_dismiss {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                    on_dismiss.emit(());
+                }
+            }
+            "Tab" => {
+                let Some(dialog) = dialog_ref.cast::<HtmlElement>() else {
+                    return;
+                };
+                let focusable_elements = get_focusable_elements(&dialog);
+                let (Some(first), Some(last)) =
+                    (focusable_elements.first(), focusable_elements.last())
+                else {
+                    evt.prevent_default();
+                    return;
+                };
+
+                let active_element = gloo_utils::document().active_element();
+                let is_active = |element: &HtmlElement| {
+                    active_element
+                        .as_ref()
+                        .map(|active| active == element.unchecked_ref::<Element>())
+                        .unwrap_or(false)
+                };
+                let focus_inside = active_element
+                    .as_ref()
+                    .map(|active| dialog.contains(Some(active)))
+                    .unwrap_or(false);
+
+                if evt.shift_key() && (is_active(first) || !focus_inside) {
+                    evt.prevent_default();
+                    let _ = last.focus();
+                } else if !evt.shift_key() && (is_active(last) || !focus_inside) {
+                    evt.prevent_default();
+                    let _ = first.focus();
+                }
+            }
+            _ => {}
+        },
+    );
+
     let tag = if props.is_form { "form" } else { "div" };
+    let title_id = format!("{}-title", *modal_id);
+    let content_id = format!("{}-content", *modal_id);
 
-    let modal_host = if let Some(modal_host) =
-        gloo_utils::document().get_element_by_id((*modal_id).clone().as_str())
-    {
-        modal_host
-    } else {
-        let modal_host = gloo_utils::document()
-            .create_element("div")
-            .expect("Failed to create div");
-        modal_host.set_id((*modal_id).clone().as_str());
-        gloo_utils::body()
-            .append_child(&modal_host)
-            .expect("Failed to append child");
-        modal_host
+    let theme = use_inherited_theme(props.theme.clone());
+
+    let Some(modal_host) = modal_host else {
+        return html!();
     };
-    {
-        let modal_host = modal_host.clone();
-        use_unmount(move || modal_host.remove());
-    }
 
     create_portal(
         html!(
-            <dialog class={classes!(modal_container_style, accent_style, props.theme.clone(), props.classes.clone())} open={true}>
+            <dialog ref={dialog_ref} class={classes!(modal_container_style, accent_style, theme, props.classes.clone())} open={true} tabindex="-1" aria-modal="true" aria-labelledby={title_id.clone()} aria-describedby={content_id.clone()} onkeydown={on_keydown}>
                 <@{tag} class={modal_style} onsubmit={on_submit}>
-                    <h1 class={modal_title_style}>{props.title.clone()}</h1>
-                    <div class={modal_content_style}>
+                    <h1 id={title_id} class={modal_title_style}>{props.title.clone()}</h1>
+                    <div id={content_id} class={modal_content_style}>
                         {for props.children.iter()}
                     </div>
                     <div class={modal_button_bar_style}>
@@ -285,6 +405,8 @@ pub struct CosmoAlertProps {
     pub theme: CosmoTheme,
     #[prop_or_default]
     pub alert_type: CosmoModalType,
+    #[prop_or_default]
+    pub on_dismiss: Option<Callback<()>>,
 }
 
 #[styled_component(CosmoAlert)]
@@ -298,7 +420,7 @@ white-space: pre-wrap;
     );
 
     html!(
-        <CosmoModal modal_type={props.alert_type.clone()} theme={props.theme.clone()} title={props.title.clone()} buttons={html!(<CosmoButton on_click={on_close} label={props.close_label.clone()} />)}>
+        <CosmoModal modal_type={props.alert_type.clone()} theme={props.theme.clone()} title={props.title.clone()} on_dismiss={props.on_dismiss.clone()} buttons={html!(<CosmoButton on_click={on_close} label={props.close_label.clone()} />)}>
             <div class={message_style}>{props.message.clone()}</div>
         </CosmoModal>
     )
@@ -316,6 +438,8 @@ pub struct CosmoConfirmProps {
     pub theme: CosmoTheme,
     #[prop_or_default]
     pub confirm_type: CosmoModalType,
+    #[prop_or_default]
+    pub on_dismiss: Option<Callback<()>>,
 }
 
 #[styled_component(CosmoConfirm)]
@@ -330,7 +454,7 @@ white-space: pre-wrap;
     );
 
     html!(
-        <CosmoModal modal_type={props.confirm_type.clone()} theme={props.theme.clone()} title={props.title.clone()} buttons={html!(
+        <CosmoModal modal_type={props.confirm_type.clone()} theme={props.theme.clone()} title={props.title.clone()} on_dismiss={props.on_dismiss.clone()} buttons={html!(
             <>
                 <CosmoButton on_click={on_decline} label={props.decline_label.clone()} />
                 <CosmoButton on_click={on_confirm} label={props.confirm_label.clone()} />
@@ -0,0 +1,119 @@
+#[cfg(feature = "ssr")]
+use std::cell::Cell;
+#[cfg(feature = "ssr")]
+use std::rc::Rc;
+
+#[cfg(feature = "ssr")]
+use bounce::helmet::StaticWriter;
+use stylist::manager::StyleManager;
+use stylist::yew::ManagerProvider;
+use yew::prelude::*;
+
+#[cfg(feature = "ssr")]
+#[derive(PartialEq, Clone)]
+pub struct CosmoHelmetWriter {
+    writer: StaticWriter,
+    is_bridged: Rc<Cell<bool>>,
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub(crate) struct CosmoSsrContext {
+    #[cfg(feature = "ssr")]
+    helmet_writer: Option<CosmoHelmetWriter>,
+}
+
+#[cfg(feature = "ssr")]
+impl CosmoSsrContext {
+    pub(crate) fn take_helmet_writer(&self) -> Option<StaticWriter> {
+        self.helmet_writer.as_ref().map(|helmet_writer| {
+            helmet_writer.is_bridged.set(true);
+            helmet_writer.writer.clone()
+        })
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoAppRootProps {
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or_default]
+    pub style_manager: Option<StyleManager>,
+    #[cfg(feature = "ssr")]
+    #[prop_or_default]
+    pub helmet_writer: Option<CosmoHelmetWriter>,
+}
+
+#[function_component(CosmoAppRoot)]
+pub fn app_root(props: &CosmoAppRootProps) -> Html {
+    let style_manager = props.style_manager.clone().unwrap_or_default();
+    let context = CosmoSsrContext {
+        #[cfg(feature = "ssr")]
+        helmet_writer: props.helmet_writer.clone(),
+    };
+
+    html!(
+        <ManagerProvider manager={style_manager}>
+            <ContextProvider<CosmoSsrContext> context={context}>
+                {props.children.clone()}
+            </ContextProvider<CosmoSsrContext>>
+        </ManagerProvider>
+    )
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CosmoRenderedPage {
+    pub head: String,
+    pub body: String,
+}
+
+#[cfg(feature = "ssr")]
+pub async fn render_cosmo_app<COMP>(props: COMP::Properties) -> CosmoRenderedPage
+where
+    COMP: BaseComponent,
+{
+    let (style_writer, style_reader) = stylist::manager::render_static();
+    let style_manager = StyleManager::builder()
+        .writer(style_writer)
+        .build()
+        .expect("Failed to create style manager");
+    let (helmet_renderer, writer) = bounce::helmet::render_static();
+    let is_bridged = Rc::new(Cell::new(false));
+
+    let body = yew::LocalServerRenderer::<CosmoAppRoot>::with_props(CosmoAppRootProps {
+        children: html!(<COMP ..props />),
+        style_manager: Some(style_manager),
+        helmet_writer: Some(CosmoHelmetWriter {
+            writer,
+            is_bridged: is_bridged.clone(),
+        }),
+    })
+    .render()
+    .await;
+
+    let mut head = String::new();
+    if is_bridged.get() {
+        for tag in helmet_renderer.render().await {
+            let _ = tag.write_static(&mut head);
+        }
+    }
+    let _ = style_reader
+        .read_style_data()
+        .write_static_markup(&mut head);
+
+    CosmoRenderedPage { head, body }
+}
+
+#[cfg(feature = "hydration")]
+pub fn hydrate_cosmo_app<COMP>(props: COMP::Properties) -> yew::AppHandle<CosmoAppRoot>
+where
+    COMP: BaseComponent,
+{
+    yew::Renderer::<CosmoAppRoot>::with_props(CosmoAppRootProps {
+        children: html!(<COMP ..props />),
+        style_manager: None,
+        #[cfg(feature = "ssr")]
+        helmet_writer: None,
+    })
+    .hydrate()
+}
@@ -1,18 +1,19 @@
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
 use stylist::yew::{styled_component, use_style};
+use stylist::Style;
+use web_sys::HtmlInputElement;
 use yew::html::ChildrenRenderer;
 use yew::prelude::*;
-use yew::virtual_dom::{Key, VChild};
+use yew::virtual_dom::{Key, VChild, VNode};
 
-#[derive(PartialEq, Clone, Properties)]
-pub struct CosmoTableProps {
-    pub headers: Vec<AttrValue>,
-    #[prop_or_default]
-    pub children: ChildrenWithProps<CosmoTableRow>,
-}
+use crate::prelude::*;
 
-#[styled_component(CosmoTable)]
-pub fn table(props: &CosmoTableProps) -> Html {
-    let table_style = use_style!(
+#[hook]
+fn use_table_style() -> Style {
+    use_style!(
         r#"
 border-collapse: collapse;
 
@@ -34,17 +35,234 @@ tr:nth-child(2n-1) td {
     background: var(--table-stripe-color);
 }
     "#
+    )
+}
+
+#[hook]
+fn use_table_selection_style() -> Style {
+    use_style!(
+        r#"
+width: 0;
+
+input {
+    accent-color: var(--primary-color);
+    cursor: pointer;
+    margin: 0;
+    vertical-align: middle;
+}
+    "#
+    )
+}
+
+#[derive(PartialEq, Clone)]
+struct CosmoTableSelection {
+    selected: Vec<AttrValue>,
+    all_selected: bool,
+    some_selected: bool,
+    on_toggle: Callback<(usize, bool)>,
+    on_toggle_all: Callback<()>,
+}
+
+impl CosmoTableSelection {
+    fn is_selected(&self, key: &AttrValue) -> bool {
+        self.selected.contains(key)
+    }
+}
+
+fn selection_range(
+    keys: &[AttrValue],
+    anchor: Option<&AttrValue>,
+    idx: usize,
+) -> RangeInclusive<usize> {
+    match anchor.and_then(|anchor| keys.iter().position(|key| key == anchor)) {
+        Some(anchor) => anchor.min(idx)..=anchor.max(idx),
+        None => idx..=idx,
+    }
+}
+
+#[hook]
+fn use_table_selection(
+    keys: Vec<AttrValue>,
+    selected_keys: Option<Vec<AttrValue>>,
+    on_selection_change: Option<Callback<Vec<AttrValue>>>,
+) -> CosmoTableSelection {
+    let selected_state = use_state_eq(Vec::<AttrValue>::new);
+    let anchor_state = use_state_eq(|| None::<AttrValue>);
+
+    let selected = selected_keys.unwrap_or_else(|| (*selected_state).clone());
+    let selected_count = keys.iter().filter(|key| selected.contains(key)).count();
+
+    let on_toggle = use_callback(
+        (
+            keys.clone(),
+            selected.clone(),
+            selected_state.clone(),
+            anchor_state.clone(),
+            on_selection_change.clone(),
+        ),
+        |(idx, is_range): (usize, bool),
+         (keys, selected, selected_state, anchor_state, on_selection_change)| {
+            let Some(key) = keys.get(idx) else {
+                return;
+            };
+            let select = !selected.contains(key);
+            let anchor = (*anchor_state).as_ref().filter(|_| is_range);
+            let range = selection_range(keys, anchor, idx);
+
+            let mut selected = selected.clone();
+            for key in &keys[range] {
+                if select && !selected.contains(key) {
+                    selected.push(key.clone());
+                } else if !select {
+                    selected.retain(|selected_key| selected_key != key);
+                }
+            }
+
+            anchor_state.set(Some(key.clone()));
+            selected_state.set(selected.clone());
+            if let Some(on_selection_change) = on_selection_change {
+                on_selection_change.emit(selected);
+            }
+        },
+    );
+    let on_toggle_all = use_callback(
+        (
+            keys.clone(),
+            selected.clone(),
+            selected_state.clone(),
+            anchor_state.clone(),
+            on_selection_change,
+        ),
+        |_, (keys, selected, selected_state, anchor_state, on_selection_change)| {
+            let mut selected = selected.clone();
+            if keys.iter().all(|key| selected.contains(key)) {
+                selected.retain(|key| !keys.contains(key));
+            } else {
+                for key in keys {
+                    if !selected.contains(key) {
+                        selected.push(key.clone());
+                    }
+                }
+            }
+
+            anchor_state.set(None);
+            selected_state.set(selected.clone());
+            if let Some(on_selection_change) = on_selection_change {
+                on_selection_change.emit(selected);
+            }
+        },
+    );
+
+    CosmoTableSelection {
+        selected,
+        all_selected: !keys.is_empty() && selected_count == keys.len(),
+        some_selected: selected_count > 0 && selected_count < keys.len(),
+        on_toggle,
+        on_toggle_all,
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoTableSelectAllProps {
+    checked: bool,
+    indeterminate: bool,
+    label: AttrValue,
+    on_toggle: Callback<()>,
+}
+
+#[styled_component(CosmoTableSelectAll)]
+fn table_select_all(props: &CosmoTableSelectAllProps) -> Html {
+    let checkbox_ref = use_node_ref();
+    let selection_style = use_table_selection_style();
+
+    {
+        let checkbox_ref = checkbox_ref.clone();
+        use_effect_with(props.indeterminate, move |indeterminate| {
+            if let Some(checkbox) = checkbox_ref.cast::<HtmlInputElement>() {
+                checkbox.set_indeterminate(*indeterminate);
+            }
+        });
+    }
+
+    let on_click = use_callback(props.on_toggle.clone(), |_: MouseEvent, on_toggle| {
+        on_toggle.emit(())
+    });
+
+    html!(
+        <th class={selection_style}>
+            <input ref={checkbox_ref} type="checkbox" aria-label={props.label.clone()} checked={props.checked} onclick={on_click} />
+        </th>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoTableProps {
+    pub headers: Vec<AttrValue>,
+    #[prop_or_default]
+    pub children: ChildrenWithProps<CosmoTableRow>,
+    #[prop_or(false)]
+    pub is_selectable: bool,
+    #[prop_or_default]
+    pub selected_keys: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub on_selection_change: Option<Callback<Vec<AttrValue>>>,
+    #[prop_or_default]
+    pub select_all_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub select_row_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoTable)]
+pub fn table(props: &CosmoTableProps) -> Html {
+    let table_style = use_table_style();
+    let locale = use_cosmo_locale();
+    let select_all_label = props
+        .select_all_label
+        .clone()
+        .unwrap_or(locale.labels.select_all_rows.clone());
+
+    let keys = props
+        .children
+        .iter()
+        .enumerate()
+        .map(|(idx, child)| {
+            child
+                .props
+                .row_key
+                .clone()
+                .unwrap_or_else(|| AttrValue::from(idx.to_string()))
+        })
+        .collect::<Vec<AttrValue>>();
+    let selection = use_table_selection(
+        keys.clone(),
+        props.selected_keys.clone(),
+        props.on_selection_change.clone(),
     );
 
     html!(
         <table class={table_style}>
             <thead>
                 <tr>
+                    if props.is_selectable {
+                        <CosmoTableSelectAll checked={selection.all_selected} indeterminate={selection.some_selected} label={select_all_label.clone()} on_toggle={selection.on_toggle_all.clone()} />
+                    }
                     {for props.headers.iter().map(|header| html!(<th>{header}</th>))}
                 </tr>
             </thead>
             <tbody>
-                {for props.children.iter()}
+                {for props.children.iter().zip(keys.iter()).enumerate().map(|(idx, (child, key))| {
+                    if props.is_selectable {
+                        let on_toggle = selection.on_toggle.clone();
+                        let mut row_props = (*child.props).clone();
+                        row_props.is_selected = selection.is_selected(key);
+                        row_props.select_label = row_props.select_label.or(props.select_row_label.clone());
+                        row_props.on_toggle_selection = Some(Callback::from(move |is_range| on_toggle.emit((idx, is_range))));
+
+                        CosmoTableRow::new(row_props, Some(Key::from(key.to_string()))).into()
+                    } else {
+                        html!({child})
+                    }
+                })}
             </tbody>
         </table>
     )
@@ -54,12 +272,65 @@ tr:nth-child(2n-1) td {
 pub struct CosmoTableRowProps {
     #[prop_or_default]
     pub children: ChildrenWithProps<CosmoTableCell>,
+    #[prop_or_default]
+    pub row_key: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_click: Option<Callback<()>>,
+    #[prop_or(false)]
+    pub is_selected: bool,
+    #[prop_or_default]
+    pub on_toggle_selection: Option<Callback<bool>>,
+    #[prop_or_default]
+    pub select_label: Option<AttrValue>,
 }
 
-#[function_component(CosmoTableRow)]
+#[styled_component(CosmoTableRow)]
 pub fn table_row(props: &CosmoTableRowProps) -> Html {
+    let selection_style = use_table_selection_style();
+    let locale = use_cosmo_locale();
+    let select_label = props
+        .select_label
+        .clone()
+        .unwrap_or(locale.labels.select_row.clone());
+    let clickable_style = use_style!(
+        r#"
+cursor: pointer;
+    "#
+    );
+    let selected_style = use_style!(
+        r#"
+td {
+    background: var(--primary-color-alpha-25) !important;
+}
+    "#
+    );
+
+    let on_click = props
+        .on_click
+        .clone()
+        .map(|on_click| Callback::from(move |_: MouseEvent| on_click.emit(())));
+    let on_toggle_selection = props.on_toggle_selection.clone().map(|on_toggle| {
+        Callback::from(move |evt: MouseEvent| {
+            evt.stop_propagation();
+            on_toggle.emit(evt.shift_key());
+        })
+    });
+
+    let mut row_classes = classes!();
+    if props.on_click.is_some() {
+        row_classes.push(clickable_style);
+    }
+    if props.is_selected {
+        row_classes.push(selected_style);
+    }
+
     html!(
-        <tr>
+        <tr class={row_classes} onclick={on_click} aria-selected={props.on_toggle_selection.as_ref().map(|_| props.is_selected.to_string())}>
+            if let Some(on_toggle_selection) = on_toggle_selection {
+                <td class={selection_style}>
+                    <input type="checkbox" aria-label={select_label} checked={props.is_selected} onclick={on_toggle_selection} />
+                </td>
+            }
             {for props.children.iter()}
         </tr>
     )
@@ -74,6 +345,11 @@ impl CosmoTableRow {
         VChild::new(
             CosmoTableRowProps {
                 children: ChildrenRenderer::new(cells),
+                row_key: key.as_ref().map(|key| AttrValue::from(key.to_string())),
+                on_click: None,
+                is_selected: false,
+                on_toggle_selection: None,
+                select_label: None,
             },
             key,
         )
@@ -109,3 +385,384 @@ impl CosmoTableCell {
         )
     }
 }
+
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum CosmoDataGridSortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl CosmoDataGridSortDirection {
+    fn toggle(&self) -> Self {
+        match self {
+            CosmoDataGridSortDirection::Ascending => CosmoDataGridSortDirection::Descending,
+            CosmoDataGridSortDirection::Descending => CosmoDataGridSortDirection::Ascending,
+        }
+    }
+}
+
+impl std::fmt::Display for CosmoDataGridSortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CosmoDataGridSortDirection::Ascending => "ascending",
+            CosmoDataGridSortDirection::Descending => "descending",
+        })
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct CosmoDataGridColumn<T> {
+    pub header: AttrValue,
+    render: Rc<dyn Fn(&T) -> Html>,
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+}
+
+impl<T> CosmoDataGridColumn<T> {
+    pub fn new(header: impl Into<AttrValue>, render: impl Fn(&T) -> Html + 'static) -> Self {
+        Self {
+            header: header.into(),
+            render: Rc::new(render),
+            compare: None,
+        }
+    }
+
+    pub fn with_sort_key<K>(mut self, sort_key: impl Fn(&T) -> K + 'static) -> Self
+    where
+        K: Ord,
+    {
+        self.compare = Some(Rc::new(move |a, b| sort_key(a).cmp(&sort_key(b))));
+        self
+    }
+
+    pub fn with_compare(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+}
+
+impl<T> PartialEq for CosmoDataGridColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && Rc::ptr_eq(&self.render, &other.render)
+            && match (&self.compare, &other.compare) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoDataGridProps<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    pub columns: Vec<CosmoDataGridColumn<T>>,
+    pub rows: Vec<T>,
+    #[prop_or(true)]
+    pub is_paginated: bool,
+    #[prop_or(10)]
+    pub page_size: usize,
+    #[prop_or(vec![10, 25, 50, 100])]
+    pub page_sizes: Vec<usize>,
+    #[prop_or_default]
+    pub page_size_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub previous_page_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub next_page_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub no_data_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub no_data: Option<VNode>,
+    #[prop_or_default]
+    pub row_key: Option<Callback<T, AttrValue>>,
+    #[prop_or_default]
+    pub on_row_click: Option<Callback<T>>,
+    #[prop_or(false)]
+    pub is_selectable: bool,
+    #[prop_or_default]
+    pub selected_keys: Option<Vec<AttrValue>>,
+    #[prop_or_default]
+    pub on_selection_change: Option<Callback<Vec<AttrValue>>>,
+    #[prop_or_default]
+    pub select_all_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub select_row_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoDataGrid)]
+pub fn data_grid<T>(props: &CosmoDataGridProps<T>) -> Html
+where
+    T: PartialEq + Clone + 'static,
+{
+    let sort_state = use_state_eq(|| None::<(usize, CosmoDataGridSortDirection)>);
+    let page_state = use_state_eq(|| 0usize);
+    let page_size_state = use_state_eq(|| props.page_size.max(1));
+    let is_mounted = use_mut_ref(|| false);
+    {
+        let page_size_state = page_size_state.clone();
+        let page_state = page_state.clone();
+        use_effect_with(props.page_size, move |page_size| {
+            if *is_mounted.borrow() {
+                page_size_state.set((*page_size).max(1));
+                page_state.set(0);
+            } else {
+                *is_mounted.borrow_mut() = true;
+            }
+        });
+    }
+
+    let table_style = use_table_style();
+    let locale = use_cosmo_locale();
+    let select_all_label = props
+        .select_all_label
+        .clone()
+        .unwrap_or(locale.labels.select_all_rows.clone());
+    let labels = &locale.labels;
+    let page_size_label = props
+        .page_size_label
+        .clone()
+        .unwrap_or(labels.rows_per_page.clone());
+    let previous_page_label = props
+        .previous_page_label
+        .clone()
+        .unwrap_or(labels.previous.clone());
+    let next_page_label = props.next_page_label.clone().unwrap_or(labels.next.clone());
+    let no_data_label = props
+        .no_data_label
+        .clone()
+        .unwrap_or(labels.no_data.clone());
+    let sortable_header_style = use_style!(
+        r#"
+cursor: pointer;
+user-select: none;
+white-space: nowrap;
+
+&::after {
+    display: inline-block;
+    content: '';
+    width: 1em;
+    margin-inline-start: 0.25rem;
+    font-size: 0.75em;
+}
+
+&[aria-sort="ascending"]::after {
+    content: '▲';
+}
+
+&[aria-sort="descending"]::after {
+    content: '▼';
+}
+    "#
+    );
+    let no_data_style = use_style!(
+        r#"
+text-align: center;
+color: var(--menu-text-color);
+    "#
+    );
+    let pagination_style = use_style!(
+        r#"
+display: flex;
+justify-content: space-between;
+align-items: center;
+gap: var(--toolbar-gap);
+margin-top: var(--button-container-margin-top);
+    "#
+    );
+    let page_size_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+gap: var(--input-group-gap);
+    "#
+    );
+    let page_indicator_style = use_style!(
+        r#"
+white-space: nowrap;
+font-variant-numeric: tabular-nums;
+    "#
+    );
+
+    let mut rows = props
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| {
+            let key = if let Some(row_key) = props.row_key.clone() {
+                row_key.emit(row.clone())
+            } else {
+                AttrValue::from(idx.to_string())
+            };
+
+            (key, row)
+        })
+        .collect::<Vec<(AttrValue, &T)>>();
+    if let Some((column, direction)) = *sort_state {
+        if let Some(compare) = props
+            .columns
+            .get(column)
+            .and_then(|column| column.compare.clone())
+        {
+            rows.sort_by(|(_, a), (_, b)| match direction {
+                CosmoDataGridSortDirection::Ascending => compare(a, b),
+                CosmoDataGridSortDirection::Descending => compare(b, a),
+            });
+        }
+    }
+
+    let page_size = if props.is_paginated {
+        *page_size_state
+    } else {
+        rows.len().max(1)
+    };
+    let page_count = rows.len().div_ceil(page_size).max(1);
+    let mut page_sizes = props.page_sizes.clone();
+    if !page_sizes.contains(&*page_size_state) {
+        page_sizes.push(*page_size_state);
+        page_sizes.sort_unstable();
+    }
+    let page = (*page_state).min(page_count - 1);
+
+    let on_sort = use_callback(
+        (sort_state.clone(), page_state.clone()),
+        |column: usize, (sort_state, page_state)| {
+            let direction = match **sort_state {
+                Some((sorted_column, direction)) if sorted_column == column => direction.toggle(),
+                _ => CosmoDataGridSortDirection::Ascending,
+            };
+            sort_state.set(Some((column, direction)));
+            page_state.set(0);
+        },
+    );
+    let on_previous_page = use_callback((page_state.clone(), page), |_, (page_state, page)| {
+        page_state.set(page.saturating_sub(1))
+    });
+    let on_next_page = use_callback(
+        (page_state.clone(), page, page_count),
+        |_, (page_state, page, page_count)| page_state.set((*page + 1).min(*page_count - 1)),
+    );
+    let on_page_size_select = use_callback(
+        (page_size_state.clone(), page_state.clone()),
+        |value: Option<AttrValue>, (page_size_state, page_state)| {
+            if let Some(page_size) = value.and_then(|value| value.parse::<usize>().ok()) {
+                page_size_state.set(page_size.max(1));
+                page_state.set(0);
+            }
+        },
+    );
+
+    let selection = use_table_selection(
+        rows.iter().map(|(key, _)| key.clone()).collect(),
+        props.selected_keys.clone(),
+        props.on_selection_change.clone(),
+    );
+
+    let column_count = (props.columns.len() + usize::from(props.is_selectable))
+        .max(1)
+        .to_string();
+
+    html!(
+        <div>
+            <table class={table_style}>
+                <thead>
+                    <tr>
+                        if props.is_selectable {
+                            <CosmoTableSelectAll checked={selection.all_selected} indeterminate={selection.some_selected} label={select_all_label.clone()} on_toggle={selection.on_toggle_all.clone()} />
+                        }
+                        {for props.columns.iter().enumerate().map(|(idx, column)| {
+                            if column.is_sortable() {
+                                let on_sort = on_sort.clone();
+                                let aria_sort = match *sort_state {
+                                    Some((sorted_column, direction)) if sorted_column == idx => direction.to_string(),
+                                    _ => "none".to_string(),
+                                };
+
+                                html!(
+                                    <th class={sortable_header_style.clone()} aria-sort={aria_sort} onclick={move |_| on_sort.emit(idx)}>{column.header.clone()}</th>
+                                )
+                            } else {
+                                html!(<th>{column.header.clone()}</th>)
+                            }
+                        })}
+                    </tr>
+                </thead>
+                <tbody>
+                    if rows.is_empty() {
+                        <tr>
+                            <td class={no_data_style} colspan={column_count}>
+                                if let Some(no_data) = props.no_data.clone() {
+                                    {no_data}
+                                } else {
+                                    {no_data_label}
+                                }
+                            </td>
+                        </tr>
+                    } else {
+                        {for rows.iter().enumerate().skip(page * page_size).take(page_size).map(|(idx, (key, row))| {
+                            let on_toggle = selection.on_toggle.clone();
+                            let on_toggle_selection = if props.is_selectable {
+                                Some(Callback::from(move |is_range| on_toggle.emit((idx, is_range))))
+                            } else {
+                                None
+                            };
+                            let on_click = props.on_row_click.clone().map(|on_row_click| {
+                                let row = (*row).clone();
+                                Callback::from(move |_| on_row_click.emit(row.clone()))
+                            });
+
+                            html!(
+                                <CosmoTableRow key={key.to_string()} row_key={key.clone()} is_selected={selection.is_selected(key)} on_toggle_selection={on_toggle_selection} select_label={props.select_row_label.clone()} on_click={on_click}>
+                                    {for props.columns.iter().map(|column| html_nested!(<CosmoTableCell>{(column.render)(row)}</CosmoTableCell>))}
+                                </CosmoTableRow>
+                            )
+                        })}
+                    }
+                </tbody>
+            </table>
+            if props.is_paginated {
+                <div class={pagination_style}>
+                    <div class={page_size_style.clone()}>
+                        <CosmoDropdown label={page_size_label} width={CosmoInputWidth::Auto} on_select={on_page_size_select} value={AttrValue::from(page_size.to_string())} items={page_sizes.iter().map(|size| (Some(AttrValue::from(size.to_string())), AttrValue::from(size.to_string()))).collect::<Vec<(Option<AttrValue>, AttrValue)>>()} />
+                    </div>
+                    <div class={page_size_style}>
+                        <CosmoButton label={previous_page_label} enabled={page > 0} on_click={on_previous_page} />
+                        <span class={page_indicator_style} aria-live="polite">{format!("{} / {}", page + 1, page_count)}</span>
+                        <CosmoButton label={next_page_label} enabled={page + 1 < page_count} on_click={on_next_page} />
+                    </div>
+                </div>
+            }
+        </div>
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_range_follows_the_anchor_key() {
+        let keys = ["c", "a", "b", "d"].map(AttrValue::from);
+
+        assert_eq!(
+            selection_range(&keys, Some(&AttrValue::from("a")), 3),
+            1..=3
+        );
+        assert_eq!(
+            selection_range(&keys, Some(&AttrValue::from("d")), 0),
+            0..=3
+        );
+        assert_eq!(
+            selection_range(&keys, Some(&AttrValue::from("x")), 2),
+            2..=2
+        );
+        assert_eq!(selection_range(&keys, None, 2), 2..=2);
+    }
+}
@@ -0,0 +1,156 @@
+use bounce::helmet::Helmet;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::Storage;
+use yew::prelude::*;
+
+use crate::prelude::CosmoTheme;
+
+fn local_storage() -> Option<Storage> {
+    gloo_utils::window().local_storage().ok().flatten()
+}
+
+fn theme_from_storage(value: &str) -> Option<CosmoTheme> {
+    match value {
+        "auto" => Some(CosmoTheme::Auto),
+        "light" => Some(CosmoTheme::Light),
+        "dark" => Some(CosmoTheme::Dark),
+        _ => None,
+    }
+}
+
+fn theme_to_storage(theme: &CosmoTheme) -> &'static str {
+    match theme {
+        CosmoTheme::Auto => "auto",
+        CosmoTheme::Light => "light",
+        CosmoTheme::Dark => "dark",
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoThemeHandle {
+    theme: CosmoTheme,
+    prefers_dark: bool,
+    set_theme: Callback<CosmoTheme>,
+}
+
+impl CosmoThemeHandle {
+    pub fn theme(&self) -> CosmoTheme {
+        self.theme.clone()
+    }
+
+    pub fn resolved_theme(&self) -> CosmoTheme {
+        match self.theme {
+            CosmoTheme::Auto if self.prefers_dark => CosmoTheme::Dark,
+            CosmoTheme::Auto => CosmoTheme::Light,
+            ref theme => theme.clone(),
+        }
+    }
+
+    pub fn is_dark(&self) -> bool {
+        self.resolved_theme() == CosmoTheme::Dark
+    }
+
+    pub fn set_theme(&self, theme: CosmoTheme) {
+        self.set_theme.emit(theme);
+    }
+}
+
+#[hook]
+pub fn use_cosmo_theme() -> CosmoThemeHandle {
+    use_context::<CosmoThemeHandle>()
+        .expect("use_cosmo_theme must be used inside of a CosmoPageLayout or CosmoThemeProvider")
+}
+
+#[hook]
+pub(crate) fn use_inherited_theme(theme: CosmoTheme) -> CosmoTheme {
+    let context = use_context::<CosmoThemeHandle>();
+
+    match (theme, context) {
+        (CosmoTheme::Auto, Some(context)) => context.theme(),
+        (theme, _) => theme,
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoThemeProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub default_theme: CosmoTheme,
+    #[prop_or(AttrValue::from("cosmo-theme"))]
+    pub storage_key: AttrValue,
+}
+
+#[function_component(CosmoThemeProvider)]
+pub fn theme_provider(props: &CosmoThemeProviderProps) -> Html {
+    let theme_state = use_state_eq(|| props.default_theme.clone());
+    let prefers_dark_state = use_state_eq(|| false);
+
+    {
+        let theme_state = theme_state.clone();
+        use_effect_with(props.storage_key.clone(), move |storage_key| {
+            if let Some(theme) = local_storage()
+                .and_then(|storage| storage.get_item(storage_key).ok().flatten())
+                .and_then(|value| theme_from_storage(value.as_str()))
+            {
+                theme_state.set(theme);
+            }
+        });
+    }
+    {
+        let prefers_dark_setter = prefers_dark_state.setter();
+        use_effect_with((), move |_| {
+            let media_query = gloo_utils::window()
+                .match_media("(prefers-color-scheme: dark)")
+                .ok()
+                .flatten();
+            let listener = media_query.clone().map(|media_query| {
+                prefers_dark_setter.set(media_query.matches());
+
+                let listener_media_query = media_query.clone();
+                let listener = Closure::<dyn Fn()>::new(move || {
+                    prefers_dark_setter.set(listener_media_query.matches())
+                });
+                let _ = media_query
+                    .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+
+                listener
+            });
+
+            move || {
+                if let (Some(media_query), Some(listener)) = (media_query, listener) {
+                    let _ = media_query.remove_event_listener_with_callback(
+                        "change",
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+
+    let set_theme = use_callback(
+        (theme_state.clone(), props.storage_key.clone()),
+        |theme: CosmoTheme, (theme_state, storage_key)| {
+            if let Some(storage) = local_storage() {
+                let _ = storage.set_item(storage_key, theme_to_storage(&theme));
+            }
+            theme_state.set(theme);
+        },
+    );
+
+    let handle = CosmoThemeHandle {
+        theme: (*theme_state).clone(),
+        prefers_dark: *prefers_dark_state,
+        set_theme,
+    };
+
+    html!(
+        <ContextProvider<CosmoThemeHandle> context={handle}>
+            <Helmet>
+                <body class={(*theme_state).clone()} />
+            </Helmet>
+            {for props.children.iter()}
+        </ContextProvider<CosmoThemeHandle>>
+    )
+}
@@ -0,0 +1,350 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use stylist::yew::{styled_component, use_style};
+use yew::prelude::*;
+use yew_hooks::{use_hovered, use_interval};
+
+use crate::button::CosmoButton;
+use crate::locale::use_cosmo_locale;
+use crate::message::{CosmoMessage, CosmoMessageType};
+use crate::modal::use_portal_host;
+use crate::prelude::CosmoTheme;
+use crate::theme::use_inherited_theme;
+
+const TOAST_TICK: u32 = 100;
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoToastAction {
+    pub label: AttrValue,
+    pub on_click: Callback<()>,
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoToast {
+    pub message_type: CosmoMessageType,
+    pub header: Option<AttrValue>,
+    pub message: AttrValue,
+    pub action: Option<CosmoToastAction>,
+    pub timeout: Option<u32>,
+}
+
+impl CosmoToast {
+    pub fn new(message_type: CosmoMessageType, message: impl Into<AttrValue>) -> Self {
+        Self {
+            message_type,
+            header: None,
+            message: message.into(),
+            action: None,
+            timeout: Some(5000),
+        }
+    }
+
+    pub fn with_header(mut self, header: impl Into<AttrValue>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    pub fn with_action(mut self, label: impl Into<AttrValue>, on_click: Callback<()>) -> Self {
+        self.action = Some(CosmoToastAction {
+            label: label.into(),
+            on_click,
+        });
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn sticky(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+struct CosmoToastState {
+    toasts: Vec<(usize, CosmoToast)>,
+    max_toasts: usize,
+}
+
+enum CosmoToastReducerAction {
+    Show(usize, CosmoToast),
+    Dismiss(usize),
+    SetMaxToasts(usize),
+}
+
+impl Reducible for CosmoToastState {
+    type Action = CosmoToastReducerAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            CosmoToastReducerAction::Show(id, toast) => state.toasts.push((id, toast)),
+            CosmoToastReducerAction::Dismiss(id) => {
+                state.toasts.retain(|(toast_id, _)| *toast_id != id)
+            }
+            CosmoToastReducerAction::SetMaxToasts(max_toasts) => state.max_toasts = max_toasts,
+        }
+        while state.toasts.len() > state.max_toasts.max(1) {
+            state.toasts.remove(0);
+        }
+
+        state.into()
+    }
+}
+
+#[derive(Clone)]
+pub struct CosmoToastHandle {
+    state: UseReducerHandle<CosmoToastState>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl PartialEq for CosmoToastHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.next_id, &other.next_id)
+    }
+}
+
+impl CosmoToastHandle {
+    pub fn show(&self, toast: CosmoToast) -> usize {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.state
+            .dispatch(CosmoToastReducerAction::Show(id, toast));
+
+        id
+    }
+
+    pub fn dismiss(&self, id: usize) {
+        self.state.dispatch(CosmoToastReducerAction::Dismiss(id));
+    }
+
+    pub fn information(&self, message: impl Into<AttrValue>) -> usize {
+        self.show(CosmoToast::new(CosmoMessageType::Information, message))
+    }
+
+    pub fn warning(&self, message: impl Into<AttrValue>) -> usize {
+        self.show(CosmoToast::new(CosmoMessageType::Warning, message))
+    }
+
+    pub fn positive(&self, message: impl Into<AttrValue>) -> usize {
+        self.show(CosmoToast::new(CosmoMessageType::Positive, message))
+    }
+
+    pub fn negative(&self, message: impl Into<AttrValue>) -> usize {
+        self.show(CosmoToast::new(CosmoMessageType::Negative, message))
+    }
+}
+
+#[hook]
+pub fn use_cosmo_toast() -> CosmoToastHandle {
+    use_context::<CosmoToastHandle>()
+        .expect("use_cosmo_toast must be used inside of a CosmoPageLayout or CosmoToastProvider")
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoToastProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or(5)]
+    pub max_toasts: usize,
+    #[prop_or_default]
+    pub close_label: Option<AttrValue>,
+}
+
+#[styled_component(CosmoToastProvider)]
+pub fn toast_provider(props: &CosmoToastProviderProps) -> Html {
+    let state = use_reducer_eq(|| CosmoToastState {
+        max_toasts: props.max_toasts,
+        ..Default::default()
+    });
+    let next_id = use_memo((), |_| Cell::new(0usize));
+    {
+        let state = state.clone();
+        use_effect_with(props.max_toasts, move |max_toasts| {
+            state.dispatch(CosmoToastReducerAction::SetMaxToasts(*max_toasts))
+        });
+    }
+    let toast_host_id = use_state_eq(|| uuid::Uuid::new_v4().to_string());
+    let toast_host = use_portal_host((*toast_host_id).clone());
+    let theme = use_inherited_theme(CosmoTheme::Auto);
+    let locale = use_cosmo_locale();
+    let close_label = props
+        .close_label
+        .clone()
+        .unwrap_or(locale.labels.close.clone());
+
+    let toast_container_style = use_style!(
+        r#"
+position: fixed;
+inset-inline-end: var(--toast-offset);
+bottom: var(--toast-offset);
+width: var(--toast-width);
+max-width: calc(100vw - var(--toast-offset) * 2);
+display: flex;
+flex-direction: column;
+gap: var(--toast-gap);
+z-index: 10000;
+    "#
+    );
+
+    let handle = CosmoToastHandle {
+        state: state.clone(),
+        next_id,
+    };
+
+    html!(
+        <ContextProvider<CosmoToastHandle> context={handle.clone()}>
+            {for props.children.iter()}
+            if let Some(toast_host) = toast_host {
+                {create_portal(
+                    html!(
+                        <div class={classes!(toast_container_style, theme)} aria-live="polite">
+                            {for state.toasts.iter().map(|(id, toast)| {
+                                let handle = handle.clone();
+                                let id = *id;
+
+                                html!(
+                                    <CosmoToastItem key={id} toast={toast.clone()} close_label={close_label.clone()} on_dismiss={Callback::from(move |_| handle.dismiss(id))} />
+                                )
+                            })}
+                        </div>
+                    ),
+                    toast_host,
+                )}
+            }
+        </ContextProvider<CosmoToastHandle>>
+    )
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoToastItemProps {
+    toast: CosmoToast,
+    close_label: AttrValue,
+    on_dismiss: Callback<()>,
+}
+
+#[styled_component(CosmoToastItem)]
+fn toast_item(props: &CosmoToastItemProps) -> Html {
+    let toast_node = use_node_ref();
+    let is_hovered = use_hovered(toast_node.clone());
+    let remaining_ref = use_mut_ref(|| props.toast.timeout.unwrap_or(0));
+    let on_dismiss_ref = use_mut_ref(|| props.on_dismiss.clone());
+    let dismissed_state = use_state_eq(|| false);
+
+    *on_dismiss_ref.borrow_mut() = props.on_dismiss.clone();
+
+    let delay = if props.toast.timeout.is_some() && !is_hovered && !*dismissed_state {
+        TOAST_TICK
+    } else {
+        0
+    };
+
+    {
+        let remaining_ref = remaining_ref.clone();
+        let on_dismiss_ref = on_dismiss_ref.clone();
+        let dismissed_state = dismissed_state.clone();
+        use_interval(
+            move || {
+                let mut remaining = remaining_ref.borrow_mut();
+                if *remaining == 0 {
+                    return;
+                }
+
+                *remaining = remaining.saturating_sub(TOAST_TICK);
+                if *remaining == 0 {
+                    dismissed_state.set(true);
+                    on_dismiss_ref.borrow().emit(());
+                }
+            },
+            delay,
+        );
+    }
+
+    let toast_style = use_style!(
+        r#"
+animation: cosmo-toast-in var(--transition-duration) ease-out;
+
+> div {
+    margin-bottom: 0;
+}
+
+@keyframes cosmo-toast-in {
+    from {
+        opacity: 0;
+        transform: translateY(1rem);
+    }
+
+    to {
+        opacity: 1;
+        transform: translateY(0);
+    }
+}
+    "#
+    );
+
+    let on_close = use_callback(props.on_dismiss.clone(), |_, on_dismiss| {
+        on_dismiss.emit(())
+    });
+    let on_action = use_callback(
+        (props.toast.action.clone(), props.on_dismiss.clone()),
+        |_, (action, on_dismiss)| {
+            if let Some(action) = action {
+                action.on_click.emit(());
+            }
+            on_dismiss.emit(());
+        },
+    );
+
+    let role = if matches!(
+        props.toast.message_type,
+        CosmoMessageType::Negative | CosmoMessageType::Warning
+    ) {
+        "alert"
+    } else {
+        "status"
+    };
+
+    html!(
+        <div ref={toast_node} class={toast_style} role={role}>
+            <CosmoMessage message_type={props.toast.message_type.clone()} header={props.toast.header.clone()} message={props.toast.message.clone()} actions={html!(
+                <>
+                    <CosmoButton label={props.close_label.clone()} on_click={on_close} />
+                    if let Some(action) = props.toast.action.clone() {
+                        <CosmoButton label={action.label} on_click={on_action} />
+                    }
+                </>
+            )} />
+        </div>
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowering_max_toasts_drops_the_oldest() {
+        let state = (0..4).fold(
+            Rc::new(CosmoToastState {
+                max_toasts: 5,
+                ..Default::default()
+            }),
+            |state, id| {
+                state.reduce(CosmoToastReducerAction::Show(
+                    id,
+                    CosmoToast::new(CosmoMessageType::Information, id.to_string()),
+                ))
+            },
+        );
+        let state = state.reduce(CosmoToastReducerAction::SetMaxToasts(2));
+
+        assert_eq!(
+            state.toasts.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use color_art::{color, Color};
+use yew::AttrValue;
+
+use crate::contrast::{
+    accessible_foreground, adjust_for_contrast, contrast_ratio, relative_luminance,
+    CosmoContrastLevel,
+};
+
+const SHADE_STEPS: [(&str, f64); 4] = [
+    ("light", 0.1),
+    ("lighter", 0.2),
+    ("dark", -0.1),
+    ("darker", -0.2),
+];
+
+const DROPDOWN_BACKGROUND_LIGHT: &str = r#"url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMDAwMDAwO3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=")"#;
+
+const DROPDOWN_BACKGROUND_DARK: &str = r#"url("data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIzMiIgaGVpZ2h0PSI2IiB2aWV3Qm94PSIwIDAgMC4wMDAzMiA2ZS0wNSI+CiAgICA8Zz4KICAgICAgICA8cGF0aCBzdHlsZT0iZmlsbDojMWE0Zjc1O3N0cm9rZS13aWR0aDowLjI2NDk5OTtzdHJva2UtbWl0ZXJsaW1pdDo0O3N0cm9rZS1kYXNoYXJyYXk6bm9uZSIKICAgICAgICAgICAgICBkPSJtIDQuODg5MDY2MSw0LjIzNDA1NTQgLTIuNDQ0NTMzLDFlLTcgTCA3Ljg1NTk1NjZlLTgsNC4yMzQwNTU0IDEuMjIyMjY2NSwyLjExNzAyNzcgMi40NDQ1MzMxLDAgMy42NjY3OTk3LDIuMTE3MDI3NiBaIgogICAgICAgICAgICAgIHRyYW5zZm9ybT0ibWF0cml4KDIuNDU0NDU2NWUtNSwwLDAsLTEuNDE3MDgxMWUtNSw5Ljk5OTk5OThlLTUsNi4wMDAwMDAxZS01KSIvPgogICAgPC9nPgo8L3N2Zz4=")"#;
+
+const DERIVED_VARIABLES: &str = "--white: rgb(var(--white-base) var(--white-base) var(--white-base)); \
+    --black: rgb(var(--black-base) var(--black-base) var(--black-base)); \
+    --menu-text-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 25%); \
+    --menu-text-selected-color: var(--black); \
+    --gradient-top-color: hsl(var(--primary-hue) var(--primary-saturation) calc(var(--primary-lightness-base) * var(--gradient-multiplier))); \
+    --gradient-bottom-color: var(--white); \
+    --modal-backdrop: rgba(var(--white-base) var(--white-base) var(--white-base) / 30%); \
+    --modal-background: rgba(var(--white-base) var(--white-base) var(--white-base) / 90%); \
+    --control-border-color: var(--gray); \
+    --control-border-color-dark: var(--gray-dark); \
+    --control-border-color-darker: var(--gray-darker); \
+    --disabled-color: var(--gray-darker); \
+    --code-color: var(--gray-darker); \
+    --table-stripe-color: rgba(var(--black-base) var(--black-base) var(--black-base) / 20%); \
+    --a-color: var(--primary-color-text); \
+    --range-track-background: var(--control-border-color); \
+    --range-thumb-color: var(--control-border-color); \
+    --range-thumb-border-color: var(--primary-color); \
+    --range-thumb-background-color: var(--range-thumb-color); \
+    --button-circle-background: var(--white); \
+    --button-border-color: var(--control-border-color); \
+    --button-background: var(--white); \
+    --button-color: var(--black); \
+    --progress-bar-gradient-color: var(--primary-color); \
+    --progress-bar-gradient-color-light: var(--primary-color-light);";
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoColorTokens {
+    pub primary: Color,
+    pub negative: Color,
+    pub positive: Color,
+    pub information: Color,
+    pub warning: Color,
+    pub gray: Color,
+}
+
+impl CosmoColorTokens {
+    pub fn light() -> Self {
+        Self {
+            primary: color!(#19324c),
+            negative: color!(hsl, 2.0, 0.67, 0.57),
+            positive: color!(hsl, 146.0, 0.35, 0.46),
+            information: color!(hsl, 205.0, 0.64, 0.48),
+            warning: color!(hsl, 42.0, 0.54, 0.54),
+            gray: color!(hsl, 0.0, 0.0, 0.8),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            primary: color!(#0f1e2e),
+            negative: color!(hsl, 2.0, 0.66, 0.28),
+            positive: color!(hsl, 146.0, 0.35, 0.28),
+            information: color!(hsl, 205.0, 0.64, 0.28),
+            warning: color!(hsl, 42.0, 0.79, 0.4),
+            gray: color!(hsl, 0.0, 0.0, 0.2),
+        }
+    }
+
+    fn accents(&self) -> [(&'static str, &Color); 5] {
+        [
+            ("primary", &self.primary),
+            ("negative", &self.negative),
+            ("positive", &self.positive),
+            ("information", &self.information),
+            ("warning", &self.warning),
+        ]
+    }
+
+    fn to_variables(&self, background: &Color, text: &Color) -> String {
+        let is_light = relative_luminance(background) > 0.5;
+        let direction = if is_light { 1.0 } else { -1.0 };
+        let steps = SHADE_STEPS.map(|(name, step)| (name, step * direction));
+
+        let mut variables = steps
+            .iter()
+            .map(|(name, step)| format!("--{name}-step: {}%;", round(step * 100.0)))
+            .collect::<Vec<_>>();
+        variables.push(format!(
+            "--white-base: {}; --black-base: {}; --gradient-multiplier: {}; --dropdown-background: {};",
+            background.red(),
+            text.red(),
+            if is_light { 4.0 } else { 0.25 },
+            if is_light {
+                DROPDOWN_BACKGROUND_LIGHT
+            } else {
+                DROPDOWN_BACKGROUND_DARK
+            }
+        ));
+        variables.extend(
+            self.accents()
+                .iter()
+                .chain([("gray", &self.gray)].iter())
+                .map(|(name, color)| {
+                    format!(
+                        "--{name}-hue: {}; --{name}-saturation: {}%; --{name}-lightness-base: {}%;",
+                        round(color.hue()),
+                        round(color.saturation() * 100.0),
+                        round(color.lightness() * 100.0)
+                    )
+                }),
+        );
+        variables.extend(self.accents().iter().map(|(name, color)| {
+            let mut shades = vec![format!(
+                "--{name}-color: {};",
+                hsl_variable(name, None, None)
+            )];
+            shades.extend(steps.iter().map(|(step, _)| {
+                format!(
+                    "--{name}-color-{step}: {};",
+                    hsl_variable(name, Some(step), None)
+                )
+            }));
+            shades.extend([25, 50].map(|alpha| {
+                format!(
+                    "--{name}-color-alpha-{alpha}: {};",
+                    hsl_variable(name, None, Some(alpha))
+                )
+            }));
+            shades.push(format!(
+                "--{name}-color-foreground: {}; --{name}-color-text: {};",
+                accessible_foreground(color, background, text).hex(),
+                adjust_for_contrast(color, background, CosmoContrastLevel::Aa).hex()
+            ));
+            shades.extend(
+                steps
+                    .iter()
+                    .filter(|(step, _)| step.starts_with("dark"))
+                    .map(|(step, offset)| {
+                        format!(
+                            "--{name}-color-{step}-foreground: {};",
+                            accessible_foreground(&shade(color, *offset), background, text).hex()
+                        )
+                    }),
+            );
+
+            shades.join(" ")
+        }));
+        variables.push(format!("--gray: {};", hsl_variable("gray", None, None)));
+        variables.extend(steps.iter().map(|(step, _)| {
+            format!("--gray-{step}: {};", hsl_variable("gray", Some(step), None))
+        }));
+        variables.extend(
+            steps
+                .iter()
+                .filter(|(step, _)| step.starts_with("dark"))
+                .map(|(step, offset)| {
+                    format!(
+                        "--control-border-color-{step}-foreground: {};",
+                        accessible_foreground(&shade(&self.gray, *offset), background, text).hex()
+                    )
+                }),
+        );
+        variables.push(String::from(DERIVED_VARIABLES));
+
+        variables.join(" ")
+    }
+
+    fn contrast_warnings(&self, scheme: &str, background: &Color, text: &Color) -> Vec<String> {
+        let foreground = accessible_foreground(&self.primary, background, text);
+        let foreground_ratio = contrast_ratio(&foreground, &self.primary);
+        let mut warnings = vec![];
+        if foreground_ratio < CosmoContrastLevel::Aa.min_ratio() {
+            warnings.push(format!(
+                "The {scheme} primary color {} has a text contrast of {:.2}:1, WCAG AA requires at least 4.5:1",
+                self.primary.hex(),
+                foreground_ratio
+            ));
+        }
+
+        warnings
+    }
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn shade(color: &Color, step: f64) -> Color {
+    Color::from_hsl(
+        color.hue(),
+        color.saturation(),
+        (color.lightness() + step).clamp(0.0, 1.0),
+    )
+    .unwrap_or(*color)
+}
+
+fn hsl_variable(name: &str, step: Option<&str>, alpha: Option<u8>) -> String {
+    let lightness = if let Some(step) = step {
+        format!("calc(var(--{name}-lightness-base) + var(--{step}-step))")
+    } else {
+        format!("var(--{name}-lightness-base)")
+    };
+    let alpha = alpha
+        .map(|alpha| format!(" / {alpha}%"))
+        .unwrap_or_default();
+
+    format!("hsl(var(--{name}-hue) var(--{name}-saturation) {lightness}{alpha})")
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoSpacingScale {
+    pub extra_small: AttrValue,
+    pub small: AttrValue,
+    pub medium: AttrValue,
+    pub large: AttrValue,
+    pub extra_large: AttrValue,
+}
+
+impl Default for CosmoSpacingScale {
+    fn default() -> Self {
+        Self {
+            extra_small: AttrValue::from("0.25rem"),
+            small: AttrValue::from("0.5rem"),
+            medium: AttrValue::from("0.75rem"),
+            large: AttrValue::from("1rem"),
+            extra_large: AttrValue::from("2rem"),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct CosmoThemeTokens {
+    pub light: CosmoColorTokens,
+    pub dark: CosmoColorTokens,
+    pub font_family: AttrValue,
+    pub font_family_heading: AttrValue,
+    pub font_family_menu: AttrValue,
+    pub font_family_code: AttrValue,
+    pub font_size: AttrValue,
+    pub line_height: AttrValue,
+    pub border_radius: AttrValue,
+    pub spacing: CosmoSpacingScale,
+    pub control_height: AttrValue,
+    pub transition_duration: AttrValue,
+}
+
+impl Default for CosmoThemeTokens {
+    fn default() -> Self {
+        Self {
+            light: CosmoColorTokens::light(),
+            dark: CosmoColorTokens::dark(),
+            font_family: AttrValue::from("'Albert Sans', sans-serif"),
+            font_family_heading: AttrValue::from("Urbanist, sans-serif"),
+            font_family_menu: AttrValue::from("Urbanist, sans-serif"),
+            font_family_code: AttrValue::from("'Oxygen Mono', monospace"),
+            font_size: AttrValue::from("1rem"),
+            line_height: AttrValue::from("1.1875"),
+            border_radius: AttrValue::from("0.25rem"),
+            spacing: CosmoSpacingScale::default(),
+            control_height: AttrValue::from("1.875rem"),
+            transition_duration: AttrValue::from("0.3s"),
+        }
+    }
+}
+
+fn light_background() -> Color {
+    color!(#ffffff)
+}
+
+fn light_text() -> Color {
+    color!(#333333)
+}
+
+fn dark_background() -> Color {
+    color!(#000000)
+}
+
+fn dark_text() -> Color {
+    color!(#cccccc)
+}
+
+impl CosmoThemeTokens {
+    pub fn contrast_warnings(&self) -> Vec<String> {
+        let mut warnings =
+            self.light
+                .contrast_warnings("light", &light_background(), &light_text());
+        warnings.extend(
+            self.dark
+                .contrast_warnings("dark", &dark_background(), &dark_text()),
+        );
+
+        warnings
+    }
+
+    pub fn to_css(&self) -> String {
+        let light = self.light.to_variables(&light_background(), &light_text());
+        let dark = self.dark.to_variables(&dark_background(), &dark_text());
+
+        format!(
+            r#":root {{
+    {light}
+    --font-family: {font_family};
+    --font-family-heading: {font_family_heading};
+    --font-family-modal-title: {font_family_heading};
+    --font-family-menu: {font_family_menu};
+    --font-family-code: {font_family_code};
+    --font-size: {font_size};
+    --line-height: {line_height};
+    --border-radius: {border_radius};
+    --spacing-xs: {spacing_xs};
+    --spacing-s: {spacing_s};
+    --spacing-m: {spacing_m};
+    --spacing-l: {spacing_l};
+    --spacing-xl: {spacing_xl};
+    --control-height: {control_height};
+    --transition-duration: {transition_duration};
+}}
+
+@media screen and (prefers-color-scheme: dark) {{
+    :root {{
+        {dark}
+        background: var(--white);
+        color: var(--black);
+    }}
+}}
+
+.is--light {{
+    {light}
+    background: var(--white);
+    color: var(--black);
+}}
+
+.is--dark {{
+    {dark}
+    background: var(--white);
+    color: var(--black);
+}}
+"#,
+            font_family = self.font_family,
+            font_family_heading = self.font_family_heading,
+            font_family_menu = self.font_family_menu,
+            font_family_code = self.font_family_code,
+            font_size = self.font_size,
+            line_height = self.line_height,
+            border_radius = self.border_radius,
+            spacing_xs = self.spacing.extra_small,
+            spacing_s = self.spacing.small,
+            spacing_m = self.spacing.medium,
+            spacing_l = self.spacing.large,
+            spacing_xl = self.spacing.extra_large,
+            control_height = self.control_height,
+            transition_duration = self.transition_duration,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_palette_follows_the_scheme() {
+        let tokens = CosmoThemeTokens::default();
+        let light = tokens
+            .light
+            .to_variables(&light_background(), &light_text());
+        let dark = tokens.dark.to_variables(&dark_background(), &dark_text());
+
+        assert!(light.contains("--dark-step: -10%;"));
+        assert!(light.contains("--white-base: 255; --black-base: 51;"));
+        assert!(dark.contains("--dark-step: 10%;"));
+        assert!(dark.contains("--white-base: 0; --black-base: 204;"));
+        for variables in [&light, &dark] {
+            assert!(variables.contains("--warning-color-darker: hsl(var(--warning-hue) var(--warning-saturation) calc(var(--warning-lightness-base) + var(--darker-step)));"));
+            assert!(variables.contains("--gray-lighter: hsl(var(--gray-hue) var(--gray-saturation) calc(var(--gray-lightness-base) + var(--lighter-step)));"));
+            assert!(variables.contains("--primary-color-alpha-25: hsl(var(--primary-hue) var(--primary-saturation) var(--primary-lightness-base) / 25%);"));
+            assert!(variables.contains("--button-background: var(--white);"));
+        }
+    }
+
+    #[test]
+    fn hover_shades_get_accessible_foregrounds() {
+        for (tokens, background, text) in [
+            (CosmoColorTokens::light(), light_background(), light_text()),
+            (CosmoColorTokens::dark(), dark_background(), dark_text()),
+        ] {
+            let variables = tokens.to_variables(&background, &text);
+            let direction = if relative_luminance(&background) > 0.5 {
+                1.0
+            } else {
+                -1.0
+            };
+            for (name, color) in tokens
+                .accents()
+                .iter()
+                .map(|(name, color)| (format!("{name}-color"), *color))
+                .chain([(String::from("control-border-color"), &tokens.gray)])
+            {
+                for (step, offset) in [("dark", -0.1), ("darker", -0.2)] {
+                    let shade = shade(color, offset * direction);
+                    let foreground = accessible_foreground(&shade, &background, &text);
+                    assert!(variables.contains(&format!(
+                        "--{name}-{step}-foreground: {};",
+                        foreground.hex()
+                    )));
+                    assert!(
+                        contrast_ratio(&foreground, &shade)
+                            >= contrast_ratio(&background, &shade)
+                                .min(contrast_ratio(&text, &shade))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn changing_a_token_updates_its_variables() {
+        let tokens = CosmoThemeTokens {
+            light: CosmoColorTokens {
+                positive: color!(hsl, 120.0, 0.5, 0.3),
+                ..CosmoColorTokens::light()
+            },
+            ..CosmoThemeTokens::default()
+        };
+        let css = tokens.to_css();
+
+        assert!(css.contains(
+            "--positive-hue: 120; --positive-saturation: 50%; --positive-lightness-base: 30%;"
+        ));
+        assert_eq!(css.matches("--positive-color-dark:").count(), 4);
+    }
+}
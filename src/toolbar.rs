@@ -1,10 +1,30 @@
 use stylist::yew::{styled_component, use_style};
+use yew::html::ChildrenRenderer;
 use yew::prelude::*;
+use yew::virtual_dom::VChild;
+
+use crate::locale::use_cosmo_locale;
+
+#[derive(Clone, derive_more::From, PartialEq)]
+pub enum CosmoToolbarChildren {
+    CosmoToolbarGroup(VChild<CosmoToolbarGroup>),
+    CosmoToolbarSelection(VChild<CosmoToolbarSelection>),
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Html> for CosmoToolbarChildren {
+    fn into(self) -> Html {
+        match self {
+            CosmoToolbarChildren::CosmoToolbarGroup(child) => child.into(),
+            CosmoToolbarChildren::CosmoToolbarSelection(child) => child.into(),
+        }
+    }
+}
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct CosmoToolbarProps {
     #[prop_or_default]
-    pub children: ChildrenWithProps<CosmoToolbarGroup>,
+    pub children: ChildrenRenderer<CosmoToolbarChildren>,
 }
 
 #[styled_component(CosmoToolbar)]
@@ -41,17 +61,17 @@ align-items: center;
 }
 
 .cosmo-button:first-child {
-	border-top-left-radius: var(--border-radius);
-	border-bottom-left-radius: var(--border-radius);
+	border-start-start-radius: var(--border-radius);
+	border-end-start-radius: var(--border-radius);
 }
 
 .cosmo-button:last-child {
-	border-top-right-radius: var(--border-radius);
-	border-bottom-right-radius: var(--border-radius);
+	border-start-end-radius: var(--border-radius);
+	border-end-end-radius: var(--border-radius);
 }
 
 .cosmo-button:not(:first-child) {
-    border-left-width: 0;
+    border-inline-start-width: 0;
 }
     "#
     );
@@ -62,3 +82,49 @@ align-items: center;
         </div>
     )
 }
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoToolbarSelectionProps {
+    pub count: usize,
+    #[prop_or_default]
+    pub format_label: Option<Callback<usize, AttrValue>>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+#[styled_component(CosmoToolbarSelection)]
+pub fn toolbar_selection(props: &CosmoToolbarSelectionProps) -> Html {
+    let locale = use_cosmo_locale();
+    let toolbar_selection_style = use_style!(
+        r#"
+display: flex;
+align-items: center;
+gap: var(--toolbar-gap);
+    "#
+    );
+    let label_style = use_style!(
+        r#"
+font-weight: var(--font-weight-bold);
+white-space: nowrap;
+    "#
+    );
+
+    if props.count == 0 {
+        return html!();
+    }
+
+    let label = if let Some(format_label) = &props.format_label {
+        format_label.emit(props.count)
+    } else {
+        AttrValue::from(format!("{} {}", props.count, locale.labels.selected))
+    };
+
+    html!(
+        <div class={toolbar_selection_style} role="status">
+            <span class={label_style}>{label}</span>
+            <CosmoToolbarGroup>
+                {for props.children.iter()}
+            </CosmoToolbarGroup>
+        </div>
+    )
+}
@@ -0,0 +1,433 @@
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use regex_lite::Regex;
+use stylist::yew::{styled_component, use_style};
+use web_sys::{HtmlElement, Node};
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+pub type CosmoValidationResult = Result<(), AttrValue>;
+
+type CosmoValidationFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+#[derive(PartialEq, Clone, Default)]
+pub enum CosmoValidationMode {
+    OnBlur,
+    OnInput,
+    #[default]
+    OnSubmit,
+}
+
+#[derive(Clone)]
+pub struct CosmoValidator {
+    validate: Rc<dyn Fn(String) -> CosmoValidationFuture<CosmoValidationResult>>,
+}
+
+impl PartialEq for CosmoValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.validate, &other.validate)
+    }
+}
+
+impl CosmoValidator {
+    pub fn custom(validate: impl Fn(&str) -> CosmoValidationResult + 'static) -> Self {
+        Self {
+            validate: Rc::new(move |value| Box::pin(std::future::ready(validate(&value)))),
+        }
+    }
+
+    pub fn custom_async<F, R>(validate: F) -> Self
+    where
+        F: Fn(String) -> R + 'static,
+        R: Future<Output = CosmoValidationResult> + 'static,
+    {
+        Self {
+            validate: Rc::new(move |value| Box::pin(validate(value))),
+        }
+    }
+
+    pub fn required(message: impl Into<AttrValue>) -> Self {
+        let message = message.into();
+        Self::custom(move |value| {
+            if value.trim().is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    pub fn min_length(min_length: usize, message: impl Into<AttrValue>) -> Self {
+        let message = message.into();
+        Self::custom(move |value| {
+            if !value.is_empty() && value.chars().count() < min_length {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    pub fn max_length(max_length: usize, message: impl Into<AttrValue>) -> Self {
+        let message = message.into();
+        Self::custom(move |value| {
+            if value.chars().count() > max_length {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    pub fn pattern(pattern: impl Into<AttrValue>, message: impl Into<AttrValue>) -> Self {
+        let message = message.into();
+        Self::try_pattern(pattern, message.clone()).unwrap_or_else(|err| {
+            #[cfg(all(debug_assertions, target_arch = "wasm32"))]
+            web_sys::console::warn_1(&format!("Invalid validation pattern: {err}").into());
+            #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+            eprintln!("Invalid validation pattern: {err}");
+            #[cfg(not(debug_assertions))]
+            let _ = err;
+
+            Self::custom(move |value| {
+                if value.is_empty() {
+                    Ok(())
+                } else {
+                    Err(message.clone())
+                }
+            })
+        })
+    }
+
+    pub fn try_pattern(
+        pattern: impl Into<AttrValue>,
+        message: impl Into<AttrValue>,
+    ) -> Result<Self, AttrValue> {
+        let regex = Regex::new(&format!("^(?:{})$", pattern.into()))
+            .map_err(|err| AttrValue::from(err.to_string()))?;
+        let message = message.into();
+
+        Ok(Self::custom(move |value| {
+            if value.is_empty() || regex.is_match(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        }))
+    }
+
+    pub fn range(min: Option<f64>, max: Option<f64>, message: impl Into<AttrValue>) -> Self {
+        let message = message.into();
+        Self::custom(move |value| {
+            if value.is_empty() {
+                return Ok(());
+            }
+
+            match value.parse::<f64>() {
+                Ok(number)
+                    if min.map(|min| number >= min).unwrap_or(true)
+                        && max.map(|max| number <= max).unwrap_or(true) =>
+                {
+                    Ok(())
+                }
+                _ => Err(message.clone()),
+            }
+        })
+    }
+
+    pub async fn validate(&self, value: impl Into<String>) -> CosmoValidationResult {
+        (self.validate)(value.into()).await
+    }
+}
+
+async fn validate_all(validators: Vec<CosmoValidator>, value: String) -> CosmoValidationResult {
+    for validator in validators {
+        validator.validate(value.clone()).await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Clone)]
+struct CosmoFormField {
+    validate: Rc<dyn Fn() -> CosmoValidationFuture<bool>>,
+    has_validators: Rc<dyn Fn() -> bool>,
+    node_ref: NodeRef,
+}
+
+#[derive(Clone)]
+pub(crate) struct CosmoFormContext {
+    fields: Rc<RefCell<BTreeMap<usize, CosmoFormField>>>,
+    next_id: Rc<Cell<usize>>,
+    is_submitting: Rc<Cell<bool>>,
+    mode: CosmoValidationMode,
+}
+
+impl PartialEq for CosmoFormContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.fields, &other.fields) && self.mode == other.mode
+    }
+}
+
+impl CosmoFormContext {
+    fn register(&self, field: CosmoFormField) -> usize {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.fields.borrow_mut().insert(id, field);
+
+        id
+    }
+
+    fn unregister(&self, id: usize) {
+        self.fields.borrow_mut().remove(&id);
+    }
+
+    pub(crate) fn submit(&self, on_valid: Callback<()>) {
+        if self.is_submitting.get() {
+            return;
+        }
+
+        let fields = self.fields.borrow().values().cloned().collect::<Vec<_>>();
+        if !fields.iter().any(|field| (field.has_validators)()) {
+            on_valid.emit(());
+            return;
+        }
+
+        let is_submitting = self.is_submitting.clone();
+        is_submitting.set(true);
+        spawn_local(async move {
+            let mut invalid = vec![];
+            for field in fields {
+                if !(field.validate)().await {
+                    invalid.push(field.node_ref);
+                }
+            }
+            is_submitting.set(false);
+
+            if invalid.is_empty() {
+                on_valid.emit(());
+            } else if let Some(element) = invalid
+                .iter()
+                .filter_map(|node_ref| node_ref.cast::<HtmlElement>())
+                .min_by(|a, b| {
+                    if a.compare_document_position(b) & Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                })
+            {
+                let _ = element.focus();
+            }
+        });
+    }
+}
+
+#[hook]
+pub(crate) fn use_cosmo_form_context(mode: CosmoValidationMode) -> CosmoFormContext {
+    let fields = use_memo((), |_| RefCell::new(BTreeMap::new()));
+    let next_id = use_memo((), |_| Cell::new(0usize));
+    let is_submitting = use_memo((), |_| Cell::new(false));
+
+    CosmoFormContext {
+        fields,
+        next_id,
+        is_submitting,
+        mode,
+    }
+}
+
+pub(crate) struct CosmoField {
+    pub(crate) error: Option<AttrValue>,
+    pub(crate) invalid_class: Option<Classes>,
+    pub(crate) on_blur: Callback<FocusEvent>,
+    pub(crate) node_ref: NodeRef,
+}
+
+impl CosmoField {
+    pub(crate) fn error_id(&self, id: &AttrValue) -> Option<AttrValue> {
+        self.error
+            .as_ref()
+            .map(|_| AttrValue::from(format!("{id}-error")))
+    }
+
+    pub(crate) fn aria_invalid(&self) -> Option<AttrValue> {
+        self.error.as_ref().map(|_| AttrValue::from("true"))
+    }
+
+    pub(crate) fn error_message(&self, id: &AttrValue) -> Html {
+        html!(
+            <CosmoFieldError id={self.error_id(id)} error={self.error.clone()} />
+        )
+    }
+}
+
+#[hook]
+pub(crate) fn use_cosmo_field(value: String, validators: Vec<CosmoValidator>) -> CosmoField {
+    let form = use_context::<CosmoFormContext>();
+    let mode = form
+        .as_ref()
+        .map(|form| form.mode.clone())
+        .unwrap_or(CosmoValidationMode::OnBlur);
+
+    let node_ref = use_node_ref();
+    let error_state = use_state_eq(|| None as Option<AttrValue>);
+    let latest = use_mut_ref(|| (String::new(), vec![]));
+    *latest.borrow_mut() = (value.clone(), validators);
+
+    let validate = use_memo((), {
+        let latest = latest.clone();
+        let error_setter = error_state.setter();
+        move |_| {
+            let generation = Rc::new(Cell::new(0usize));
+            Rc::new(move || -> CosmoValidationFuture<bool> {
+                let (value, validators) = latest.borrow().clone();
+                let current_generation = generation.get() + 1;
+                generation.set(current_generation);
+
+                let generation = generation.clone();
+                let error_setter = error_setter.clone();
+                Box::pin(async move {
+                    let result = validate_all(validators, value).await;
+                    if generation.get() == current_generation {
+                        error_setter.set(result.clone().err());
+                    }
+
+                    result.is_ok()
+                })
+            }) as Rc<dyn Fn() -> CosmoValidationFuture<bool>>
+        }
+    });
+
+    let registration = use_mut_ref(|| None as Option<usize>);
+    {
+        let form = form.clone();
+        let registration = registration.clone();
+        let validate = (*validate).clone();
+        let latest = latest.clone();
+        let node_ref = node_ref.clone();
+        use_effect_with(form, move |form| {
+            if let Some(form) = form {
+                *registration.borrow_mut() = Some(form.register(CosmoFormField {
+                    validate,
+                    has_validators: Rc::new(move || !latest.borrow().1.is_empty()),
+                    node_ref,
+                }));
+            }
+
+            let form = form.clone();
+            move || {
+                if let (Some(form), Some(id)) = (form, registration.borrow_mut().take()) {
+                    form.unregister(id);
+                }
+            }
+        });
+    }
+    let is_mounted = use_mut_ref(|| false);
+    {
+        let validate = validate.clone();
+        let has_error = error_state.is_some();
+        let mode = mode.clone();
+        use_effect_with(value, move |_| {
+            if *is_mounted.borrow() {
+                if mode == CosmoValidationMode::OnInput || has_error {
+                    spawn_local(async move {
+                        validate().await;
+                    });
+                }
+            } else {
+                *is_mounted.borrow_mut() = true;
+            }
+        });
+    }
+
+    let on_blur = {
+        let validate = validate.clone();
+        use_callback(
+            (mode, error_state.is_some()),
+            move |_: FocusEvent, (mode, has_error)| {
+                if *mode != CosmoValidationMode::OnSubmit || *has_error {
+                    let validate = validate.clone();
+                    spawn_local(async move {
+                        validate().await;
+                    });
+                }
+            },
+        )
+    };
+
+    let invalid_style = use_style!(
+        r#"
+--border-indicator-color: var(--negative-color);
+border-color: var(--negative-color);
+    "#
+    );
+
+    CosmoField {
+        invalid_class: error_state.as_ref().map(|_| classes!(invalid_style)),
+        error: (*error_state).clone(),
+        on_blur,
+        node_ref,
+    }
+}
+
+#[derive(PartialEq, Clone, Properties)]
+struct CosmoFieldErrorProps {
+    id: Option<AttrValue>,
+    error: Option<AttrValue>,
+}
+
+#[styled_component(CosmoFieldError)]
+fn field_error(props: &CosmoFieldErrorProps) -> Html {
+    let error_style = use_style!(
+        r#"
+grid-column: 2 / 3;
+margin-top: calc(var(--input-group-gap) * -0.5);
+color: var(--negative-color-text);
+font-size: var(--input-error-font-size);
+font-family: var(--font-family);
+    "#
+    );
+
+    if let Some(error) = props.error.clone() {
+        html!(
+            <div id={props.id.clone()} class={error_style} role="alert">{error}</div>
+        )
+    } else {
+        html!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pattern_matches_the_whole_value() {
+        let email = CosmoValidator::pattern(r"[^@\s]+@[^@\s]+\.[^@\s]+", "Invalid");
+
+        assert_eq!(email.validate("").await, Ok(()));
+        assert_eq!(email.validate("jane@example.com").await, Ok(()));
+        assert_eq!(
+            email.validate("Jane <jane@example.com>").await,
+            Err(AttrValue::from("Invalid"))
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_patterns_reject_every_value() {
+        assert!(CosmoValidator::try_pattern("[a-z", "Invalid").is_err());
+
+        let broken = CosmoValidator::pattern("[a-z", "Invalid");
+        assert_eq!(broken.validate("").await, Ok(()));
+        assert_eq!(
+            broken.validate("abc").await,
+            Err(AttrValue::from("Invalid"))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::MediaQueryList;
+use yew::prelude::*;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct CosmoBreakpoints {
+    pub compact: u32,
+    pub medium: u32,
+}
+
+impl Default for CosmoBreakpoints {
+    fn default() -> Self {
+        Self {
+            compact: 768,
+            medium: 1280,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum CosmoViewport {
+    Compact,
+    Medium,
+    #[default]
+    Wide,
+}
+
+impl CosmoViewport {
+    pub fn is_compact(&self) -> bool {
+        *self == CosmoViewport::Compact
+    }
+}
+
+impl std::fmt::Display for CosmoViewport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CosmoViewport::Compact => "is--compact",
+            CosmoViewport::Medium => "is--medium",
+            CosmoViewport::Wide => "",
+        })
+    }
+}
+
+impl From<CosmoViewport> for Classes {
+    fn from(value: CosmoViewport) -> Self {
+        value.to_string().into()
+    }
+}
+
+#[hook]
+pub fn use_cosmo_viewport() -> CosmoViewport {
+    use_context::<CosmoViewport>().unwrap_or_default()
+}
+
+fn match_max_width(width: u32) -> Option<MediaQueryList> {
+    gloo_utils::window()
+        .match_media(format!("(max-width: {width}px)").as_str())
+        .ok()
+        .flatten()
+}
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct CosmoViewportProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub breakpoints: CosmoBreakpoints,
+}
+
+#[function_component(CosmoViewportProvider)]
+pub fn viewport_provider(props: &CosmoViewportProviderProps) -> Html {
+    let viewport_state = use_state_eq(CosmoViewport::default);
+
+    {
+        let viewport_setter = viewport_state.setter();
+        use_effect_with(props.breakpoints, move |breakpoints| {
+            let media_queries = [
+                match_max_width(breakpoints.compact),
+                match_max_width(breakpoints.medium),
+            ];
+            let update_viewport = {
+                let media_queries = media_queries.clone();
+                move || {
+                    let matches = |media_query: &Option<MediaQueryList>| {
+                        media_query
+                            .as_ref()
+                            .map(|media_query| media_query.matches())
+                            .unwrap_or(false)
+                    };
+                    let viewport = if matches(&media_queries[0]) {
+                        CosmoViewport::Compact
+                    } else if matches(&media_queries[1]) {
+                        CosmoViewport::Medium
+                    } else {
+                        CosmoViewport::Wide
+                    };
+
+                    viewport_setter.set(viewport);
+                }
+            };
+            update_viewport();
+
+            let listener = Closure::<dyn Fn()>::new(update_viewport);
+            for media_query in media_queries.iter().flatten() {
+                let _ = media_query
+                    .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+            }
+
+            move || {
+                for media_query in media_queries.iter().flatten() {
+                    let _ = media_query.remove_event_listener_with_callback(
+                        "change",
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+
+    html!(
+        <ContextProvider<CosmoViewport> context={*viewport_state}>
+            {for props.children.iter()}
+        </ContextProvider<CosmoViewport>>
+    )
+}
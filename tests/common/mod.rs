@@ -0,0 +1,53 @@
+use yew::prelude::*;
+use yew::ServerRenderer;
+use yew_cosmo::prelude::*;
+use yew_router::history::{AnyHistory, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(PartialEq, Properties)]
+pub struct HostProps {
+    pub content: Html,
+    pub path: &'static str,
+}
+
+#[function_component(Host)]
+pub fn host(props: &HostProps) -> Html {
+    html!(
+        <CosmoAppRoot>
+            <Router history={AnyHistory::from(MemoryHistory::with_entries(vec![props.path]))}>
+                {props.content.clone()}
+            </Router>
+        </CosmoAppRoot>
+    )
+}
+
+pub async fn render(content: fn() -> Html) -> String {
+    render_at("/", content).await
+}
+
+pub async fn render_at(path: &'static str, content: fn() -> Html) -> String {
+    ServerRenderer::<Host>::with_props(move || HostProps {
+        content: content(),
+        path,
+    })
+    .hydratable(false)
+    .render()
+    .await
+}
+
+#[derive(Clone, Routable, PartialEq)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/gallery")]
+    Gallery,
+    #[at("/gallery/folder")]
+    Folder,
+    #[at("/settings")]
+    Settings,
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct Person {
+    pub name: AttrValue,
+}
@@ -0,0 +1,741 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use yew::prelude::*;
+use yew_cosmo::prelude::*;
+
+use common::{render, render_at, Person, Route};
+
+mod common;
+
+fn take_class_name(input: &str) -> Option<usize> {
+    let rest = input.strip_prefix("stylist-")?;
+    let len = rest
+        .bytes()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+
+    (len > 0).then_some("stylist-".len() + len)
+}
+
+fn take_uuid(input: &str) -> Option<usize> {
+    let candidate = input.get(..36)?;
+    let is_uuid = candidate.bytes().enumerate().all(|(idx, byte)| match idx {
+        8 | 13 | 18 | 23 => byte == b'-',
+        _ => byte.is_ascii_hexdigit(),
+    });
+
+    is_uuid.then_some(36)
+}
+
+fn normalize(html: &str) -> String {
+    let mut class_names = Vec::<&str>::new();
+    let mut uuids = Vec::<&str>::new();
+    let mut normalized = String::with_capacity(html.len());
+    let mut idx = 0;
+
+    while idx < html.len() {
+        let rest = &html[idx..];
+        if let Some(len) = take_class_name(rest) {
+            let class_name = &rest[..len];
+            let position = class_names
+                .iter()
+                .position(|known| *known == class_name)
+                .unwrap_or_else(|| {
+                    class_names.push(class_name);
+                    class_names.len() - 1
+                });
+            normalized.push_str(format!("stylist-{position}").as_str());
+            idx += len;
+        } else if let Some(len) = take_uuid(rest) {
+            let uuid = &rest[..len];
+            let position = uuids
+                .iter()
+                .position(|known| *known == uuid)
+                .unwrap_or_else(|| {
+                    uuids.push(uuid);
+                    uuids.len() - 1
+                });
+            normalized.push_str(format!("uuid-{position}").as_str());
+            idx += len;
+        } else {
+            let char = rest.chars().next().expect("Should have a char");
+            normalized.push(char);
+            idx += char.len_utf8();
+        }
+    }
+
+    format!("{}\n", normalized.replace("><", ">\n<"))
+}
+
+fn assert_snapshot(name: &str, html: String) {
+    let actual = normalize(html.as_str());
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.html"));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).expect("Failed to write snapshot");
+        return;
+    }
+
+    if !path.exists() {
+        panic!("Missing snapshot {name}, rerun with UPDATE_SNAPSHOTS=1");
+    }
+
+    let expected = fs::read_to_string(&path).expect("Failed to read snapshot");
+    if expected != actual {
+        let (line, expected_line, actual_line) = expected
+            .lines()
+            .map(Some)
+            .chain(std::iter::repeat(None))
+            .zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+            .enumerate()
+            .find(|(_, (expected, actual))| expected != actual)
+            .map(|(line, (expected, actual))| (line + 1, expected, actual))
+            .expect("Snapshots differ");
+
+        panic!(
+            "Snapshot {name} differs at line {line}\n  expected: {}\n  actual:   {}\nRun the tests with UPDATE_SNAPSHOTS=1 to accept the new markup",
+            expected_line.unwrap_or("<end of snapshot>"),
+            actual_line.unwrap_or("<end of markup>"),
+        );
+    }
+}
+
+macro_rules! snapshot {
+    ($name:ident, $html:expr) => {
+        #[tokio::test]
+        async fn $name() {
+            assert_snapshot(stringify!($name), render(|| $html).await);
+        }
+    };
+    ($name:ident, $path:literal, $html:expr) => {
+        #[tokio::test]
+        async fn $name() {
+            assert_snapshot(stringify!($name), render_at($path, || $html).await);
+        }
+    };
+}
+
+fn menu_items() -> Vec<CosmoMenuItem<Route>> {
+    vec![
+        CosmoMenuItem::new("Gallery", Route::Gallery)
+            .with_route_match(CosmoRouteMatch::Prefix)
+            .with_children(vec![
+                CosmoMenuItem::new("Overview", Route::Gallery),
+                CosmoMenuItem::new("Folder", Route::Folder),
+            ]),
+        CosmoMenuItem::new("Settings", Route::Settings),
+    ]
+}
+
+snapshot!(button, html!(<CosmoButton label="Save" />));
+
+snapshot!(
+    button_states,
+    html!(
+        <>
+            <CosmoButton label="Primary" state={CosmoButtonType::Primary} />
+            <CosmoButton label="Positive" state={CosmoButtonType::Positive} />
+            <CosmoButton label="Negative" state={CosmoButtonType::Negative} />
+            <CosmoButton label="Information" state={CosmoButtonType::Information} />
+            <CosmoButton label="Warning" state={CosmoButtonType::Warning} />
+            <CosmoButton label="Disabled" enabled={false} />
+            <CosmoButton label="Submit" is_submit={true} is_full_width={true} />
+        </>
+    )
+);
+
+snapshot!(
+    button_link,
+    html!(<CosmoButtonLink<Route> label="Home" to={Route::Home} />)
+);
+
+snapshot!(
+    button_container,
+    html!(
+        <CosmoButtonContainer>
+            <CosmoButton label="Cancel" />
+            <CosmoButton label="Save" state={CosmoButtonType::Primary} />
+        </CosmoButtonContainer>
+    )
+);
+
+snapshot!(
+    circle_button,
+    html!(
+        <>
+            <CosmoCircleButton title="Leaf" icon={IconId::LucideLeaf} size={CosmoCircleButtonSize::Small} />
+            <CosmoCircleButton title="Leaf" icon={IconId::LucideLeaf} state={CosmoButtonType::Primary} />
+            <CosmoCircleButton title="Leaf" icon={IconId::LucideLeaf} size={CosmoCircleButtonSize::Large} enabled={false} />
+        </>
+    )
+);
+
+snapshot!(back_button, html!(<CosmoBackButton />));
+
+snapshot!(
+    back_button_with_callback,
+    html!(<CosmoBackButton label="Back to list" on_click={Callback::noop()} />)
+);
+
+snapshot!(
+    text_box,
+    html!(
+        <CosmoInputGroup>
+            <CosmoTextBox label="Name" value="Jane" on_input={Callback::noop()} />
+            <CosmoTextBox label="Password" value="" input_type={CosmoTextBoxType::Password} required={true} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    text_area,
+    html!(
+        <CosmoInputGroup>
+            <CosmoTextArea label="Bio" value="Some text" on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    number_box,
+    html!(
+        <CosmoInputGroup>
+            <CosmoNumberBox label="Age" value={42} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    decimal_box,
+    html!(
+        <CosmoInputGroup>
+            <CosmoDecimalBox label="Height" value={1.82} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    date_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoDatePicker label="Birthday" value={NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    date_range_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoDateRangePicker label="Publishing window" value={(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), NaiveDate::from_ymd_opt(2024, 1, 16).unwrap())} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    calendar,
+    html!(
+        <CosmoCalendar value={NaiveDate::from_ymd_opt(2024, 2, 14).unwrap()} range_end={NaiveDate::from_ymd_opt(2024, 2, 16).unwrap()} today={NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()} min={NaiveDate::from_ymd_opt(2024, 2, 5).unwrap()} disabled_dates={CosmoDateFilter::weekends()} show_week_numbers={true} on_select={Callback::noop()} />
+    )
+);
+
+snapshot!(
+    time_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoTimePicker label="Alarm" value={NaiveTime::from_hms_opt(7, 30, 0).unwrap()} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    date_time_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoDateTimePicker label="Meeting" value={Local.with_ymd_and_hms(2024, 1, 2, 7, 30, 0).unwrap()} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    optional_inputs,
+    html!(
+        <CosmoInputGroup>
+            <CosmoOptionalNumberBox label="Age" value={None::<i64>} on_input={Callback::noop()} />
+            <CosmoOptionalDecimalBox label="Height" value={Some(1.82)} on_input={Callback::noop()} />
+            <CosmoOptionalDatePicker label="Birthday" value={None::<NaiveDate>} on_input={Callback::noop()} />
+            <CosmoOptionalTimePicker label="Alarm" value={None::<NaiveTime>} on_input={Callback::noop()} />
+            <CosmoOptionalDateTimePicker label="Meeting" value={None::<DateTime<Local>>} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    zoned_date_time_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoZonedDateTimePicker<FixedOffset> label="Publish at" value={FixedOffset::east_opt(9 * 3600).unwrap().with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap()} zones={vec![
+                CosmoTimeZone::new("London", FixedOffset::east_opt(0).unwrap()),
+                CosmoTimeZone::new("Tokyo", FixedOffset::east_opt(9 * 3600).unwrap()),
+            ]} on_input={Callback::noop()} />
+            <CosmoZonedDateTimePicker<Utc> label="Archive at" value={Utc.with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap()} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    color_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoColorPicker label="Color" value={color!(#514B57)} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    checkbox,
+    html!(
+        <CosmoInputGroup>
+            <CosmoCheckbox label="Subscribed" checked={true} on_check={Callback::noop()} />
+            <CosmoCheckbox label="Read only" checked={false} readonly={true} on_check={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    switch,
+    html!(
+        <CosmoInputGroup>
+            <CosmoSwitch label="Enabled" checked={true} on_check={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    dropdown,
+    html!(
+        <CosmoInputGroup>
+            <CosmoDropdown
+                label="Fruit"
+                value={Some(AttrValue::from("pear"))}
+                items={vec![
+                    (Some(AttrValue::from("apple")), AttrValue::from("Apple")),
+                    (Some(AttrValue::from("pear")), AttrValue::from("Pear")),
+                ]}
+                on_select={Callback::noop()}
+            />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    modern_select,
+    html!(
+        <CosmoInputGroup>
+            <CosmoModernSelect
+                label="Tags"
+                items={vec![
+                    CosmoModernSelectItem::new("Rust", "rust", true),
+                    CosmoModernSelectItem::new("Yew", "yew", false),
+                ]}
+                on_select={Callback::noop()}
+            />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    modern_select_multiple,
+    html!(
+        <CosmoInputGroup>
+            <CosmoModernSelect
+                label="Tags"
+                items={vec![
+                    CosmoModernSelectItem::new("Rust", "rust", true),
+                    CosmoModernSelectItem::new("Yew", "yew", true),
+                ]}
+                on_select={Callback::noop()}
+                on_deselect={Callback::noop()}
+            />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    radios,
+    html!(
+        <CosmoInputGroup>
+            <CosmoRadios
+                label="Size"
+                value="small"
+                options={vec![
+                    (AttrValue::from("small"), AttrValue::from("Small")),
+                    (AttrValue::from("large"), AttrValue::from("Large")),
+                ]}
+                on_change={Callback::noop()}
+            />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    slider,
+    html!(
+        <CosmoInputGroup>
+            <CosmoSlider label="Volume" value={5} min={0} max={10} on_input={Callback::noop()} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    file_picker,
+    html!(
+        <CosmoInputGroup>
+            <CosmoFilePicker label="Avatar" accept="image/*" multiple={true} />
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    fieldset,
+    html!(
+        <CosmoInputGroup>
+            <CosmoFieldset title="Address">
+                <CosmoTextBox label="Street" value="" on_input={Callback::noop()} />
+                <CosmoTextBox label="City" value="" on_input={Callback::noop()} />
+            </CosmoFieldset>
+        </CosmoInputGroup>
+    )
+);
+
+snapshot!(
+    form,
+    html!(
+        <CosmoForm<Person> on_submit={Callback::noop()} buttons={html!(<CosmoButton label="Submit" is_submit={true} />)}>
+            <CosmoTextBox label="Name" value="" on_input={Callback::noop()} />
+        </CosmoForm<Person>>
+    )
+);
+
+snapshot!(
+    page_layout,
+    html!(
+        <CosmoPageLayout>
+            <CosmoPageBody>
+                <CosmoParagraph>{"Content"}</CosmoParagraph>
+            </CosmoPageBody>
+        </CosmoPageLayout>
+    )
+);
+
+snapshot!(
+    top_bar,
+    html!(
+        <CosmoTopBar has_right_item={true} right_item_label="Logout">
+            <CosmoTopBarItem label="Item" />
+            <CosmoTopBarItemExternal label="Github" href="https://github.com" />
+            <CosmoTopBarItemLink<Route> label="Home" to={Route::Home} />
+        </CosmoTopBar>
+    )
+);
+
+snapshot!(
+    menu_bar,
+    html!(
+        <CosmoMenuBar>
+            <CosmoMainMenu>
+                <CosmoMainMenuItem label="Main" is_active={true} />
+                <CosmoMainMenuItemLink<Route> label="Linked" to={Route::Home} is_active={false} />
+            </CosmoMainMenu>
+            <CosmoSubMenuBar>
+                <CosmoSubMenuItem label="Sub" is_active={true} />
+                <CosmoSubMenuItemLink<Route> label="Sub linked" to={Route::Home} is_active={false} />
+            </CosmoSubMenuBar>
+        </CosmoMenuBar>
+    )
+);
+
+snapshot!(
+    page_body,
+    html!(
+        <CosmoPageBody>
+            <CosmoTitle title="Title" subtitle="Subtitle" />
+            <CosmoParagraph>{"Content"}</CosmoParagraph>
+        </CosmoPageBody>
+    )
+);
+
+snapshot!(
+    bottom_bar,
+    html!(
+        <CosmoBottomBar progress_state={CosmoBottomBarProgressState::Visible} progress_value={30} progress_max={100} progress_top_label="Uploading" progress_bottom_label="30%">
+            <CosmoBottomBarLeftItem>
+                <CosmoButton label="Left" />
+            </CosmoBottomBarLeftItem>
+            <CosmoBottomBarRightItem>
+                <CosmoButton label="Right" />
+            </CosmoBottomBarRightItem>
+        </CosmoBottomBar>
+    )
+);
+
+snapshot!(
+    side_list,
+    html!(
+        <CosmoSideList has_add_button={true} add_button_label="Add">
+            <CosmoSideListItem label="First">{"First content"}</CosmoSideListItem>
+            <CosmoSideListItem label="Second">{"Second content"}</CosmoSideListItem>
+        </CosmoSideList>
+    )
+);
+
+snapshot!(
+    tab_control,
+    html!(
+        <CosmoTabControl>
+            <CosmoTabItem label="First">{"First content"}</CosmoTabItem>
+            <CosmoTabItem label="Second">{"Second content"}</CosmoTabItem>
+        </CosmoTabControl>
+    )
+);
+
+snapshot!(
+    progress_bar,
+    html!(
+        <>
+            <CosmoProgressBar value={30} max={100} />
+            <CosmoProgressBar is_indeterminate={true} />
+        </>
+    )
+);
+
+snapshot!(progress_ring, html!(<CosmoProgressRing />));
+
+snapshot!(
+    message,
+    html!(
+        <>
+            <CosmoMessage message="Information" />
+            <CosmoMessage header="Heads up" message="Warning" message_type={CosmoMessageType::Warning} actions={html!(<CosmoButton label="Retry" />)} />
+        </>
+    )
+);
+
+snapshot!(
+    modal_deferred,
+    html!(
+        <CosmoPageLayout>
+            <CosmoModal title="Modal" buttons={html!(<CosmoButton label="Close" />)}>
+                {"Modal content"}
+            </CosmoModal>
+            <CosmoAlert title="Alert" message="Alert message" close_label="Close" on_close={Callback::noop()} />
+            <CosmoConfirm title="Confirm" message="Confirm message" confirm_label="Yes" decline_label="No" on_confirm={Callback::noop()} on_decline={Callback::noop()} />
+        </CosmoPageLayout>
+    )
+);
+
+snapshot!(
+    table,
+    html!(
+        <CosmoTable headers={vec![AttrValue::from("Name"), AttrValue::from("Age")]}>
+            <CosmoTableRow>
+                <CosmoTableCell>{"Jane"}</CosmoTableCell>
+                <CosmoTableCell>{"42"}</CosmoTableCell>
+            </CosmoTableRow>
+        </CosmoTable>
+    )
+);
+
+snapshot!(
+    data_grid,
+    html!(
+        <CosmoDataGrid<Person>
+            columns={vec![CosmoDataGridColumn::new("Person", |person: &Person| html!({person.name.clone()}))]}
+            rows={vec![Person { name: AttrValue::from("John") }, Person { name: AttrValue::from("Jane") }]}
+        />
+    )
+);
+
+snapshot!(
+    providers,
+    html!(
+        <CosmoPageLayout>
+            <CosmoThemeProvider default_theme={CosmoTheme::Dark}>
+                <CosmoToastProvider>
+                    <CosmoDialogProvider>
+                        <CosmoParagraph>{"Provided"}</CosmoParagraph>
+                    </CosmoDialogProvider>
+                </CosmoToastProvider>
+            </CosmoThemeProvider>
+        </CosmoPageLayout>
+    )
+);
+
+snapshot!(
+    toolbar,
+    html!(
+        <CosmoToolbar>
+            <CosmoToolbarGroup>
+                <CosmoButton label="Action" />
+            </CosmoToolbarGroup>
+            <CosmoToolbarSelection count={3}>
+                <CosmoButton label="Delete" state={CosmoButtonType::Negative} />
+            </CosmoToolbarSelection>
+        </CosmoToolbar>
+    )
+);
+
+snapshot!(
+    header,
+    html!(
+        <>
+            <CosmoHeader header="Level 1" />
+            <CosmoHeader header="Level 2" level={CosmoHeaderLevel::H2} />
+            <CosmoHeader header="Level 3" level={CosmoHeaderLevel::H3} />
+        </>
+    )
+);
+
+snapshot!(
+    inline_typography,
+    html!(
+        <CosmoParagraph>
+            <CosmoStrong>{"Strong"}</CosmoStrong>
+            <CosmoEm>{"Emphasis"}</CosmoEm>
+            <CosmoCode>{"code"}</CosmoCode>
+            <CosmoBr />
+            <CosmoAnchor href="https://example.com">{"Anchor"}</CosmoAnchor>
+            <CosmoAnchorLink<Route> to={Route::Home}>{"Anchor link"}</CosmoAnchorLink<Route>>
+        </CosmoParagraph>
+    )
+);
+
+snapshot!(
+    block_typography,
+    html!(
+        <>
+            <CosmoHr />
+            <CosmoPre>{"fn main() {}"}</CosmoPre>
+        </>
+    )
+);
+
+snapshot!(
+    key_value_list,
+    html!(
+        <CosmoKeyValueList>
+            <CosmoKeyValueListItem title="Key">{"Value"}</CosmoKeyValueListItem>
+            <CosmoKeyValueListItem title="Other key">{"Other value"}</CosmoKeyValueListItem>
+        </CosmoKeyValueList>
+    )
+);
+
+snapshot!(
+    compact_menu_bar,
+    html!(
+        <ContextProvider<CosmoViewport> context={CosmoViewport::Compact}>
+            <CosmoMenuBar menu_label="Navigation">
+                <CosmoMainMenu>
+                    <CosmoMainMenuItem label="Main" is_active={true} />
+                </CosmoMainMenu>
+                <CosmoSubMenuBar>
+                    <CosmoSubMenuItem label="Sub" is_active={true} />
+                </CosmoSubMenuBar>
+            </CosmoMenuBar>
+        </ContextProvider<CosmoViewport>>
+    )
+);
+
+snapshot!(
+    compact_side_list,
+    html!(
+        <ContextProvider<CosmoViewport> context={CosmoViewport::Compact}>
+            <CosmoSideList>
+                <CosmoSideListItem label="First">{"First content"}</CosmoSideListItem>
+                <CosmoSideListItem label="Second">{"Second content"}</CosmoSideListItem>
+            </CosmoSideList>
+        </ContextProvider<CosmoViewport>>
+    )
+);
+
+snapshot!(
+    compact_bars,
+    html!(
+        <ContextProvider<CosmoViewport> context={CosmoViewport::Compact}>
+            <CosmoTopBar>
+                <CosmoTopBarItem label="Item" />
+            </CosmoTopBar>
+            <CosmoBottomBar progress_state={CosmoBottomBarProgressState::Indeterminate}>
+                <CosmoBottomBarLeftItem>
+                    <CosmoButton label="Left" />
+                </CosmoBottomBarLeftItem>
+                <CosmoBottomBarRightItem>
+                    <CosmoButton label="Right" />
+                </CosmoBottomBarRightItem>
+            </CosmoBottomBar>
+        </ContextProvider<CosmoViewport>>
+    )
+);
+
+snapshot!(
+    menu_links_from_location,
+    "/gallery/folder",
+    html!(
+        <CosmoMenuBar>
+            <CosmoMainMenu>
+                <CosmoMainMenuItemLink<Route> label="Gallery" to={Route::Gallery} />
+                <CosmoMainMenuItemLink<Route> label="Settings" to={Route::Settings} />
+            </CosmoMainMenu>
+            <CosmoSubMenuBar>
+                <CosmoSubMenuItemLink<Route> label="Overview" to={Route::Gallery} />
+                <CosmoSubMenuItemLink<Route> label="Folder" to={Route::Folder} />
+            </CosmoSubMenuBar>
+        </CosmoMenuBar>
+    )
+);
+
+snapshot!(
+    menu_model,
+    "/gallery/folder",
+    html!(
+        <CosmoMenuBar>
+            <CosmoMenu<Route> items={menu_items()} />
+        </CosmoMenuBar>
+    )
+);
+
+snapshot!(
+    menu_breadcrumbs,
+    "/gallery/folder",
+    html!(<CosmoMenuBreadcrumbs<Route> items={menu_items()} />)
+);
+
+snapshot!(
+    breadcrumbs,
+    html!(
+        <CosmoBreadcrumbs>
+            <CosmoBreadcrumbItemLink<Route> label="Gallery" to={Route::Gallery} />
+            <CosmoBreadcrumbItemLink<Route> label="Folder" to={Route::Folder} siblings={vec![CosmoBreadcrumbSibling::new("Settings", Route::Settings)]} />
+            <CosmoBreadcrumbItem label="File" />
+        </CosmoBreadcrumbs>
+    )
+);
+
+snapshot!(
+    collapsed_breadcrumbs,
+    html!(
+        <CosmoBreadcrumbs max_items={3} separator="›">
+            <CosmoBreadcrumbItem label="Home" on_click={Callback::noop()} />
+            <CosmoBreadcrumbItem label="Gallery" on_click={Callback::noop()} />
+            <CosmoBreadcrumbItem label="Folder" on_click={Callback::noop()} />
+            <CosmoBreadcrumbItem label="Subfolder" on_click={Callback::noop()} />
+            <CosmoBreadcrumbItem label="File" />
+        </CosmoBreadcrumbs>
+    )
+);
@@ -0,0 +1,629 @@
+use chrono::{FixedOffset, Local, NaiveDate, NaiveTime, TimeZone};
+use yew::prelude::*;
+use yew_cosmo::prelude::*;
+
+use common::{render, render_at, Person, Route};
+
+mod common;
+
+#[function_component(Page)]
+fn page() -> Html {
+    html!(
+        <CosmoPageLayout default_title="Cosmo">
+            <CosmoTitle title="Page title" />
+            <CosmoPageBody>
+                <CosmoParagraph>{"Server rendered"}</CosmoParagraph>
+            </CosmoPageBody>
+        </CosmoPageLayout>
+    )
+}
+
+#[derive(PartialEq, Clone, Default, CosmoFormModel)]
+struct Signup {
+    name: String,
+    age: i64,
+}
+
+#[function_component(SignupSummary)]
+fn signup_summary() -> Html {
+    let default_form = use_cosmo_form::<Signup>();
+    let form = use_cosmo_form_with(|| Signup {
+        name: String::from("Jane"),
+        age: 42,
+    });
+    let fields = form.fields();
+
+    html!(
+        <>
+            <p>{format!("default name={} age={}", default_form.get(fields.name), default_form.get(fields.age))}</p>
+            <p>{format!("{}={} {}={}", fields.name.name(), form.get(fields.name), fields.age.name(), form.get(fields.age))}</p>
+            <p>{format!("dirty={} touched={}", form.is_dirty(), form.is_touched())}</p>
+        </>
+    )
+}
+
+#[tokio::test]
+async fn render_cosmo_app_collects_styles_into_head() {
+    let page = render_cosmo_app::<Page>(()).await;
+
+    assert!(page.head.contains("<style data-style="));
+    assert!(page.head.contains("--primary-color"));
+    assert!(page.head.contains("data-bounce-helmet=\"pre-render\""));
+    assert!(page.body.contains("Server rendered"));
+    assert!(page.body.contains("Page title"));
+}
+
+#[tokio::test]
+async fn render_cosmo_app_without_layout_has_no_helmet_tags() {
+    #[function_component(Plain)]
+    fn plain() -> Html {
+        html!(<CosmoButton label="Plain" />)
+    }
+
+    let page = render_cosmo_app::<Plain>(()).await;
+
+    assert!(page.head.contains("<style data-style="));
+    assert!(!page.head.contains("data-bounce-helmet"));
+    assert!(page.body.contains("Plain"));
+}
+
+#[tokio::test]
+async fn render_buttons() {
+    let html = render(|| {
+        html!(
+            <CosmoButtonContainer>
+                <CosmoButton label="Save" state={CosmoButtonType::Primary} />
+                <CosmoButtonLink<Route> label="Home" to={Route::Home} />
+                <CosmoCircleButton title="Leaf" icon={IconId::LucideLeaf} />
+                <CosmoBackButton />
+            </CosmoButtonContainer>
+        )
+    })
+    .await;
+
+    assert!(html.contains(">Save</button>"));
+    assert!(html.contains("href=\"/\""));
+    assert!(html.contains("title=\"Leaf\""));
+    assert!(html.contains("disabled"));
+}
+
+#[tokio::test]
+async fn render_form_controls() {
+    let html = render(|| {
+        html!(
+            <CosmoInputGroup>
+                <CosmoTextBox label="Name" value="Jane" on_input={Callback::noop()} />
+                <CosmoTextArea label="Bio" value="Text" on_input={Callback::noop()} />
+                <CosmoNumberBox label="Age" value={42} on_input={Callback::noop()} />
+                <CosmoDecimalBox label="Height" value={1.8} on_input={Callback::noop()} />
+                <CosmoDatePicker label="Birthday" value={NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()} on_input={Callback::noop()} />
+                <CosmoTimePicker label="Alarm" value={NaiveTime::from_hms_opt(7, 30, 0).unwrap()} on_input={Callback::noop()} />
+                <CosmoDateTimePicker label="Meeting" value={Local.with_ymd_and_hms(2024, 1, 2, 7, 30, 0).unwrap()} on_input={Callback::noop()} />
+                <CosmoColorPicker label="Color" value={color!(#514B57)} on_input={Callback::noop()} />
+                <CosmoCheckbox label="Subscribed" checked={true} on_check={Callback::noop()} />
+                <CosmoSwitch label="Enabled" checked={false} on_check={Callback::noop()} />
+                <CosmoDropdown label="Fruit" items={vec![(Some(AttrValue::from("apple")), AttrValue::from("Apple"))]} on_select={Callback::noop()} />
+                <CosmoModernSelect label="Tags" items={vec![CosmoModernSelectItem::new("Rust", "rust", true)]} on_select={Callback::noop()} />
+                <CosmoRadios label="Size" value="small" options={vec![(AttrValue::from("small"), AttrValue::from("Small"))]} on_change={Callback::noop()} />
+                <CosmoSlider label="Volume" value={5} max={10} on_input={Callback::noop()} />
+                <CosmoFilePicker label="Avatar" />
+                <CosmoFieldset title="Address">
+                    <CosmoTextBox label="Street" value="" on_input={Callback::noop()} />
+                </CosmoFieldset>
+            </CosmoInputGroup>
+        )
+    })
+    .await;
+
+    for label in [
+        "Name",
+        "Bio",
+        "Age",
+        "Height",
+        "Birthday",
+        "Alarm",
+        "Meeting",
+        "Color",
+        "Subscribed",
+        "Enabled",
+        "Fruit",
+        "Tags",
+        "Size",
+        "Volume",
+        "Avatar",
+        "Address",
+        "Street",
+    ] {
+        assert!(html.contains(label), "missing {label}");
+    }
+    assert!(html.contains("value=\"Jane\""));
+    assert!(html.contains("01/02/2024"));
+}
+
+#[tokio::test]
+async fn render_form() {
+    let html = render(|| {
+        html!(
+            <CosmoForm<Person> on_submit={Callback::noop()} buttons={html!(<CosmoButton label="Submit" is_submit={true} />)}>
+                <CosmoTextBox label="Name" value="" on_input={Callback::noop()} />
+            </CosmoForm<Person>>
+        )
+    })
+    .await;
+
+    assert!(html.contains("<form"));
+    assert!(html.contains("type=\"submit\""));
+}
+
+#[tokio::test]
+async fn render_layout() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout>
+                <CosmoTopBar has_right_item={true} right_item_label="Logout">
+                    <CosmoTopBarItem label="Item" />
+                    <CosmoTopBarItemExternal label="Github" href="https://github.com" />
+                    <CosmoTopBarItemLink<Route> label="Home" to={Route::Home} />
+                </CosmoTopBar>
+                <CosmoMenuBar>
+                    <CosmoMainMenu>
+                        <CosmoMainMenuItem label="Main" is_active={true} />
+                        <CosmoMainMenuItemLink<Route> label="Linked" to={Route::Home} is_active={false} />
+                    </CosmoMainMenu>
+                    <CosmoSubMenuBar>
+                        <CosmoSubMenuItem label="Sub" is_active={true} />
+                        <CosmoSubMenuItemLink<Route> label="Sub linked" to={Route::Home} is_active={false} />
+                    </CosmoSubMenuBar>
+                </CosmoMenuBar>
+                <CosmoPageBody>
+                    <CosmoTitle title="Title" />
+                </CosmoPageBody>
+                <CosmoBottomBar>
+                    <CosmoBottomBarLeftItem>
+                        <CosmoStrong>{"Left"}</CosmoStrong>
+                    </CosmoBottomBarLeftItem>
+                    <CosmoBottomBarRightItem>
+                        <CosmoStrong>{"Right"}</CosmoStrong>
+                    </CosmoBottomBarRightItem>
+                </CosmoBottomBar>
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    for text in [
+        "Logout",
+        "Item",
+        "https://github.com",
+        "Main",
+        "Linked",
+        "Sub",
+        "Sub linked",
+        "Title",
+        "Left",
+        "Right",
+    ] {
+        assert!(html.contains(text), "missing {text}");
+    }
+}
+
+#[tokio::test]
+async fn render_lists_and_tabs() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoSideList>
+                    <CosmoSideListItem label="First">{"First content"}</CosmoSideListItem>
+                    <CosmoSideListItem label="Second">{"Second content"}</CosmoSideListItem>
+                </CosmoSideList>
+                <CosmoTabControl>
+                    <CosmoTabItem label="Tab one">{"Tab content"}</CosmoTabItem>
+                </CosmoTabControl>
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("First content"));
+    assert!(!html.contains("Second content"));
+    assert!(html.contains("Tab one"));
+    assert!(html.contains("Tab content"));
+}
+
+#[tokio::test]
+async fn render_loaders_and_messages() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoProgressBar />
+                <CosmoProgressRing />
+                <CosmoMessage header="Heads up" message="Something happened" message_type={CosmoMessageType::Warning} />
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Heads up"));
+    assert!(html.contains("Something happened"));
+}
+
+#[tokio::test]
+async fn render_portals_are_deferred_on_the_server() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout>
+                <CosmoModal title="Modal" buttons={html!(<CosmoButton label="Close" />)}>
+                    {"Modal content"}
+                </CosmoModal>
+                <CosmoAlert title="Alert" message="Alert message" close_label="Close" on_close={Callback::noop()} />
+                <CosmoConfirm title="Confirm" message="Confirm message" confirm_label="Yes" decline_label="No" on_confirm={Callback::noop()} on_decline={Callback::noop()} />
+                <CosmoParagraph>{"Page content"}</CosmoParagraph>
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Page content"));
+    assert!(!html.contains("Modal content"));
+    assert!(!html.contains("<dialog"));
+}
+
+#[tokio::test]
+async fn render_tables() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoTable headers={vec![AttrValue::from("Name")]}>
+                    <CosmoTableRow>
+                        <CosmoTableCell>{"Jane"}</CosmoTableCell>
+                    </CosmoTableRow>
+                </CosmoTable>
+                <CosmoDataGrid<Person>
+                    columns={vec![CosmoDataGridColumn::new("Person", |person: &Person| html!({person.name.clone()}))]}
+                    rows={vec![Person { name: AttrValue::from("John") }]}
+                />
+                <CosmoDataGrid<Person>
+                    columns={vec![CosmoDataGridColumn::new("Person", |person: &Person| html!({person.name.clone()}))]}
+                    rows={vec![Person { name: AttrValue::from("Jim") }]}
+                    is_paginated={true}
+                    page_size={7}
+                />
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Jane"));
+    assert!(html.contains("Person"));
+    assert!(html.contains("John"));
+    assert!(html.contains("<option value=\"7\" selected=\"selected\">7</option>"));
+    assert!(html.contains("<span aria-live=\"polite\""));
+}
+
+#[tokio::test]
+async fn render_derived_form_model() {
+    let html = render(|| html!(<SignupSummary />)).await;
+
+    assert!(html.contains("default name= age=0"));
+    assert!(html.contains("name=Jane age=42"));
+    assert!(html.contains("dirty=false touched=false"));
+}
+
+#[tokio::test]
+async fn render_selectable_rows_with_labels() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoTable headers={vec![AttrValue::from("Name")]} is_selectable={true}>
+                    <CosmoTableRow>
+                        <CosmoTableCell>{"Jane"}</CosmoTableCell>
+                    </CosmoTableRow>
+                    <CosmoTableRow select_label="Select Joe">
+                        <CosmoTableCell>{"Joe"}</CosmoTableCell>
+                    </CosmoTableRow>
+                </CosmoTable>
+                <CosmoDataGrid<Person>
+                    columns={vec![CosmoDataGridColumn::new("Person", |person: &Person| html!({person.name.clone()}))]}
+                    rows={vec![Person { name: AttrValue::from("John") }]}
+                    is_selectable={true}
+                    select_row_label="Select person"
+                />
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("aria-label=\"Select row\""));
+    assert!(html.contains("aria-label=\"Select Joe\""));
+    assert!(html.contains("aria-label=\"Select person\""));
+}
+
+#[tokio::test]
+async fn render_theme_toast_and_dialog_providers() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout default_theme={CosmoTheme::Dark}>
+                <CosmoThemeProvider default_theme={CosmoTheme::Light}>
+                    <CosmoToastProvider>
+                        <CosmoDialogProvider>
+                            <CosmoParagraph>{"Provided"}</CosmoParagraph>
+                        </CosmoDialogProvider>
+                    </CosmoToastProvider>
+                </CosmoThemeProvider>
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Provided"));
+    assert!(!html.contains("aria-live"));
+}
+
+#[tokio::test]
+async fn render_toolbar() {
+    let html = render(|| {
+        html!(
+            <CosmoToolbar>
+                <CosmoToolbarGroup>
+                    <CosmoButton label="Action" />
+                </CosmoToolbarGroup>
+                <CosmoToolbarSelection count={3} />
+            </CosmoToolbar>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Action"));
+    assert!(html.contains("3 selected"));
+}
+
+#[tokio::test]
+async fn render_typography() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoHeader header="Header" level={CosmoHeaderLevel::H2} />
+                <CosmoParagraph>
+                    <CosmoStrong>{"Strong"}</CosmoStrong>
+                    <CosmoEm>{"Emphasis"}</CosmoEm>
+                    <CosmoCode>{"code"}</CosmoCode>
+                    <CosmoBr />
+                    <CosmoAnchor href="https://example.com">{"Anchor"}</CosmoAnchor>
+                    <CosmoAnchorLink<Route> to={Route::Home}>{"Anchor link"}</CosmoAnchorLink<Route>>
+                </CosmoParagraph>
+                <CosmoHr />
+                <CosmoPre>{"pre"}</CosmoPre>
+                <CosmoKeyValueList>
+                    <CosmoKeyValueListItem title="Key">{"Value"}</CosmoKeyValueListItem>
+                </CosmoKeyValueList>
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("<h2"));
+    for text in [
+        "Header",
+        "Strong",
+        "Emphasis",
+        "code",
+        "https://example.com",
+        "Anchor link",
+        "<hr",
+        "<pre",
+        "Key",
+        "Value",
+    ] {
+        assert!(html.contains(text), "missing {text}");
+    }
+}
+
+#[tokio::test]
+async fn render_menu_model_for_active_branch() {
+    let html = render_at("/gallery/folder", || {
+        html!(
+            <CosmoMenu<Route> items={vec![
+                CosmoMenuItem::new("Gallery", Route::Gallery)
+                    .with_route_match(CosmoRouteMatch::Prefix)
+                    .with_children(vec![CosmoMenuItem::new("Folder", Route::Folder)]),
+                CosmoMenuItem::new("Settings", Route::Settings)
+                    .with_children(vec![CosmoMenuItem::new("Profile", Route::Home)]),
+            ]} />
+        )
+    })
+    .await;
+
+    assert!(html.contains("Gallery"));
+    assert!(html.contains("Folder"));
+    assert!(html.contains("Settings"));
+    assert!(!html.contains("Profile"));
+}
+
+#[tokio::test]
+async fn render_back_button_state() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout>
+                <CosmoMenuBar />
+                <CosmoBackButton label="Back to list" on_click={Callback::noop()} />
+                <CosmoBackButton label="Disabled" on_click={Callback::noop()} is_enabled={false} />
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert!(html.contains("aria-label=\"Back\" title=\"Back\" disabled=\"disabled\""));
+    assert!(html.contains("aria-label=\"Back to list\" title=\"Back to list\" class="));
+    assert!(html.contains("aria-label=\"Disabled\" title=\"Disabled\" disabled=\"disabled\""));
+}
+
+#[tokio::test]
+async fn render_collapsed_breadcrumbs() {
+    let html = render(|| {
+        html!(
+            <CosmoBreadcrumbs max_items={3}>
+                <CosmoBreadcrumbItem label="First" />
+                <CosmoBreadcrumbItem label="Second" />
+                <CosmoBreadcrumbItem label="Third" />
+                <CosmoBreadcrumbItem label="Fourth" />
+                <CosmoBreadcrumbItem label="Fifth" />
+            </CosmoBreadcrumbs>
+        )
+    })
+    .await;
+
+    assert!(html.contains("First"));
+    assert!(!html.contains("Second"));
+    assert!(!html.contains("Third"));
+    assert!(html.contains("Show full path"));
+    assert!(html.contains("Fourth"));
+    assert!(html.contains("<span aria-current=\"page\">Fifth</span>"));
+}
+
+#[tokio::test]
+async fn render_optional_inputs() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout locale={CosmoLocale::german()}>
+                <CosmoInputGroup>
+                    <CosmoOptionalNumberBox label="Alter" value={None::<i64>} on_input={Callback::noop()} />
+                    <CosmoOptionalDecimalBox label="Größe" value={Some(1.5)} on_input={Callback::noop()} />
+                    <CosmoOptionalDatePicker label="Geburtstag" value={None::<NaiveDate>} on_input={Callback::noop()} />
+                    <CosmoTimePicker label="Wecker" value={NaiveTime::from_hms_opt(7, 30, 0).unwrap()} on_input={Callback::noop()} />
+                </CosmoInputGroup>
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert_eq!(html.matches(r#"value="""#).count(), 2);
+    assert!(html.contains(r#"value="1,50""#));
+    assert!(html.contains(r#"value="07:30""#));
+    assert!(!html.contains(r#"value="0""#));
+}
+
+#[tokio::test]
+async fn render_calendar() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout locale={CosmoLocale::german()}>
+                <CosmoCalendar value={NaiveDate::from_ymd_opt(2024, 2, 14).unwrap()} today={NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()} max={NaiveDate::from_ymd_opt(2024, 2, 20).unwrap()} disabled_dates={CosmoDateFilter::weekends()} show_week_numbers={true} on_select={Callback::noop()} />
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert!(html.contains("Februar 2024"));
+    assert!(html.contains(">KW</th>"));
+    assert!(html.contains(">Mo</th>"));
+    assert!(html.contains(">5</th>"));
+    assert!(html.contains("aria-label=\"14.02.2024\""));
+    assert!(html.contains("aria-current=\"date\""));
+    assert!(html.contains("aria-selected=\"true\""));
+    assert!(html.contains("aria-label=\"Nächster Monat\" title=\"Nächster Monat\" disabled"));
+    assert!(html.contains(">Heute</button>"));
+}
+
+#[tokio::test]
+async fn render_calendar_keeps_disabled_days_focusable() {
+    let html = render(|| {
+        html!(
+            <CosmoCalendar value={NaiveDate::from_ymd_opt(2024, 2, 17).unwrap()} today={NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()} disabled_dates={CosmoDateFilter::weekends()} on_select={Callback::noop()} />
+        )
+    })
+    .await;
+
+    let focused = html
+        .split("<button")
+        .find(|button| button.contains("tabindex=\"0\""))
+        .and_then(|button| button.split_once('>'))
+        .map(|(attributes, _)| attributes)
+        .expect("Should render a focusable day");
+
+    assert!(focused.contains("aria-label=\"02/17/2024\""));
+    assert!(focused.contains("aria-disabled=\"true\""));
+    assert!(!focused.contains(" disabled"));
+}
+
+#[tokio::test]
+async fn render_calendar_deterministically_for_hydration() {
+    let html = render(|| {
+        html!(
+            <>
+                <CosmoCalendar id="due" value={NaiveDate::from_ymd_opt(2024, 2, 14).unwrap()} on_select={Callback::noop()} />
+                <CosmoCalendar id="unset" on_select={Callback::noop()} />
+            </>
+        )
+    })
+    .await;
+
+    assert!(html.contains("id=\"due-title\""));
+    assert!(html.contains("aria-labelledby=\"due-title\""));
+    assert!(html.contains("February 2024"));
+    assert!(!html.contains("aria-current=\"date\""));
+    assert!(!html.contains("unset-title"));
+}
+
+#[tokio::test]
+async fn render_zoned_date_time_picker() {
+    let html = render(|| {
+        html!(
+            <CosmoInputGroup>
+                <CosmoZonedDateTimePicker<FixedOffset> label="Publish at" value={FixedOffset::east_opt(9 * 3600).unwrap().with_ymd_and_hms(2024, 1, 2, 9, 0, 0).unwrap()} zone_label="Tokyo" on_input={Callback::noop()} />
+            </CosmoInputGroup>
+        )
+    })
+    .await;
+
+    assert!(html.contains("value=\"01/02/2024 09:00 AM\""));
+    assert!(html.contains("title=\"UTC+09:00\""));
+    assert!(html.contains(">Tokyo</span>"));
+}
+
+#[tokio::test]
+async fn render_german_locale() {
+    let html = render(|| {
+        html!(
+            <CosmoPageLayout locale={CosmoLocale::german()}>
+                <CosmoMenuBar />
+                <CosmoDecimalBox label="Preis" value={1234.5} grouping={true} on_input={Callback::noop()} />
+                <CosmoNumberBox label="Anzahl" value={1234} grouping={true} on_input={Callback::noop()} />
+                <CosmoNumberBox label="Lager" value={5678} on_input={Callback::noop()} />
+                <CosmoFilePicker label="Datei" is_drop_zone={true} />
+                <CosmoToolbar>
+                    <CosmoToolbarSelection count={2} />
+                </CosmoToolbar>
+            </CosmoPageLayout>
+        )
+    })
+    .await;
+
+    assert!(html.contains("value=\"1.234,50\""));
+    assert!(html.contains("value=\"1.234\""));
+    assert!(html.contains("value=\"5678\""));
+    assert_eq!(html.matches("type=\"number\"").count(), 1);
+    assert!(html.contains("aria-label=\"Zurück\""));
+    assert!(html.contains("Dateien hier ablegen oder klicken zum Auswählen"));
+    assert!(html.contains("2 ausgewählt"));
+}
+
+#[tokio::test]
+async fn render_right_to_left_layout() {
+    #[function_component(RtlPage)]
+    fn rtl_page() -> Html {
+        html!(
+            <CosmoPageLayout dir={CosmoTextDirection::Rtl}>
+                <CosmoMenuBar />
+                <CosmoSideList>
+                    <CosmoSideListItem label="First">{"First content"}</CosmoSideListItem>
+                </CosmoSideList>
+            </CosmoPageLayout>
+        )
+    }
+
+    let page = render_cosmo_app::<RtlPage>(()).await;
+
+    assert!(page.body.contains("dir=\"rtl\""));
+    assert!(page.head.contains("border-inline-end"));
+    assert!(page.head.contains(":dir(rtl)"));
+}